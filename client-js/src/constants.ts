@@ -1 +1,2 @@
 export const CEP78_CONTRACT_PACKAGE = "cep78_contract_package";
+export const PAGE_BITMAP_HEADER = 0xff;
@@ -51,6 +51,7 @@ const { Contract } = Contracts;
 
 export * from "./types";
 export * from "./events";
+export * from "./pages";
 
 enum ERRORS {
   CONFLICT_CONFIG = "Conflicting arguments provided",
@@ -183,6 +184,24 @@ export class CEP78Client {
       runtimeArgs.insert("events_mode", CLValueBuilder.u8(args.eventsMode));
     }
 
+    if (args.pageEncoding !== undefined) {
+      runtimeArgs.insert(
+        "page_encoding",
+        CLValueBuilder.u8(args.pageEncoding)
+      );
+    }
+
+    if (args.pageSize !== undefined) {
+      runtimeArgs.insert("page_size", CLValueBuilder.u64(args.pageSize));
+    }
+
+    if (args.metadataStorageMode !== undefined) {
+      runtimeArgs.insert(
+        "metadata_storage_mode",
+        CLValueBuilder.u8(args.metadataStorageMode)
+      );
+    }
+
     return this.contractClient.install(
       wasmToInstall,
       runtimeArgs,
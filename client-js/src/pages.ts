@@ -0,0 +1,40 @@
+import { PAGE_BITMAP_HEADER } from "./constants";
+
+/**
+ * Decodes a reverse lookup page or page table record into its flags.
+ * Bitmap records start with `PAGE_BITMAP_HEADER` and pack eight flags per byte,
+ * least significant bit first. Records written in the bool list encoding hold
+ * one flag per entry.
+ */
+export const decodePageRecord = (
+  record: ArrayLike<number | boolean>
+): boolean[] => {
+  const values = Array.from(record, Number);
+  if (values[0] !== PAGE_BITMAP_HEADER) {
+    return values.map((flag) => flag !== 0);
+  }
+
+  const flags: boolean[] = [];
+  values.slice(1).forEach((byte) => {
+    for (let bit = 0; bit < 8; bit += 1) {
+      // eslint-disable-next-line no-bitwise
+      flags.push((byte & (1 << bit)) !== 0);
+    }
+  });
+  return flags;
+};
+
+/**
+ * Returns the indexes of the tokens marked as owned on a decoded page.
+ */
+export const getOwnedTokenIndexes = (
+  page: boolean[],
+  pageNumber: number,
+  pageSize = 1000
+): number[] =>
+  page.reduce<number[]>((indexes, isOwned, pageAddress) => {
+    if (isOwned) {
+      indexes.push(pageNumber * pageSize + pageAddress);
+    }
+    return indexes;
+  }, []);
@@ -80,6 +80,16 @@ export enum OwnerReverseLookupMode {
   TransfersOnly,
 }
 
+export enum PageEncoding {
+  BoolList,
+  Bitmap,
+}
+
+export enum MetadataStorageMode {
+  PerKind,
+  Compact,
+}
+
 export enum EventsMode {
   NoEvents,
   CEP47,
@@ -120,6 +130,9 @@ export type InstallArgs = {
   accessKeyName?: string;
   hashKeyName?: string;
   eventsMode?: EventsMode;
+  pageEncoding?: PageEncoding;
+  pageSize?: string;
+  metadataStorageMode?: MetadataStorageMode;
 } & ConfigurableVariables;
 
 export interface RegisterArgs {
@@ -7,6 +7,8 @@ import {
   MetadataMutability,
   MintingMode,
   NamedKeyConventionMode,
+  decodePageRecord,
+  getOwnedTokenIndexes,
 } from "../src/index";
 
 import {
@@ -404,3 +406,19 @@ describe("CEP78Client", () => {
     );
   });
 });
+
+describe("Page records", () => {
+  it("Should decode a bitmap page record", () => {
+    const page = decodePageRecord([0xff, 0b00000101, 0b10000000]);
+
+    expect(page).toHaveLength(16);
+    expect(getOwnedTokenIndexes(page, 2)).toEqual([2000, 2002, 2015]);
+  });
+
+  it("Should decode a bool list page record", () => {
+    const page = decodePageRecord([false, true, true]);
+
+    expect(page).toEqual([false, true, true]);
+    expect(getOwnedTokenIndexes(page, 0)).toEqual([1, 2]);
+  });
+});
@@ -0,0 +1,47 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+use alloc::{format, string::String};
+
+use casper_contract::contract_api::runtime;
+use casper_types::{runtime_args, ContractPackageHash, Key, RuntimeArgs, URef};
+
+const ENTRY_POINT_ACCEPT_ADMIN: &str = "accept_admin";
+const ARG_NFT_CONTRACT_PACKAGE_HASH: &str = "nft_contract_package_hash";
+const ARG_COLLECTION_NAME: &str = "collection_name";
+const PREFIX_ACCESS_KEY_NAME: &str = "cep78_contract_package_access";
+const PREFIX_HASH_KEY_NAME: &str = "cep78_contract_package";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let nft_contract_package_hash: ContractPackageHash =
+        runtime::get_named_arg::<Key>(ARG_NFT_CONTRACT_PACKAGE_HASH)
+            .into_hash()
+            .map(ContractPackageHash::new)
+            .unwrap();
+    let collection_name: String = runtime::get_named_arg(ARG_COLLECTION_NAME);
+
+    let package_access_uref = runtime::call_versioned_contract::<Option<URef>>(
+        nft_contract_package_hash,
+        None,
+        ENTRY_POINT_ACCEPT_ADMIN,
+        runtime_args! {},
+    );
+
+    // Keeps the package access URef under the names the upgrade looks up, so that the new admin
+    // can upgrade the contract.
+    if let Some(package_access_uref) = package_access_uref {
+        runtime::put_key(
+            &format!("{PREFIX_ACCESS_KEY_NAME}_{collection_name}"),
+            package_access_uref.into(),
+        );
+        runtime::put_key(
+            &format!("{PREFIX_HASH_KEY_NAME}_{collection_name}"),
+            nft_contract_package_hash.into(),
+        );
+    }
+}
@@ -0,0 +1,31 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+use alloc::{collections::BTreeMap, string::String};
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs};
+
+const ENTRY_POINT_COLLECTION_STATS: &str = "collection_stats";
+const ARG_NFT_CONTRACT_HASH: &str = "nft_contract_hash";
+const ARG_KEY_NAME: &str = "key_name";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let nft_contract_hash: ContractHash = runtime::get_named_arg::<Key>(ARG_NFT_CONTRACT_HASH)
+        .into_hash()
+        .map(ContractHash::new)
+        .unwrap();
+    let key_name: String = runtime::get_named_arg(ARG_KEY_NAME);
+
+    let collection_stats = runtime::call_contract::<BTreeMap<String, u64>>(
+        nft_contract_hash,
+        ENTRY_POINT_COLLECTION_STATS,
+        runtime_args! {},
+    );
+    runtime::put_key(&key_name, storage::new_uref(collection_stats).into());
+}
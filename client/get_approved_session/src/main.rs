@@ -5,7 +5,7 @@
 compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
 
 extern crate alloc;
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 
 use casper_contract::contract_api::{runtime, storage};
 use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs};
@@ -25,9 +25,9 @@ pub extern "C" fn call() {
         .unwrap();
     let key_name: String = runtime::get_named_arg(ARG_KEY_NAME);
 
-    let maybe_approved_account = if runtime::get_named_arg::<bool>(ARG_IS_HASH_IDENTIFIER_MODE) {
+    let approved_accounts = if runtime::get_named_arg::<bool>(ARG_IS_HASH_IDENTIFIER_MODE) {
         let token_hash = runtime::get_named_arg::<String>(ARG_TOKEN_HASH);
-        runtime::call_contract::<Option<Key>>(
+        runtime::call_contract::<Vec<Key>>(
             nft_contract_hash,
             ENTRY_POINT_GET_APPROVED,
             runtime_args! {
@@ -36,7 +36,7 @@ pub extern "C" fn call() {
         )
     } else {
         let token_id = runtime::get_named_arg::<u64>(ARG_TOKEN_ID);
-        runtime::call_contract::<Option<Key>>(
+        runtime::call_contract::<Vec<Key>>(
             nft_contract_hash,
             ENTRY_POINT_GET_APPROVED,
             runtime_args! {
@@ -44,5 +44,5 @@ pub extern "C" fn call() {
             },
         )
     };
-    runtime::put_key(&key_name, storage::new_uref(maybe_approved_account).into());
+    runtime::put_key(&key_name, storage::new_uref(approved_accounts).into());
 }
@@ -0,0 +1,48 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+use alloc::string::String;
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs};
+
+const ENTRY_POINT_TOKEN_OF_OWNER_BY_INDEX: &str = "token_of_owner_by_index";
+const ARG_NFT_CONTRACT_HASH: &str = "nft_contract_hash";
+const ARG_KEY_NAME: &str = "key_name";
+const ARG_TOKEN_OWNER: &str = "token_owner";
+const ARG_INDEX: &str = "index";
+const ARG_IS_HASH_IDENTIFIER_MODE: &str = "is_hash_identifier_mode";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let nft_contract_hash: ContractHash = runtime::get_named_arg::<Key>(ARG_NFT_CONTRACT_HASH)
+        .into_hash()
+        .map(ContractHash::new)
+        .unwrap();
+    let key_name: String = runtime::get_named_arg(ARG_KEY_NAME);
+
+    let args = runtime_args! {
+        ARG_TOKEN_OWNER => runtime::get_named_arg::<Key>(ARG_TOKEN_OWNER),
+        ARG_INDEX => runtime::get_named_arg::<u64>(ARG_INDEX),
+    };
+
+    if runtime::get_named_arg(ARG_IS_HASH_IDENTIFIER_MODE) {
+        let token_hash = runtime::call_contract::<String>(
+            nft_contract_hash,
+            ENTRY_POINT_TOKEN_OF_OWNER_BY_INDEX,
+            args,
+        );
+        runtime::put_key(&key_name, storage::new_uref(token_hash).into());
+    } else {
+        let token_id = runtime::call_contract::<u64>(
+            nft_contract_hash,
+            ENTRY_POINT_TOKEN_OF_OWNER_BY_INDEX,
+            args,
+        );
+        runtime::put_key(&key_name, storage::new_uref(token_id).into());
+    }
+}
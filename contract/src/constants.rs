@@ -8,90 +8,163 @@ pub const PREFIX_PAGE_DICTIONARY: &str = "page";
 pub const ARG_ACCESS_KEY_NAME_1_0_0: &str = "access_key_name";
 pub const ARG_ACL_PACKAGE_MODE: &str = "acl_package_mode";
 pub const ARG_ACL_WHITELIST: &str = "acl_whitelist";
+pub const ARG_ADMIN: &str = "admin";
 pub const ARG_ADDITIONAL_REQUIRED_METADATA: &str = "additional_required_metadata";
 pub const ARG_ALLOW_MINTING: &str = "allow_minting";
 pub const ARG_APPROVE_ALL: &str = "approve_all";
+pub const ARG_BASE_URI: &str = "base_uri";
+pub const ARG_BURN_FILTER_CONTRACT: &str = "burn_filter_contract";
 pub const ARG_BURN_MODE: &str = "burn_mode";
+pub const ARG_BURNT_TOKENS_COUNT: &str = "burnt_tokens_count";
+pub const ARG_CALLER: &str = "caller";
+pub const ARG_COLLECTION_METADATA: &str = "collection_metadata";
 pub const ARG_COLLECTION_NAME: &str = "collection_name";
 pub const ARG_COLLECTION_SYMBOL: &str = "collection_symbol";
 pub const ARG_CONTRACT_WHITELIST: &str = "contract_whitelist";
+pub const ARG_DATA: &str = "data";
 pub const ARG_EVENTS_MODE: &str = "events_mode";
+pub const ARG_EXPIRES_AT: &str = "expires_at";
+pub const ARG_FROM: &str = "from";
 pub const ARG_HASH_KEY_NAME_1_0_0: &str = "hash_key_name";
+pub const ARG_HOLDER_COUNT: &str = "holder_count";
 pub const ARG_HOLDER_MODE: &str = "holder_mode";
 pub const ARG_IDENTIFIER_MODE: &str = "identifier_mode";
+pub const ARG_INDEX: &str = "index";
+pub const ARG_ISSUER: &str = "issuer";
 pub const ARG_JSON_SCHEMA: &str = "json_schema";
+pub const ARG_LIMIT: &str = "limit";
 pub const ARG_METADATA_MUTABILITY: &str = "metadata_mutability";
+pub const ARG_METADATA_STORAGE_MODE: &str = "metadata_storage_mode";
+pub const ARG_MINT_FILTER_CONTRACT: &str = "mint_filter_contract";
 pub const ARG_MINTING_MODE: &str = "minting_mode";
 pub const ARG_NAMED_KEY_CONVENTION: &str = "named_key_convention";
 pub const ARG_NFT_KIND: &str = "nft_kind";
 pub const ARG_NFT_METADATA_KIND: &str = "nft_metadata_kind";
 pub const ARG_NFT_PACKAGE_KEY: &str = "cep78_package_key";
+pub const ARG_NON_TRANSFERABLE: &str = "non_transferable";
+pub const ARG_OFFSET: &str = "offset";
 pub const ARG_OPTIONAL_METADATA: &str = "optional_metadata";
 pub const ARG_OPERATOR: &str = "operator";
 pub const ARG_OPERATOR_BURN_MODE: &str = "operator_burn_mode";
 pub const ARG_OWNERSHIP_MODE: &str = "ownership_mode";
 pub const ARG_OWNER_LOOKUP_MODE: &str = "owner_reverse_lookup_mode";
+pub const ARG_PACKAGE_ACCESS_UREF: &str = "package_access_uref";
 pub const ARG_PACKAGE_OPERATOR_MODE: &str = "package_operator_mode";
+pub const ARG_PAGE_ENCODING: &str = "page_encoding";
+pub const ARG_PAGE_SIZE: &str = "page_size";
 pub const ARG_RECEIPT_NAME: &str = "receipt_name";
+pub const ARG_ROLE: &str = "role";
+pub const ARG_ROLE_HOLDER: &str = "role_holder";
+pub const ARG_ROYALTY_BASIS_POINTS: &str = "royalty_basis_points";
+pub const ARG_ROYALTY_RECEIVER: &str = "royalty_receiver";
+pub const ARG_SAFE: &str = "safe";
+pub const ARG_SALE_PRICE: &str = "sale_price";
 pub const ARG_SOURCE_KEY: &str = "source_key";
 pub const ARG_SPENDER: &str = "spender";
 pub const ARG_TARGET_KEY: &str = "target_key";
 pub const ARG_TOKEN_HASH: &str = "token_hash";
+pub const ARG_TOKEN_HASHES: &str = "token_hashes";
 pub const ARG_TOKEN_ID: &str = "token_id";
+pub const ARG_TOKEN_IDS: &str = "token_ids";
 pub const ARG_TOKEN_META_DATA: &str = "token_meta_data";
+pub const ARG_TOKEN_META_DATAS: &str = "token_meta_datas";
 pub const ARG_TOKEN_OWNER: &str = "token_owner";
+pub const ARG_TOKEN_OWNERS: &str = "token_owners";
 pub const ARG_TOTAL_TOKEN_SUPPLY: &str = "total_token_supply";
 pub const ARG_TRANSFER_FILTER_CONTRACT: &str = "transfer_filter_contract";
+pub const ARG_TRANSFER_FILTER_CONTRACT_VERSION: &str = "transfer_filter_contract_version";
 pub const ARG_WHITELIST_MODE: &str = "whitelist_mode";
 
+pub const ENTRY_POINT_ACCEPT_ADMIN: &str = "accept_admin";
 pub const ENTRY_POINT_APPROVE: &str = "approve";
+pub const ENTRY_POINT_APPROVE_BATCH: &str = "approve_batch";
 pub const ENTRY_POINT_BALANCE_OF: &str = "balance_of";
 pub const ENTRY_POINT_BURN: &str = "burn";
+pub const ENTRY_POINT_BURN_BATCH: &str = "burn_batch";
+pub const ENTRY_POINT_COLLECTION_METADATA: &str = "collection_metadata";
+pub const ENTRY_POINT_COLLECTION_STATS: &str = "collection_stats";
+pub const ENTRY_POINT_FREEZE_METADATA: &str = "freeze_metadata";
 pub const ENTRY_POINT_GET_APPROVED: &str = "get_approved";
+pub const ENTRY_POINT_GRANT_ROLE: &str = "grant_role";
+pub const ENTRY_POINT_HAS_ROLE: &str = "has_role";
 pub const ENTRY_POINT_INIT: &str = "init";
 pub const ENTRY_POINT_IS_APPROVED_FOR_ALL: &str = "is_approved_for_all";
+pub const ENTRY_POINT_IS_NON_TRANSFERABLE: &str = "is_non_transferable";
 pub const ENTRY_POINT_METADATA: &str = "metadata";
 pub const ENTRY_POINT_MIGRATE: &str = "migrate";
 pub const ENTRY_POINT_MINT: &str = "mint";
+pub const ENTRY_POINT_MINT_BATCH: &str = "mint_batch";
 pub const ENTRY_POINT_OWNER_OF: &str = "owner_of";
 pub const ENTRY_POINT_REVOKE: &str = "revoke";
+pub const ENTRY_POINT_REVOKE_ROLE: &str = "revoke_role";
 pub const ENTRY_POINT_REGISTER_OWNER: &str = "register_owner";
+pub const ENTRY_POINT_ROYALTY_INFO: &str = "royalty_info";
+pub const ENTRY_POINT_SAFE_TRANSFER: &str = "safe_transfer";
 pub const ENTRY_POINT_SET_APPROVALL_FOR_ALL: &str = "set_approval_for_all";
+pub const ENTRY_POINT_SET_BASE_URI: &str = "set_base_uri";
+pub const ENTRY_POINT_SET_COLLECTION_METADATA: &str = "set_collection_metadata";
 pub const ENTRY_POINT_SET_TOKEN_METADATA: &str = "set_token_metadata";
 pub const ENTRY_POINT_SET_VARIABLES: &str = "set_variables";
+pub const ENTRY_POINT_TOKEN_OF_OWNER_BY_INDEX: &str = "token_of_owner_by_index";
+pub const ENTRY_POINT_TOKEN_URI: &str = "token_uri";
+pub const ENTRY_POINT_TOKENS_OF_OWNER: &str = "tokens_of_owner";
 pub const ENTRY_POINT_TRANSFER: &str = "transfer";
+pub const ENTRY_POINT_TRANSFER_ADMIN: &str = "transfer_admin";
+pub const ENTRY_POINT_TRANSFER_BATCH: &str = "transfer_batch";
 pub const ENTRY_POINT_UPDATED_RECEIPTS: &str = "updated_receipts";
 
 pub const ACL_PACKAGE_MODE: &str = "acl_package_mode";
+pub const ADMIN: &str = "admin";
 pub const ACL_WHITELIST: &str = "acl_whitelist";
 pub const ALLOW_MINTING: &str = "allow_minting";
-pub const APPROVED: &str = "approved";
+pub const APPROVAL_EXPIRIES: &str = "approval_expiries";
+pub const APPROVED: &str = "approved"; // Deprecated in favor of APPROVED_SPENDERS
+pub const APPROVED_SPENDERS: &str = "approved_spenders";
+pub const BASE_URI: &str = "base_uri";
+pub const BURN_FILTER_CONTRACT: &str = "burn_filter_contract";
+pub const BURN_FILTER_CONTRACT_METHOD: &str = "can_burn";
 pub const BURN_MODE: &str = "burn_mode";
+pub const BURNERS: &str = "burners";
 pub const BURNT_TOKENS: &str = "burnt_tokens";
+pub const BURNT_TOKENS_COUNT: &str = "burnt_tokens_count";
+pub const CIRCULATING_SUPPLY: &str = "circulating_supply";
+pub const COLLECTION_METADATA: &str = "collection_metadata";
 pub const COLLECTION_NAME: &str = "collection_name";
+pub const COLLECTION_STATS_TRACKED: &str = "collection_stats_tracked";
 pub const COLLECTION_SYMBOL: &str = "collection_symbol";
+pub const CONFIG: &str = "config";
 pub const CONTRACT_WHITELIST: &str = "contract_whitelist";
 pub const EVENT_TYPE: &str = "event_type";
 pub const EVENTS: &str = "events";
 pub const EVENTS_MODE: &str = "events_mode";
+pub const FROZEN_METADATA: &str = "frozen_metadata";
 pub const HASH_BY_INDEX: &str = "hash_by_index";
+pub const HOLDER_COUNT: &str = "holder_count";
 pub const HOLDER_MODE: &str = "holder_mode";
 pub const IDENTIFIER_MODE: &str = "identifier_mode";
 pub const INDEX_BY_HASH: &str = "index_by_hash";
 pub const INSTALLER: &str = "installer";
 pub const JSON_SCHEMA: &str = "json_schema";
 pub const METADATA_CEP78: &str = "metadata_cep78";
+pub const METADATA_COMPACT: &str = "metadata_compact";
 pub const METADATA_CUSTOM_VALIDATED: &str = "metadata_custom_validated";
+pub const METADATA_EDITORS: &str = "metadata_editors";
 pub const METADATA_MUTABILITY: &str = "metadata_mutability";
 pub const METADATA_NFT721: &str = "metadata_nft721";
 pub const METADATA_RAW: &str = "metadata_raw";
+pub const METADATA_STORAGE_MODE: &str = "metadata_storage_mode";
 pub const MIGRATION_FLAG: &str = "migration_flag";
+pub const MINT_FILTER_CONTRACT: &str = "mint_filter_contract";
+pub const MINT_FILTER_CONTRACT_METHOD: &str = "can_mint";
 pub const MINTING_MODE: &str = "minting_mode";
+pub const NEW_ADMIN: &str = "new_admin";
 pub const NFT_KIND: &str = "nft_kind";
 pub const NFT_METADATA_KIND: &str = "nft_metadata_kind";
 pub const NFT_METADATA_KINDS: &str = "nft_metadata_kinds";
+pub const NON_TRANSFERABLE_TOKENS: &str = "non_transferable_tokens";
 pub const NUMBER_OF_MINTED_TOKENS: &str = "number_of_minted_tokens";
 pub const OPERATOR: &str = "operator";
+pub const OPERATOR_EXPIRIES: &str = "operator_expiries";
 pub const OPERATORS: &str = "operators";
 pub const OPERATOR_BURN_MODE: &str = "operator_burn_mode";
 pub const OWNED_TOKENS: &str = "owned_tokens";
@@ -99,26 +172,62 @@ pub const OWNER: &str = "owner";
 pub const BURNER: &str = "burner";
 pub const OWNERSHIP_MODE: &str = "ownership_mode";
 pub const PACKAGE_OPERATOR_MODE: &str = "package_operator_mode";
+pub const PAGE_ENCODING: &str = "page_encoding";
 pub const PAGE_LIMIT: &str = "page_limit";
+pub const PAGE_SIZE: &str = "page_size";
 pub const PAGE_TABLE: &str = "page_table";
+pub const PAUSERS: &str = "pausers";
+pub const PENDING_ADMIN: &str = "pending_admin";
+pub const PENDING_PACKAGE_ACCESS: &str = "pending_package_access";
+pub const PREVIOUS_ADMIN: &str = "previous_admin";
 pub const RECEIPT_NAME: &str = "receipt_name";
+pub const RECEIVER_CONTRACT_METHOD: &str = "on_cep78_received";
 pub const RECIPIENT: &str = "recipient";
 pub const REPORTING_MODE: &str = "reporting_mode";
 pub const RLO_MFLAG: &str = "rlo_mflag";
+pub const ROYALTY_BASIS_POINTS: &str = "royalty_basis_points";
+pub const ROYALTY_RECEIVER: &str = "royalty_receiver";
 pub const SENDER: &str = "sender";
 pub const SPENDER: &str = "spender";
 pub const TOKEN_COUNT: &str = "balances";
 pub const TOKEN_ID: &str = "token_id";
 pub const TOKEN_ISSUERS: &str = "token_issuers";
 pub const TOKEN_OWNERS: &str = "token_owners";
+pub const TOKEN_ROYALTIES: &str = "token_royalties";
 pub const TOTAL_TOKEN_SUPPLY: &str = "total_token_supply";
 pub const TRANSFER_FILTER_CONTRACT: &str = "transfer_filter_contract";
 pub const TRANSFER_FILTER_CONTRACT_METHOD: &str = "can_transfer";
+pub const TRANSFER_FILTER_CONTRACT_METHOD_V2: &str = "can_transfer_v2";
+pub const TRANSFER_FILTER_CONTRACT_VERSION: &str = "transfer_filter_contract_version";
 pub const UNMATCHED_HASH_COUNT: &str = "unmatched_hash_count";
 pub const WHITELIST_MODE: &str = "whitelist_mode";
 
-// The cap on the amount of tokens within a given CEP-78 collection.
-pub const MAX_TOTAL_TOKEN_SUPPLY: u64 = 1_000_000u64;
+// Placeholder of a base URI replaced by the token id or hash.
+pub const TOKEN_URI_ID_PLACEHOLDER: &str = "{id}";
+
+// A deny reason returned by a mint, burn or v2 transfer filter contract is reverted with as a user
+// error offset by this value, keeping it apart from the NFTCoreError codes.
+pub const FILTER_CONTRACT_DENY_REASON_OFFSET: u16 = 1_000u16;
+
+// A contract receiving a token through safe_transfer or a safe mint acknowledges it by returning
+// this value from its receiver method.
+pub const RECEIVER_CONTRACT_ACKNOWLEDGMENT: &str = "on_cep78_received";
+
+// Royalties are expressed in basis points of the sale price, 10_000 being the whole price.
+pub const MAX_ROYALTY_BASIS_POINTS: u64 = 10_000u64;
+
+// Leading byte of a bitmap encoded page record. Bool list records only hold 0 and 1 bytes, which
+// keeps both formats apart when a record is read.
+pub const PAGE_BITMAP_HEADER: u8 = 0xff;
+
+// Each page of the owner reverse lookup tracks this many tokens unless another page size is set at
+// install. Smaller pages make each write cheaper, larger ones need fewer page dictionaries.
+pub const DEFAULT_PAGE_SIZE: u64 = 1_000u64;
+pub const MIN_PAGE_SIZE: u64 = 8u64;
+pub const MAX_PAGE_SIZE: u64 = 10_000u64;
+// The cap on the number of pages a total token supply spans, which bounds the width of the owner
+// page tables written on every mint and transfer.
+pub const MAX_PAGE_LIMIT: u64 = 10_000u64;
 
 pub const ACCESS_KEY_NAME_1_0_0: &str = "nft_contract_package_access";
 pub const HASH_KEY_NAME_1_0_0: &str = "nft_contract_package";
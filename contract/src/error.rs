@@ -173,6 +173,69 @@ pub enum NFTCoreError {
     MissingOperatorBurnMode = 168,
     InvalidIdentifier = 169,
     DuplicateIdentifier = 170,
+    EmptyTokenBatch = 171,
+    MismatchedTokenBatch = 172,
+    MissingRoyaltyReceiver = 173,
+    InvalidRoyaltyReceiver = 174,
+    MissingRoyaltyBasisPoints = 175,
+    InvalidRoyaltyBasisPoints = 176,
+    MissingSalePrice = 177,
+    InvalidSalePrice = 178,
+    InvalidNonTransferable = 179,
+    NonTransferableToken = 180,
+    MissingAdmin = 181,
+    InvalidAdmin = 182,
+    MissingPendingAdmin = 183,
+    InvalidPendingAdmin = 184,
+    MissingRole = 185,
+    InvalidRole = 186,
+    MissingRoleHolder = 187,
+    InvalidRoleHolder = 188,
+    InvalidMetadataEditor = 189,
+    FrozenMetadata = 190,
+    MissingBaseUri = 191,
+    InvalidBaseUri = 192,
+    MissingCollectionMetadata = 193,
+    InvalidCollectionMetadata = 194,
+    InvalidExpiresAt = 195,
+    MissingTransferFilterContractVersion = 196,
+    InvalidTransferFilterContractVersion = 197,
+    InvalidTransferData = 198,
+    MissingMintFilterContract = 199,
+    InvalidMintFilterContract = 200,
+    MissingBurnFilterContract = 201,
+    InvalidBurnFilterContract = 202,
+    InvalidSafe = 203,
+    UnacknowledgedTokenReceipt = 204,
+    OwnerEnumerationNeedsReverseLookup = 205,
+    MissingOffset = 206,
+    InvalidOffset = 207,
+    MissingLimit = 208,
+    InvalidLimit = 209,
+    MissingIndex = 210,
+    InvalidIndex = 211,
+    OwnerTokenIndexOutOfBounds = 212,
+    MissingBurntTokensCount = 213,
+    InvalidBurntTokensCount = 214,
+    MissingCirculatingSupply = 215,
+    InvalidCirculatingSupply = 216,
+    MissingHolderCount = 217,
+    InvalidHolderCount = 218,
+    MissingPageEncoding = 219,
+    InvalidPageEncoding = 220,
+    MissingPageSize = 221,
+    InvalidPageSize = 222,
+    MissingConfig = 223,
+    InvalidConfig = 224,
+    MissingMetadataStorageMode = 225,
+    InvalidMetadataStorageMode = 226,
+    MissingCollectionStatsTracked = 227,
+    InvalidCollectionStatsTracked = 228,
+    UntrackedCollectionStats = 229,
+    CollectionStatsUnderflow = 230,
+    MissingTokenIssuer = 231,
+    CannotUpgradeToLessSupply = 232,
+    InvalidPackageAccessUref = 233,
 }
 
 impl From<NFTCoreError> for ApiError {
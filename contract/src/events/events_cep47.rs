@@ -12,8 +12,8 @@ use casper_types::Key;
 
 use crate::{
     constants::{
-        BURNER, EVENTS, EVENT_TYPE, OPERATOR, OWNER, PREFIX_CEP78, PREFIX_HASH_KEY_NAME, RECIPIENT,
-        SENDER, SPENDER, TOKEN_ID,
+        ADMIN, BURNER, EVENTS, EVENT_TYPE, NEW_ADMIN, OPERATOR, OWNER, PENDING_ADMIN, PREFIX_CEP78,
+        PREFIX_HASH_KEY_NAME, PREVIOUS_ADMIN, RECIPIENT, SENDER, SPENDER, TOKEN_ID,
     },
     error::NFTCoreError,
     modalities::TokenIdentifier,
@@ -55,8 +55,20 @@ pub enum CEP47Event {
     MetadataUpdate {
         token_id: TokenIdentifier,
     },
+    MetadataFreeze {
+        token_id: TokenIdentifier,
+    },
+    CollectionMetadataUpdate,
     VariablesSet,
     Migrate,
+    AdminTransferStarted {
+        admin: Key,
+        pending_admin: Key,
+    },
+    AdminTransferred {
+        previous_admin: Key,
+        new_admin: Key,
+    },
 }
 
 pub fn record_cep47_event_dictionary(event: CEP47Event) {
@@ -154,6 +166,19 @@ pub fn record_cep47_event_dictionary(event: CEP47Event) {
             event.insert(TOKEN_ID, token_id.to_string());
             event
         }
+        CEP47Event::MetadataFreeze { token_id } => {
+            let mut event = BTreeMap::new();
+            event.insert(PREFIX_HASH_KEY_NAME, package);
+            event.insert(EVENT_TYPE, "MetadataFreeze".to_string());
+            event.insert(TOKEN_ID, token_id.to_string());
+            event
+        }
+        CEP47Event::CollectionMetadataUpdate => {
+            let mut event = BTreeMap::new();
+            event.insert(PREFIX_HASH_KEY_NAME, package);
+            event.insert(EVENT_TYPE, "CollectionMetadataUpdate".to_string());
+            event
+        }
         CEP47Event::Migrate => {
             let mut event = BTreeMap::new();
             event.insert(PREFIX_HASH_KEY_NAME, package);
@@ -166,6 +191,28 @@ pub fn record_cep47_event_dictionary(event: CEP47Event) {
             event.insert(EVENT_TYPE, "VariablesSet".to_string());
             event
         }
+        CEP47Event::AdminTransferStarted {
+            admin,
+            pending_admin,
+        } => {
+            let mut event = BTreeMap::new();
+            event.insert(PREFIX_HASH_KEY_NAME, package);
+            event.insert(EVENT_TYPE, "AdminTransferStarted".to_string());
+            event.insert(ADMIN, admin.to_string());
+            event.insert(PENDING_ADMIN, pending_admin.to_string());
+            event
+        }
+        CEP47Event::AdminTransferred {
+            previous_admin,
+            new_admin,
+        } => {
+            let mut event = BTreeMap::new();
+            event.insert(PREFIX_HASH_KEY_NAME, package);
+            event.insert(EVENT_TYPE, "AdminTransferred".to_string());
+            event.insert(PREVIOUS_ADMIN, previous_admin.to_string());
+            event.insert(NEW_ADMIN, new_admin.to_string());
+            event
+        }
     };
     let dictionary_uref = match runtime::get_key(EVENTS) {
         Some(dict_uref) => dict_uref.into_uref().unwrap_or_revert(),
@@ -134,6 +134,30 @@ impl MetadataUpdated {
     }
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MetadataFrozen {
+    token_id: String,
+}
+
+impl MetadataFrozen {
+    pub fn new(token_id: TokenIdentifier) -> Self {
+        Self {
+            token_id: token_id.to_string(),
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct CollectionMetadataUpdated {
+    data: String,
+}
+
+impl CollectionMetadataUpdated {
+    pub fn new(data: String) -> Self {
+        Self { data }
+    }
+}
+
 #[derive(Event, Debug, PartialEq, Eq, Default)]
 pub struct VariablesSet {}
 
@@ -143,6 +167,36 @@ impl VariablesSet {
     }
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AdminTransferStarted {
+    admin: Key,
+    pending_admin: Key,
+}
+
+impl AdminTransferStarted {
+    pub fn new(admin: Key, pending_admin: Key) -> Self {
+        Self {
+            admin,
+            pending_admin,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AdminTransferred {
+    previous_admin: Key,
+    new_admin: Key,
+}
+
+impl AdminTransferred {
+    pub fn new(previous_admin: Key, new_admin: Key) -> Self {
+        Self {
+            previous_admin,
+            new_admin,
+        }
+    }
+}
+
 #[derive(Event, Debug, PartialEq, Eq, Default)]
 pub struct Migration {}
 
@@ -15,7 +15,7 @@ extern crate alloc;
 
 use alloc::{
     boxed::Box,
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     format,
     string::{String, ToString},
     vec,
@@ -29,51 +29,75 @@ use casper_contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    contracts::NamedKeys, runtime_args, CLType, CLValue, ContractHash, ContractPackageHash,
-    EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, KeyTag, Parameter, RuntimeArgs,
-    Tagged,
+    bytesrepr::Bytes, contracts::NamedKeys, runtime_args, ApiError, CLType, CLValue, ContractHash,
+    ContractPackageHash, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, KeyTag,
+    Parameter, RuntimeArgs, Tagged, URef, U512,
 };
 use constants::{
-    ACCESS_KEY_NAME_1_0_0, ACL_PACKAGE_MODE, ACL_WHITELIST, ALLOW_MINTING, APPROVED,
-    ARG_ACCESS_KEY_NAME_1_0_0, ARG_ACL_PACKAGE_MODE, ARG_ACL_WHITELIST,
-    ARG_ADDITIONAL_REQUIRED_METADATA, ARG_ALLOW_MINTING, ARG_APPROVE_ALL, ARG_BURN_MODE,
-    ARG_COLLECTION_NAME, ARG_COLLECTION_SYMBOL, ARG_CONTRACT_WHITELIST, ARG_EVENTS_MODE,
-    ARG_HASH_KEY_NAME_1_0_0, ARG_HOLDER_MODE, ARG_IDENTIFIER_MODE, ARG_JSON_SCHEMA,
-    ARG_METADATA_MUTABILITY, ARG_MINTING_MODE, ARG_NAMED_KEY_CONVENTION, ARG_NFT_KIND,
-    ARG_NFT_METADATA_KIND, ARG_NFT_PACKAGE_KEY, ARG_OPERATOR, ARG_OPERATOR_BURN_MODE,
-    ARG_OPTIONAL_METADATA, ARG_OWNERSHIP_MODE, ARG_OWNER_LOOKUP_MODE, ARG_PACKAGE_OPERATOR_MODE,
-    ARG_RECEIPT_NAME, ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID,
-    ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ARG_TOTAL_TOKEN_SUPPLY, ARG_TRANSFER_FILTER_CONTRACT,
-    ARG_WHITELIST_MODE, BURNT_TOKENS, BURN_MODE, COLLECTION_NAME, COLLECTION_SYMBOL,
-    ENTRY_POINT_APPROVE, ENTRY_POINT_BALANCE_OF, ENTRY_POINT_BURN, ENTRY_POINT_GET_APPROVED,
-    ENTRY_POINT_INIT, ENTRY_POINT_IS_APPROVED_FOR_ALL, ENTRY_POINT_METADATA, ENTRY_POINT_MIGRATE,
-    ENTRY_POINT_MINT, ENTRY_POINT_OWNER_OF, ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_REVOKE,
-    ENTRY_POINT_SET_APPROVALL_FOR_ALL, ENTRY_POINT_SET_TOKEN_METADATA, ENTRY_POINT_SET_VARIABLES,
-    ENTRY_POINT_TRANSFER, ENTRY_POINT_UPDATED_RECEIPTS, EVENTS_MODE, HASH_BY_INDEX,
-    HASH_KEY_NAME_1_0_0, HOLDER_MODE, IDENTIFIER_MODE, INDEX_BY_HASH, INSTALLER, JSON_SCHEMA,
-    MAX_TOTAL_TOKEN_SUPPLY, METADATA_CEP78, METADATA_CUSTOM_VALIDATED, METADATA_MUTABILITY,
-    METADATA_NFT721, METADATA_RAW, MINTING_MODE, NFT_KIND, NFT_METADATA_KIND, NFT_METADATA_KINDS,
-    NUMBER_OF_MINTED_TOKENS, OPERATOR, OPERATORS, OPERATOR_BURN_MODE, OWNED_TOKENS, OWNERSHIP_MODE,
-    PACKAGE_OPERATOR_MODE, PAGE_LIMIT, PAGE_TABLE, PREFIX_ACCESS_KEY_NAME, PREFIX_CEP78,
-    PREFIX_CONTRACT_NAME, PREFIX_CONTRACT_VERSION, PREFIX_HASH_KEY_NAME, PREFIX_PAGE_DICTIONARY,
-    RECEIPT_NAME, REPORTING_MODE, RLO_MFLAG, TOKEN_COUNT, TOKEN_ISSUERS, TOKEN_OWNERS,
+    ACCESS_KEY_NAME_1_0_0, ACL_PACKAGE_MODE, ACL_WHITELIST, ALLOW_MINTING, APPROVAL_EXPIRIES,
+    APPROVED, APPROVED_SPENDERS, ARG_ACCESS_KEY_NAME_1_0_0, ARG_ACL_PACKAGE_MODE,
+    ARG_ACL_WHITELIST, ARG_ADDITIONAL_REQUIRED_METADATA, ARG_ADMIN, ARG_ALLOW_MINTING,
+    ARG_APPROVE_ALL, ARG_BASE_URI, ARG_BURNT_TOKENS_COUNT, ARG_BURN_FILTER_CONTRACT, ARG_BURN_MODE,
+    ARG_CALLER, ARG_COLLECTION_METADATA, ARG_COLLECTION_NAME, ARG_COLLECTION_SYMBOL,
+    ARG_CONTRACT_WHITELIST, ARG_DATA, ARG_EVENTS_MODE, ARG_FROM, ARG_HASH_KEY_NAME_1_0_0,
+    ARG_HOLDER_COUNT, ARG_HOLDER_MODE, ARG_IDENTIFIER_MODE, ARG_INDEX, ARG_ISSUER, ARG_JSON_SCHEMA,
+    ARG_LIMIT, ARG_METADATA_MUTABILITY, ARG_METADATA_STORAGE_MODE, ARG_MINTING_MODE,
+    ARG_MINT_FILTER_CONTRACT, ARG_NAMED_KEY_CONVENTION, ARG_NFT_KIND, ARG_NFT_METADATA_KIND,
+    ARG_NFT_PACKAGE_KEY, ARG_NON_TRANSFERABLE, ARG_OFFSET, ARG_OPERATOR, ARG_OPERATOR_BURN_MODE,
+    ARG_OPTIONAL_METADATA, ARG_OWNERSHIP_MODE, ARG_OWNER_LOOKUP_MODE, ARG_PACKAGE_ACCESS_UREF,
+    ARG_PACKAGE_OPERATOR_MODE, ARG_PAGE_ENCODING, ARG_PAGE_SIZE, ARG_RECEIPT_NAME, ARG_ROLE,
+    ARG_ROLE_HOLDER, ARG_ROYALTY_BASIS_POINTS, ARG_ROYALTY_RECEIVER, ARG_SAFE, ARG_SALE_PRICE,
+    ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_HASHES, ARG_TOKEN_ID,
+    ARG_TOKEN_META_DATA, ARG_TOKEN_META_DATAS, ARG_TOKEN_OWNER, ARG_TOKEN_OWNERS,
+    ARG_TOTAL_TOKEN_SUPPLY, ARG_TRANSFER_FILTER_CONTRACT, ARG_TRANSFER_FILTER_CONTRACT_VERSION,
+    ARG_WHITELIST_MODE, BASE_URI, BURNERS, BURNT_TOKENS, BURNT_TOKENS_COUNT, BURN_FILTER_CONTRACT,
+    BURN_FILTER_CONTRACT_METHOD, BURN_MODE, CIRCULATING_SUPPLY, COLLECTION_METADATA,
+    COLLECTION_NAME, COLLECTION_STATS_TRACKED, COLLECTION_SYMBOL, DEFAULT_PAGE_SIZE,
+    ENTRY_POINT_ACCEPT_ADMIN, ENTRY_POINT_APPROVE, ENTRY_POINT_APPROVE_BATCH,
+    ENTRY_POINT_BALANCE_OF, ENTRY_POINT_BURN, ENTRY_POINT_BURN_BATCH,
+    ENTRY_POINT_COLLECTION_METADATA, ENTRY_POINT_COLLECTION_STATS, ENTRY_POINT_FREEZE_METADATA,
+    ENTRY_POINT_GET_APPROVED, ENTRY_POINT_GRANT_ROLE, ENTRY_POINT_HAS_ROLE, ENTRY_POINT_INIT,
+    ENTRY_POINT_IS_APPROVED_FOR_ALL, ENTRY_POINT_IS_NON_TRANSFERABLE, ENTRY_POINT_METADATA,
+    ENTRY_POINT_MIGRATE, ENTRY_POINT_MINT, ENTRY_POINT_MINT_BATCH, ENTRY_POINT_OWNER_OF,
+    ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_REVOKE, ENTRY_POINT_REVOKE_ROLE,
+    ENTRY_POINT_ROYALTY_INFO, ENTRY_POINT_SAFE_TRANSFER, ENTRY_POINT_SET_APPROVALL_FOR_ALL,
+    ENTRY_POINT_SET_BASE_URI, ENTRY_POINT_SET_COLLECTION_METADATA, ENTRY_POINT_SET_TOKEN_METADATA,
+    ENTRY_POINT_SET_VARIABLES, ENTRY_POINT_TOKENS_OF_OWNER, ENTRY_POINT_TOKEN_OF_OWNER_BY_INDEX,
+    ENTRY_POINT_TOKEN_URI, ENTRY_POINT_TRANSFER, ENTRY_POINT_TRANSFER_ADMIN,
+    ENTRY_POINT_TRANSFER_BATCH, ENTRY_POINT_UPDATED_RECEIPTS, EVENTS_MODE,
+    FILTER_CONTRACT_DENY_REASON_OFFSET, FROZEN_METADATA, HASH_BY_INDEX, HASH_KEY_NAME_1_0_0,
+    HOLDER_COUNT, HOLDER_MODE, IDENTIFIER_MODE, INDEX_BY_HASH, INSTALLER, JSON_SCHEMA,
+    MAX_PAGE_SIZE, MAX_ROYALTY_BASIS_POINTS, METADATA_CEP78, METADATA_COMPACT,
+    METADATA_CUSTOM_VALIDATED, METADATA_EDITORS, METADATA_MUTABILITY, METADATA_NFT721,
+    METADATA_RAW, METADATA_STORAGE_MODE, MINTING_MODE, MINT_FILTER_CONTRACT,
+    MINT_FILTER_CONTRACT_METHOD, MIN_PAGE_SIZE, NFT_KIND, NFT_METADATA_KIND, NFT_METADATA_KINDS,
+    NON_TRANSFERABLE_TOKENS, NUMBER_OF_MINTED_TOKENS, OPERATOR, OPERATORS, OPERATOR_BURN_MODE,
+    OPERATOR_EXPIRIES, OWNED_TOKENS, OWNERSHIP_MODE, PACKAGE_OPERATOR_MODE, PAGE_ENCODING,
+    PAGE_LIMIT, PAGE_SIZE, PAGE_TABLE, PAUSERS, PENDING_ADMIN, PENDING_PACKAGE_ACCESS,
+    PREFIX_ACCESS_KEY_NAME, PREFIX_CEP78, PREFIX_CONTRACT_NAME, PREFIX_CONTRACT_VERSION,
+    PREFIX_HASH_KEY_NAME, PREFIX_PAGE_DICTIONARY, RECEIPT_NAME, RECEIVER_CONTRACT_ACKNOWLEDGMENT,
+    RECEIVER_CONTRACT_METHOD, REPORTING_MODE, RLO_MFLAG, ROYALTY_BASIS_POINTS, ROYALTY_RECEIVER,
+    TOKEN_COUNT, TOKEN_ISSUERS, TOKEN_OWNERS, TOKEN_ROYALTIES, TOKEN_URI_ID_PLACEHOLDER,
     TOTAL_TOKEN_SUPPLY, TRANSFER_FILTER_CONTRACT, TRANSFER_FILTER_CONTRACT_METHOD,
-    UNMATCHED_HASH_COUNT, WHITELIST_MODE,
+    TRANSFER_FILTER_CONTRACT_METHOD_V2, TRANSFER_FILTER_CONTRACT_VERSION, UNMATCHED_HASH_COUNT,
+    WHITELIST_MODE,
 };
 use core::convert::{TryFrom, TryInto};
 use error::NFTCoreError;
 use events::{
     events_cep47::{record_cep47_event_dictionary, CEP47Event},
     events_ces::{
-        Approval, ApprovalForAll, ApprovalRevoked, Burn, MetadataUpdated, Migration, Mint,
-        RevokedForAll, Transfer, VariablesSet,
+        AdminTransferStarted, AdminTransferred, Approval, ApprovalForAll, ApprovalRevoked, Burn,
+        CollectionMetadataUpdated, MetadataFrozen, MetadataUpdated, Migration, Mint, RevokedForAll,
+        Transfer, VariablesSet,
     },
 };
 use metadata::CustomMetadataSchema;
 use modalities::{
-    BurnMode, EventsMode, MetadataMutability, MintingMode, NFTHolderMode, NFTIdentifierMode,
-    NFTKind, NFTMetadataKind, NamedKeyConventionMode, OwnerReverseLookupMode, OwnershipMode,
-    Requirement, TokenIdentifier, TransferFilterContractResult, WhitelistMode,
+    BurnMode, Config, EventsMode, MetadataMutability, MetadataStorageMode, MintingMode,
+    NFTHolderMode, NFTIdentifierMode, NFTKind, NFTMetadataKind, NamedKeyConventionMode,
+    OwnerReverseLookupMode, OwnershipMode, PageEncoding, Requirement, Role, TokenIdentifier,
+    TransferFilterContractResult, TransferFilterContractVersion, WhitelistMode,
 };
 use utils::Caller;
 
@@ -120,10 +144,6 @@ pub extern "C" fn init() {
     )
     .unwrap_or_revert();
 
-    if total_token_supply > MAX_TOTAL_TOKEN_SUPPLY {
-        runtime::revert(NFTCoreError::ExceededMaxTotalSupply)
-    }
-
     let allow_minting: bool = utils::get_named_arg_with_user_errors(
         ARG_ALLOW_MINTING,
         NFTCoreError::MissingMintingStatus,
@@ -272,6 +292,7 @@ pub extern "C" fn init() {
         {
             serde_json_wasm::from_str::<CustomMetadataSchema>(&json_schema)
                 .map_err(|_| NFTCoreError::InvalidJsonSchema)
+                .and_then(|schema| schema.validate())
                 .unwrap_or_revert();
         }
     }
@@ -347,6 +368,103 @@ pub extern "C" fn init() {
         runtime::revert(NFTCoreError::TransferFilterContractNeedsTransferableMode)
     }
 
+    // Mint and burn filter contracts are consulted before any token is minted or burnt.
+    let mint_filter_contract: Option<ContractHash> =
+        utils::get_optional_named_arg_with_user_errors::<Option<Key>>(
+            ARG_MINT_FILTER_CONTRACT,
+            NFTCoreError::InvalidMintFilterContract,
+        )
+        .unwrap_or_default()
+        .map(|mint_filter_contract_key| {
+            utils::get_filter_contract_hash(
+                mint_filter_contract_key,
+                NFTCoreError::InvalidMintFilterContract,
+            )
+        });
+
+    let burn_filter_contract: Option<ContractHash> =
+        utils::get_optional_named_arg_with_user_errors::<Option<Key>>(
+            ARG_BURN_FILTER_CONTRACT,
+            NFTCoreError::InvalidBurnFilterContract,
+        )
+        .unwrap_or_default()
+        .map(|burn_filter_contract_key| {
+            utils::get_filter_contract_hash(
+                burn_filter_contract_key,
+                NFTCoreError::InvalidBurnFilterContract,
+            )
+        });
+
+    let transfer_filter_contract_version: TransferFilterContractVersion =
+        utils::get_optional_named_arg_with_user_errors::<u8>(
+            ARG_TRANSFER_FILTER_CONTRACT_VERSION,
+            NFTCoreError::InvalidTransferFilterContractVersion,
+        )
+        .unwrap_or(TransferFilterContractVersion::V1 as u8)
+        .try_into()
+        .unwrap_or_revert();
+
+    let page_encoding: PageEncoding = utils::get_optional_named_arg_with_user_errors::<u8>(
+        ARG_PAGE_ENCODING,
+        NFTCoreError::InvalidPageEncoding,
+    )
+    .unwrap_or(PageEncoding::Bitmap as u8)
+    .try_into()
+    .unwrap_or_revert();
+
+    let page_size: u64 = utils::get_optional_named_arg_with_user_errors(
+        ARG_PAGE_SIZE,
+        NFTCoreError::InvalidPageSize,
+    )
+    .unwrap_or(DEFAULT_PAGE_SIZE);
+
+    if !(MIN_PAGE_SIZE..=MAX_PAGE_SIZE).contains(&page_size) {
+        runtime::revert(NFTCoreError::InvalidPageSize)
+    }
+
+    utils::check_total_token_supply(total_token_supply, page_size);
+
+    let metadata_storage_mode: MetadataStorageMode =
+        utils::get_optional_named_arg_with_user_errors::<u8>(
+            ARG_METADATA_STORAGE_MODE,
+            NFTCoreError::InvalidMetadataStorageMode,
+        )
+        .unwrap_or(MetadataStorageMode::PerKind as u8)
+        .try_into()
+        .unwrap_or_revert();
+
+    // The installer receives the royalties unless another default receiver is provided.
+    let royalty_receiver: Key = utils::get_optional_named_arg_with_user_errors::<Option<Key>>(
+        ARG_ROYALTY_RECEIVER,
+        NFTCoreError::InvalidRoyaltyReceiver,
+    )
+    .unwrap_or_default()
+    .unwrap_or(Key::Account(installing_account));
+
+    let royalty_basis_points: u64 = utils::get_optional_named_arg_with_user_errors(
+        ARG_ROYALTY_BASIS_POINTS,
+        NFTCoreError::InvalidRoyaltyBasisPoints,
+    )
+    .unwrap_or_default();
+
+    if royalty_basis_points > MAX_ROYALTY_BASIS_POINTS {
+        runtime::revert(NFTCoreError::InvalidRoyaltyBasisPoints)
+    }
+
+    let base_uri: String =
+        utils::get_optional_named_arg_with_user_errors(ARG_BASE_URI, NFTCoreError::InvalidBaseUri)
+            .unwrap_or_default();
+
+    let collection_metadata: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_COLLECTION_METADATA,
+        NFTCoreError::InvalidCollectionMetadata,
+    )
+    .unwrap_or_default();
+
+    if !collection_metadata.is_empty() {
+        metadata::validate_collection_metadata(&collection_metadata).unwrap_or_revert();
+    }
+
     // Put all created URefs into the contract's context (necessary to retain access rights,
     // for future use).
     //
@@ -386,7 +504,7 @@ pub extern "C" fn init() {
     );
     runtime::put_key(
         NFT_METADATA_KINDS,
-        storage::new_uref(nft_metadata_kinds).into(),
+        storage::new_uref(nft_metadata_kinds.clone()).into(),
     );
     runtime::put_key(
         IDENTIFIER_MODE,
@@ -401,6 +519,17 @@ pub extern "C" fn init() {
         OPERATOR_BURN_MODE,
         storage::new_uref(operator_burn_mode).into(),
     );
+    runtime::put_key(ROYALTY_RECEIVER, storage::new_uref(royalty_receiver).into());
+    runtime::put_key(PENDING_ADMIN, storage::new_uref(None::<Key>).into());
+    runtime::put_key(
+        ROYALTY_BASIS_POINTS,
+        storage::new_uref(royalty_basis_points).into(),
+    );
+    runtime::put_key(BASE_URI, storage::new_uref(base_uri).into());
+    runtime::put_key(
+        COLLECTION_METADATA,
+        storage::new_uref(collection_metadata).into(),
+    );
 
     let events_mode: EventsMode = utils::get_named_arg_with_user_errors::<u8>(
         ARG_EVENTS_MODE,
@@ -423,6 +552,11 @@ pub extern "C" fn init() {
     // This is an internal variable that the installing account cannot change
     // but is incremented by the contract itself.
     runtime::put_key(NUMBER_OF_MINTED_TOKENS, storage::new_uref(0u64).into());
+    // Collection statistics are likewise maintained by the contract on mint, burn and transfer.
+    runtime::put_key(BURNT_TOKENS_COUNT, storage::new_uref(0u64).into());
+    runtime::put_key(CIRCULATING_SUPPLY, storage::new_uref(0u64).into());
+    runtime::put_key(HOLDER_COUNT, storage::new_uref(0u64).into());
+    runtime::put_key(COLLECTION_STATS_TRACKED, storage::new_uref(true).into());
 
     // Create the data dictionaries to store essential values, topically.
     storage::new_dictionary(TOKEN_OWNERS)
@@ -430,20 +564,34 @@ pub extern "C" fn init() {
     storage::new_dictionary(TOKEN_ISSUERS)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(APPROVED).unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
-    storage::new_dictionary(OPERATORS)
-        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
-    storage::new_dictionary(BURNT_TOKENS)
+    storage::new_dictionary(APPROVED_SPENDERS)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
-    storage::new_dictionary(TOKEN_COUNT)
+    storage::new_dictionary(OPERATORS)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
-    storage::new_dictionary(METADATA_CUSTOM_VALIDATED)
+    storage::new_dictionary(APPROVAL_EXPIRIES)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
-    storage::new_dictionary(METADATA_CEP78)
+    storage::new_dictionary(OPERATOR_EXPIRIES)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
-    storage::new_dictionary(METADATA_NFT721)
+    storage::new_dictionary(BURNT_TOKENS)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
-    storage::new_dictionary(METADATA_RAW)
+    storage::new_dictionary(TOKEN_COUNT)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    match metadata_storage_mode {
+        MetadataStorageMode::PerKind => {
+            storage::new_dictionary(METADATA_CUSTOM_VALIDATED)
+                .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+            storage::new_dictionary(METADATA_CEP78)
+                .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+            storage::new_dictionary(METADATA_NFT721)
+                .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+            storage::new_dictionary(METADATA_RAW)
+                .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+        }
+        MetadataStorageMode::Compact => {
+            storage::new_dictionary(METADATA_COMPACT)
+                .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+        }
+    }
     storage::new_dictionary(HASH_BY_INDEX)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(INDEX_BY_HASH)
@@ -452,6 +600,16 @@ pub extern "C" fn init() {
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(ACL_WHITELIST)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(TOKEN_ROYALTIES)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(NON_TRANSFERABLE_TOKENS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(FROZEN_METADATA)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(METADATA_EDITORS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(PAUSERS).unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(BURNERS).unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
 
     for key in acl_whitelist.iter() {
         utils::upsert_dictionary_value_from_key(
@@ -473,9 +631,15 @@ pub extern "C" fn init() {
     ]
     .contains(&reporting_mode)
     {
-        let page_table_width = utils::max_number_of_pages(total_token_supply);
+        let page_table_width = utils::max_number_of_pages(total_token_supply, page_size);
         runtime::put_key(PAGE_LIMIT, storage::new_uref(page_table_width).into());
     }
+    runtime::put_key(PAGE_ENCODING, storage::new_uref(page_encoding as u8).into());
+    runtime::put_key(PAGE_SIZE, storage::new_uref(page_size).into());
+    runtime::put_key(
+        METADATA_STORAGE_MODE,
+        storage::new_uref(metadata_storage_mode as u8).into(),
+    );
     runtime::put_key(
         REPORTING_MODE,
         storage::new_uref(reporting_mode as u8).into(),
@@ -488,6 +652,38 @@ pub extern "C" fn init() {
             storage::new_uref(transfer_filter_contract).into(),
         );
     }
+    runtime::put_key(
+        TRANSFER_FILTER_CONTRACT_VERSION,
+        storage::new_uref(transfer_filter_contract_version as u8).into(),
+    );
+    runtime::put_key(
+        MINT_FILTER_CONTRACT,
+        storage::new_uref(mint_filter_contract).into(),
+    );
+    runtime::put_key(
+        BURN_FILTER_CONTRACT,
+        storage::new_uref(burn_filter_contract).into(),
+    );
+
+    // The modalities stored above are also packed into a single record read once by each entry
+    // point.
+    let config = Config {
+        ownership_mode,
+        holder_mode,
+        whitelist_mode,
+        minting_mode,
+        identifier_mode,
+        metadata_mutability,
+        burn_mode,
+        reporting_mode,
+        events_mode,
+        transfer_filter_contract_version,
+        page_encoding,
+        page_size,
+        nft_metadata_kinds,
+        metadata_storage_mode,
+    };
+    utils::put_config(&config);
 }
 
 // set_variables allows the user to set any variable or any combination of variables simultaneously.
@@ -500,11 +696,28 @@ pub extern "C" fn set_variables() {
         NFTCoreError::InvalidInstaller,
     );
 
-    // Only the installing account can change the mutable variables.
+    // Only the installing account can change the mutable variables. Pausers may only toggle
+    // allow_minting.
     if installer != runtime::get_caller() {
-        runtime::revert(NFTCoreError::InvalidAccount);
+        let is_pauser = utils::has_role(Role::Pauser, runtime::get_caller().into());
+        let sets_other_variables = [
+            ARG_ACL_PACKAGE_MODE,
+            ARG_PACKAGE_OPERATOR_MODE,
+            ARG_OPERATOR_BURN_MODE,
+            ARG_ACL_WHITELIST,
+            ARG_CONTRACT_WHITELIST,
+            ARG_MINT_FILTER_CONTRACT,
+            ARG_BURN_FILTER_CONTRACT,
+        ]
+        .iter()
+        .any(|arg_name| utils::get_named_arg_size(arg_name).is_some());
+        if !is_pauser || sets_other_variables {
+            runtime::revert(NFTCoreError::InvalidAccount);
+        }
     }
 
+    let config = utils::get_config();
+
     if let Some(allow_minting) = utils::get_optional_named_arg_with_user_errors::<bool>(
         ARG_ALLOW_MINTING,
         NFTCoreError::InvalidAllowMinting,
@@ -553,6 +766,49 @@ pub extern "C" fn set_variables() {
         storage::write(operator_burn_mode_uref, operator_burn_mode);
     }
 
+    // A filter contract passed as None is removed.
+    if let Some(mint_filter_contract_key) =
+        utils::get_optional_named_arg_with_user_errors::<Option<Key>>(
+            ARG_MINT_FILTER_CONTRACT,
+            NFTCoreError::InvalidMintFilterContract,
+        )
+    {
+        let mint_filter_contract_uref = utils::get_uref(
+            MINT_FILTER_CONTRACT,
+            NFTCoreError::MissingMintFilterContract,
+            NFTCoreError::InvalidMintFilterContract,
+        );
+        let mint_filter_contract: Option<ContractHash> =
+            mint_filter_contract_key.map(|mint_filter_contract_key| {
+                utils::get_filter_contract_hash(
+                    mint_filter_contract_key,
+                    NFTCoreError::InvalidMintFilterContract,
+                )
+            });
+        storage::write(mint_filter_contract_uref, mint_filter_contract);
+    }
+
+    if let Some(burn_filter_contract_key) =
+        utils::get_optional_named_arg_with_user_errors::<Option<Key>>(
+            ARG_BURN_FILTER_CONTRACT,
+            NFTCoreError::InvalidBurnFilterContract,
+        )
+    {
+        let burn_filter_contract_uref = utils::get_uref(
+            BURN_FILTER_CONTRACT,
+            NFTCoreError::MissingBurnFilterContract,
+            NFTCoreError::InvalidBurnFilterContract,
+        );
+        let burn_filter_contract: Option<ContractHash> =
+            burn_filter_contract_key.map(|burn_filter_contract_key| {
+                utils::get_filter_contract_hash(
+                    burn_filter_contract_key,
+                    NFTCoreError::InvalidBurnFilterContract,
+                )
+            });
+        storage::write(burn_filter_contract_uref, burn_filter_contract);
+    }
+
     let mut new_acl_whitelist = utils::get_optional_named_arg_with_user_errors::<Vec<Key>>(
         ARG_ACL_WHITELIST,
         NFTCoreError::InvalidACLWhitelist,
@@ -572,14 +828,7 @@ pub extern "C" fn set_variables() {
     }
 
     if !new_acl_whitelist.is_empty() {
-        let whitelist_mode: WhitelistMode = utils::get_stored_value_with_user_errors::<u8>(
-            WHITELIST_MODE,
-            NFTCoreError::MissingWhitelistMode,
-            NFTCoreError::InvalidWhitelistMode,
-        )
-        .try_into()
-        .unwrap_or_revert();
-        match whitelist_mode {
+        match config.whitelist_mode {
             WhitelistMode::Unlocked => {
                 // Clear acl whitelist
                 runtime::remove_key(ACL_WHITELIST);
@@ -598,131 +847,203 @@ pub extern "C" fn set_variables() {
         }
     }
 
-    let events_mode: EventsMode = utils::get_stored_value_with_user_errors::<u8>(
-        EVENTS_MODE,
-        NFTCoreError::MissingEventsMode,
-        NFTCoreError::InvalidEventsMode,
-    )
-    .try_into()
-    .unwrap_or_revert();
-
     // Emit VariablesSet event.
-    match events_mode {
+    match config.events_mode {
         EventsMode::NoEvents => {}
         EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::VariablesSet),
         EventsMode::CES => casper_event_standard::emit(VariablesSet::new()),
     }
 }
 
-// Mints a new token. Minting will fail if allow_minting is set to false.
+// Starts handing the admin rights held by the installer over to another account. The transfer is
+// only effective once the pending admin calls accept_admin. Calling it again replaces the pending
+// admin.
 #[no_mangle]
-pub extern "C" fn mint() {
-    // The contract owner can toggle the minting behavior on and off over time.
-    // The contract is toggled on by default.
-    let minting_status = utils::get_stored_value_with_user_errors::<bool>(
-        ALLOW_MINTING,
-        NFTCoreError::MissingAllowMinting,
-        NFTCoreError::InvalidAllowMinting,
+pub extern "C" fn transfer_admin() {
+    let admin = utils::get_account_hash(
+        INSTALLER,
+        NFTCoreError::MissingInstaller,
+        NFTCoreError::InvalidInstaller,
     );
 
-    // If contract minting behavior is currently toggled off we revert.
-    if !minting_status {
-        runtime::revert(NFTCoreError::MintingIsPaused);
+    // Only the current admin can hand over its rights.
+    if admin != runtime::get_caller() {
+        runtime::revert(NFTCoreError::InvalidAccount);
     }
 
-    let total_token_supply = utils::get_stored_value_with_user_errors::<u64>(
-        TOTAL_TOKEN_SUPPLY,
-        NFTCoreError::MissingTotalTokenSupply,
-        NFTCoreError::InvalidTotalTokenSupply,
+    let pending_admin: Key = utils::get_named_arg_with_user_errors(
+        ARG_ADMIN,
+        NFTCoreError::MissingAdmin,
+        NFTCoreError::InvalidAdmin,
+    )
+    .unwrap_or_revert();
+
+    // Installer checks compare the caller's account hash, so the admin must be an account.
+    if pending_admin.into_account().is_none() {
+        runtime::revert(NFTCoreError::InvalidAdmin);
+    }
+
+    let pending_admin_uref = utils::get_uref(
+        PENDING_ADMIN,
+        NFTCoreError::MissingPendingAdmin,
+        NFTCoreError::InvalidPendingAdmin,
     );
+    storage::write(pending_admin_uref, Some(pending_admin));
+
+    // The package access URef needed to upgrade the contract can be handed over with the admin
+    // rights. The contract holds it until the pending admin accepts them.
+    runtime::remove_key(PENDING_PACKAGE_ACCESS);
+    if let Some(package_access_uref) = utils::get_optional_named_arg_with_user_errors::<URef>(
+        ARG_PACKAGE_ACCESS_UREF,
+        NFTCoreError::InvalidPackageAccessUref,
+    ) {
+        runtime::put_key(PENDING_PACKAGE_ACCESS, package_access_uref.into());
+    }
 
-    // The minted_tokens_count is the number of minted tokens so far.
-    let minted_tokens_count = utils::get_stored_value_with_user_errors::<u64>(
-        NUMBER_OF_MINTED_TOKENS,
-        NFTCoreError::MissingNumberOfMintedTokens,
-        NFTCoreError::InvalidNumberOfMintedTokens,
+    match utils::get_config().events_mode {
+        EventsMode::NoEvents => {}
+        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::AdminTransferStarted {
+            admin: admin.into(),
+            pending_admin,
+        }),
+        EventsMode::CES => {
+            casper_event_standard::emit(AdminTransferStarted::new(admin.into(), pending_admin))
+        }
+    }
+}
+
+// Completes the transfer started by transfer_admin. The INSTALLER named key then holds the new
+// admin, so every check made against the installer, including the one made on upgrade, now
+// applies to it. Returns the package access URef handed over by the previous admin, if any.
+#[no_mangle]
+pub extern "C" fn accept_admin() {
+    let pending_admin_uref = utils::get_uref(
+        PENDING_ADMIN,
+        NFTCoreError::MissingPendingAdmin,
+        NFTCoreError::InvalidPendingAdmin,
     );
+    let pending_admin: Option<Key> = storage::read(pending_admin_uref)
+        .unwrap_or_revert_with(NFTCoreError::InvalidPendingAdmin)
+        .unwrap_or_revert_with(NFTCoreError::MissingPendingAdmin);
 
-    // Revert if the token supply has been exhausted.
-    if minted_tokens_count >= total_token_supply {
-        runtime::revert(NFTCoreError::TokenSupplyDepleted);
+    let new_admin: Key = runtime::get_caller().into();
+
+    // Only the pending admin can accept the rights.
+    if pending_admin != Some(new_admin) {
+        runtime::revert(NFTCoreError::InvalidAccount);
     }
 
-    let minting_mode: MintingMode = utils::get_stored_value_with_user_errors::<u8>(
-        MINTING_MODE,
-        NFTCoreError::MissingMintingMode,
-        NFTCoreError::InvalidMintingMode,
+    let previous_admin =
+        runtime::get_key(INSTALLER).unwrap_or_revert_with(NFTCoreError::MissingInstallerKey);
+    runtime::put_key(INSTALLER, new_admin);
+    storage::write(pending_admin_uref, None::<Key>);
+
+    let package_access_uref = runtime::get_key(PENDING_PACKAGE_ACCESS).and_then(Key::into_uref);
+    runtime::remove_key(PENDING_PACKAGE_ACCESS);
+
+    match utils::get_config().events_mode {
+        EventsMode::NoEvents => {}
+        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::AdminTransferred {
+            previous_admin,
+            new_admin,
+        }),
+        EventsMode::CES => {
+            casper_event_standard::emit(AdminTransferred::new(previous_admin, new_admin))
+        }
+    }
+
+    runtime::ret(CLValue::from_t(package_access_uref).unwrap_or_revert())
+}
+
+// Grants a role to an account, a contract or, with ACL package mode, a contract package. Granting
+// the minter role adds the key to the ACL whitelist.
+#[no_mangle]
+pub extern "C" fn grant_role() {
+    let (role, role_holder) = get_verified_role_update();
+    utils::upsert_dictionary_value_from_key(
+        utils::get_role_dictionary_name(role),
+        &utils::encode_dictionary_item_key(role_holder),
+        true,
+    );
+}
+
+// Revokes a role previously granted through grant_role.
+#[no_mangle]
+pub extern "C" fn revoke_role() {
+    let (role, role_holder) = get_verified_role_update();
+    utils::upsert_dictionary_value_from_key(
+        utils::get_role_dictionary_name(role),
+        &utils::encode_dictionary_item_key(role_holder),
+        false,
+    );
+}
+
+// Returns whether the role was granted to the given key.
+#[no_mangle]
+pub extern "C" fn has_role() {
+    let role: Role = utils::get_named_arg_with_user_errors::<u8>(
+        ARG_ROLE,
+        NFTCoreError::MissingRole,
+        NFTCoreError::InvalidRole,
     )
+    .unwrap_or_revert()
     .try_into()
     .unwrap_or_revert();
 
-    let (caller, contract_package): (Key, Option<Key>) =
-        match utils::get_verified_caller().unwrap_or_revert() {
-            Caller::Session(account_hash) => (account_hash.into(), None),
-            Caller::StoredCaller(contract_hash, contract_package_hash) => {
-                (contract_hash.into(), Some(contract_package_hash.into()))
-            }
-        };
+    let role_holder: Key = utils::get_named_arg_with_user_errors(
+        ARG_ROLE_HOLDER,
+        NFTCoreError::MissingRoleHolder,
+        NFTCoreError::InvalidRoleHolder,
+    )
+    .unwrap_or_revert();
 
-    // Revert if minting is private and caller is not installer.
-    if MintingMode::Installer == minting_mode {
-        match caller.tag() {
-            KeyTag::Account => {
-                let installer_account = runtime::get_key(INSTALLER)
-                    .unwrap_or_revert_with(NFTCoreError::MissingInstallerKey)
-                    .into_account()
-                    .unwrap_or_revert_with(NFTCoreError::FailedToConvertToAccountHash);
+    let has_role_cl_value = CLValue::from_t(utils::has_role(role, role_holder))
+        .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    runtime::ret(has_role_cl_value);
+}
 
-                // Revert if private minting is required and caller is not installer.
-                if runtime::get_caller() != installer_account {
-                    runtime::revert(NFTCoreError::InvalidMinter)
-                }
-            }
-            _ => runtime::revert(NFTCoreError::InvalidKey),
-        }
+// Only the installer manages roles. The minter role follows the whitelist mode of the ACL
+// whitelist it is stored in.
+fn get_verified_role_update() -> (Role, Key) {
+    let installer = utils::get_account_hash(
+        INSTALLER,
+        NFTCoreError::MissingInstaller,
+        NFTCoreError::InvalidInstaller,
+    );
+
+    if installer != runtime::get_caller() {
+        runtime::revert(NFTCoreError::InvalidAccount);
     }
 
-    // Revert if minting is acl and caller is not whitelisted.
-    if MintingMode::Acl == minting_mode {
-        let acl_package_mode: bool = utils::get_stored_value_with_user_errors::<bool>(
-            ACL_PACKAGE_MODE,
-            NFTCoreError::MissingACLPackageMode,
-            NFTCoreError::InvalidACLPackageMode,
-        );
-        let is_whitelisted = match (acl_package_mode, contract_package) {
-            (true, Some(contract_package)) => utils::get_dictionary_value_from_key::<bool>(
-                ACL_WHITELIST,
-                &utils::encode_dictionary_item_key(contract_package),
-            )
-            .unwrap_or_default(),
-            _ => utils::get_dictionary_value_from_key::<bool>(
-                ACL_WHITELIST,
-                &utils::encode_dictionary_item_key(caller),
-            )
-            .unwrap_or_default(),
-        };
+    let role: Role = utils::get_named_arg_with_user_errors::<u8>(
+        ARG_ROLE,
+        NFTCoreError::MissingRole,
+        NFTCoreError::InvalidRole,
+    )
+    .unwrap_or_revert()
+    .try_into()
+    .unwrap_or_revert();
 
-        match caller.tag() {
-            KeyTag::Hash => {
-                if !is_whitelisted {
-                    runtime::revert(NFTCoreError::UnlistedContractHash)
-                }
-            }
-            KeyTag::Account => {
-                if !is_whitelisted {
-                    runtime::revert(NFTCoreError::InvalidMinter);
-                }
-            }
-            _ => runtime::revert(NFTCoreError::InvalidKey),
-        }
+    let role_holder: Key = utils::get_named_arg_with_user_errors(
+        ARG_ROLE_HOLDER,
+        NFTCoreError::MissingRoleHolder,
+        NFTCoreError::InvalidRoleHolder,
+    )
+    .unwrap_or_revert();
+
+    if Role::Minter == role && WhitelistMode::Locked == utils::get_config().whitelist_mode {
+        runtime::revert(NFTCoreError::InvalidWhitelistMode)
     }
 
-    let metadata_kinds: BTreeMap<NFTMetadataKind, Requirement> =
-        utils::get_stored_value_with_user_errors(
-            NFT_METADATA_KINDS,
-            NFTCoreError::MissingNFTMetadataKind,
-            NFTCoreError::InvalidNFTMetadataKind,
-        );
+    (role, role_holder)
+}
+
+// Mints a new token. Minting will fail if allow_minting is set to false.
+#[no_mangle]
+pub extern "C" fn mint() {
+    let minted_tokens_count = get_minted_tokens_count_before_mint(1u64);
+    let config = utils::get_config();
+    let caller = get_verified_minter(&config);
 
     let token_metadata = utils::get_named_arg_with_user_errors::<String>(
         ARG_TOKEN_META_DATA,
@@ -731,61 +1052,525 @@ pub extern "C" fn mint() {
     )
     .unwrap_or_revert();
 
-    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
-        IDENTIFIER_MODE,
-        NFTCoreError::MissingIdentifierMode,
-        NFTCoreError::InvalidIdentifierMode,
-    )
-    .try_into()
-    .unwrap_or_revert();
-
     // This is the token ID.
     let optional_token_hash: String = utils::get_optional_named_arg_with_user_errors::<String>(
         ARG_TOKEN_HASH,
         NFTCoreError::InvalidIdentifier,
     )
     .unwrap_or_default();
-    let token_identifier: TokenIdentifier = match identifier_mode {
-        NFTIdentifierMode::Ordinal => TokenIdentifier::Index(minted_tokens_count),
-        NFTIdentifierMode::Hash => TokenIdentifier::Hash(if optional_token_hash.is_empty() {
-            base16::encode_lower(&runtime::blake2b(token_metadata.clone()))
-        } else {
-            optional_token_hash
-        }),
-    };
+    let token_identifier = get_minted_token_identifier(
+        config.identifier_mode,
+        minted_tokens_count,
+        &token_metadata,
+        optional_token_hash,
+    );
 
-    for (metadata_kind, required) in metadata_kinds {
-        if required == Requirement::Unneeded {
-            continue;
-        }
-        let token_metadata_validation =
-            metadata::validate_metadata(&metadata_kind, token_metadata.clone());
-        match token_metadata_validation {
-            Ok(validated_token_metadata) => {
-                utils::upsert_dictionary_value_from_key(
-                    &metadata::get_metadata_dictionary_name(&metadata_kind),
-                    &token_identifier.get_dictionary_item_key(),
-                    validated_token_metadata,
-                );
+    // The contract's ownership behavior (determined at installation) determines,
+    // who owns the NFT we are about to mint.()
+    let token_owner_key: Key =
+        if let OwnershipMode::Assigned | OwnershipMode::Transferable = config.ownership_mode {
+            runtime::get_named_arg(ARG_TOKEN_OWNER)
+        } else {
+            caller
+        };
+
+    if let Some(mint_filter_contract) = utils::get_mint_filter_contract() {
+        verify_token_mint(
+            mint_filter_contract,
+            token_owner_key,
+            caller,
+            &token_identifier,
+            &token_metadata,
+        );
+    }
+
+    store_token_metadata(&config, &token_identifier, &token_metadata);
+
+    if let Some(royalty) = get_royalty_override_from_runtime_args() {
+        utils::upsert_dictionary_value_from_key(
+            TOKEN_ROYALTIES,
+            &token_identifier.get_dictionary_item_key(),
+            royalty,
+        );
+    }
+
+    if is_non_transferable_from_runtime_args() {
+        utils::upsert_dictionary_value_from_key(
+            NON_TRANSFERABLE_TOKENS,
+            &token_identifier.get_dictionary_item_key(),
+            (),
+        );
+    }
+
+    record_minted_token(
+        caller,
+        token_owner_key,
+        &token_identifier,
+        minted_tokens_count,
+        token_metadata,
+        config.events_mode,
+    );
+
+    //Increment the count of owned tokens.
+    increment_token_count(token_owner_key, 1u64);
+
+    // Increment number_of_minted_tokens by one
+    let number_of_minted_tokens_uref = utils::get_uref(
+        NUMBER_OF_MINTED_TOKENS,
+        NFTCoreError::MissingTotalTokenSupply,
+        NFTCoreError::InvalidTotalTokenSupply,
+    );
+    storage::write(number_of_minted_tokens_uref, minted_tokens_count + 1u64);
+    update_collection_stat(
+        CIRCULATING_SUPPLY,
+        NFTCoreError::MissingCirculatingSupply,
+        NFTCoreError::InvalidCirculatingSupply,
+        |circulating_supply| circulating_supply.checked_add(1u64),
+    );
+
+    let receipt = if let OwnerReverseLookupMode::Complete = config.reporting_mode {
+        Some(add_mint_receipt(
+            token_owner_key,
+            &token_identifier,
+            minted_tokens_count,
+            &config,
+        ))
+    } else {
+        None
+    };
+
+    if let Some(data) = get_safe_mint_data_from_runtime_args() {
+        verify_token_receipt(token_owner_key, caller, None, &token_identifier, &data);
+    }
+
+    if let Some(receipt) = receipt {
+        runtime::ret(
+            CLValue::from_t(receipt).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+        )
+    }
+}
+
+// Mints a batch of tokens in a single call. The same checks as mint() apply to the whole batch,
+// and the collection configuration is only read once regardless of the size of the batch.
+#[no_mangle]
+pub extern "C" fn mint_batch() {
+    let token_metadatas = utils::get_named_arg_with_user_errors::<Vec<String>>(
+        ARG_TOKEN_META_DATAS,
+        NFTCoreError::MissingTokenMetaData,
+        NFTCoreError::InvalidTokenMetaData,
+    )
+    .unwrap_or_revert();
+
+    if token_metadatas.is_empty() {
+        runtime::revert(NFTCoreError::EmptyTokenBatch)
+    }
+
+    let number_of_tokens = token_metadatas.len();
+    let minted_tokens_count = get_minted_tokens_count_before_mint(number_of_tokens as u64);
+    let config = utils::get_config();
+    let caller = get_verified_minter(&config);
+
+    // Empty hashes fall back to the hash of the metadata, as they do in mint().
+    let optional_token_hashes: Vec<String> = utils::get_optional_named_arg_with_user_errors::<
+        Vec<String>,
+    >(
+        ARG_TOKEN_HASHES, NFTCoreError::InvalidIdentifier
+    )
+    .unwrap_or_else(|| vec![String::new(); number_of_tokens]);
+
+    if optional_token_hashes.len() != number_of_tokens {
+        runtime::revert(NFTCoreError::MismatchedTokenBatch)
+    }
+
+    let token_owner_keys: Vec<Key> = match config.ownership_mode {
+        OwnershipMode::Assigned | OwnershipMode::Transferable => {
+            utils::get_named_arg_with_user_errors::<Vec<Key>>(
+                ARG_TOKEN_OWNERS,
+                NFTCoreError::MissingTokenOwner,
+                NFTCoreError::InvalidTokenOwner,
+            )
+            .unwrap_or_revert()
+        }
+        OwnershipMode::Minter => vec![caller; number_of_tokens],
+    };
+
+    if token_owner_keys.len() != number_of_tokens {
+        runtime::revert(NFTCoreError::MismatchedTokenBatch)
+    }
+
+    // A royalty override and the non transferable flag apply to every token of the batch.
+    let royalty_override = get_royalty_override_from_runtime_args();
+    let non_transferable = is_non_transferable_from_runtime_args();
+
+    let mint_filter_contract = utils::get_mint_filter_contract();
+    let safe_mint_data = get_safe_mint_data_from_runtime_args();
+
+    // Every token of the batch is submitted to the mint filter contract before any is recorded.
+    let mut tokens: Vec<(u64, Key, TokenIdentifier, String)> = vec![];
+    for (offset, ((token_metadata, optional_token_hash), token_owner_key)) in token_metadatas
+        .into_iter()
+        .zip(optional_token_hashes)
+        .zip(token_owner_keys)
+        .enumerate()
+    {
+        let token_index = minted_tokens_count + offset as u64;
+        let token_identifier = get_minted_token_identifier(
+            config.identifier_mode,
+            token_index,
+            &token_metadata,
+            optional_token_hash,
+        );
+
+        if let Some(mint_filter_contract) = mint_filter_contract {
+            verify_token_mint(
+                mint_filter_contract,
+                token_owner_key,
+                caller,
+                &token_identifier,
+                &token_metadata,
+            );
+        }
+
+        tokens.push((
+            token_index,
+            token_owner_key,
+            token_identifier,
+            token_metadata,
+        ));
+    }
+
+    // Balances are accumulated per owner and written once at the end of the batch.
+    let mut token_counts: BTreeMap<Key, u64> = BTreeMap::new();
+    let mut receipts: Vec<(String, Key, String)> = vec![];
+    let mut minted_tokens: Vec<(Key, TokenIdentifier)> = vec![];
+
+    for (token_index, token_owner_key, token_identifier, token_metadata) in tokens {
+        store_token_metadata(&config, &token_identifier, &token_metadata);
+
+        if let Some(royalty) = royalty_override {
+            utils::upsert_dictionary_value_from_key(
+                TOKEN_ROYALTIES,
+                &token_identifier.get_dictionary_item_key(),
+                royalty,
+            );
+        }
+
+        if non_transferable {
+            utils::upsert_dictionary_value_from_key(
+                NON_TRANSFERABLE_TOKENS,
+                &token_identifier.get_dictionary_item_key(),
+                (),
+            );
+        }
+
+        record_minted_token(
+            caller,
+            token_owner_key,
+            &token_identifier,
+            token_index,
+            token_metadata,
+            config.events_mode,
+        );
+
+        *token_counts.entry(token_owner_key).or_default() += 1u64;
+
+        if let OwnerReverseLookupMode::Complete = config.reporting_mode {
+            receipts.push(add_mint_receipt(
+                token_owner_key,
+                &token_identifier,
+                token_index,
+                &config,
+            ));
+        }
+
+        if safe_mint_data.is_some() {
+            minted_tokens.push((token_owner_key, token_identifier));
+        }
+    }
+
+    for (token_owner_key, token_count) in token_counts {
+        increment_token_count(token_owner_key, token_count);
+    }
+
+    let number_of_minted_tokens_uref = utils::get_uref(
+        NUMBER_OF_MINTED_TOKENS,
+        NFTCoreError::MissingTotalTokenSupply,
+        NFTCoreError::InvalidTotalTokenSupply,
+    );
+    storage::write(
+        number_of_minted_tokens_uref,
+        minted_tokens_count + number_of_tokens as u64,
+    );
+    update_collection_stat(
+        CIRCULATING_SUPPLY,
+        NFTCoreError::MissingCirculatingSupply,
+        NFTCoreError::InvalidCirculatingSupply,
+        |circulating_supply| circulating_supply.checked_add(number_of_tokens as u64),
+    );
+
+    // Receiving contracts are only called once the whole batch is recorded.
+    if let Some(data) = safe_mint_data {
+        for (token_owner_key, token_identifier) in &minted_tokens {
+            verify_token_receipt(*token_owner_key, caller, None, token_identifier, &data);
+        }
+    }
+
+    if let OwnerReverseLookupMode::Complete = config.reporting_mode {
+        runtime::ret(
+            CLValue::from_t(receipts).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+        )
+    }
+}
+
+// Returns the number of tokens minted so far. Reverts if minting is paused or if minting
+// `number_of_tokens` additional tokens would exceed the total token supply.
+fn get_minted_tokens_count_before_mint(number_of_tokens: u64) -> u64 {
+    // The contract owner can toggle the minting behavior on and off over time.
+    // The contract is toggled on by default.
+    let minting_status = utils::get_stored_value_with_user_errors::<bool>(
+        ALLOW_MINTING,
+        NFTCoreError::MissingAllowMinting,
+        NFTCoreError::InvalidAllowMinting,
+    );
+
+    // If contract minting behavior is currently toggled off we revert.
+    if !minting_status {
+        runtime::revert(NFTCoreError::MintingIsPaused);
+    }
+
+    let total_token_supply = utils::get_stored_value_with_user_errors::<u64>(
+        TOTAL_TOKEN_SUPPLY,
+        NFTCoreError::MissingTotalTokenSupply,
+        NFTCoreError::InvalidTotalTokenSupply,
+    );
+
+    // The minted_tokens_count is the number of minted tokens so far.
+    let minted_tokens_count = utils::get_stored_value_with_user_errors::<u64>(
+        NUMBER_OF_MINTED_TOKENS,
+        NFTCoreError::MissingNumberOfMintedTokens,
+        NFTCoreError::InvalidNumberOfMintedTokens,
+    );
+
+    // Revert if the token supply has been exhausted.
+    if minted_tokens_count >= total_token_supply
+        || number_of_tokens > total_token_supply - minted_tokens_count
+    {
+        runtime::revert(NFTCoreError::TokenSupplyDepleted);
+    }
+
+    minted_tokens_count
+}
+
+// Returns the caller if it is allowed to mint under the current minting mode and reverts
+// otherwise.
+fn get_verified_minter(config: &Config) -> Key {
+    let (caller, contract_package): (Key, Option<Key>) =
+        match utils::get_verified_caller(config.holder_mode).unwrap_or_revert() {
+            Caller::Session(account_hash) => (account_hash.into(), None),
+            Caller::StoredCaller(contract_hash, contract_package_hash) => {
+                (contract_hash.into(), Some(contract_package_hash.into()))
             }
+        };
+
+    // Revert if minting is private and caller is not installer.
+    if MintingMode::Installer == config.minting_mode {
+        match caller.tag() {
+            KeyTag::Account => {
+                let installer_account = runtime::get_key(INSTALLER)
+                    .unwrap_or_revert_with(NFTCoreError::MissingInstallerKey)
+                    .into_account()
+                    .unwrap_or_revert_with(NFTCoreError::FailedToConvertToAccountHash);
+
+                // Revert if private minting is required and caller is neither installer nor
+                // minter.
+                if runtime::get_caller() != installer_account
+                    && !utils::has_role(Role::Minter, caller)
+                {
+                    runtime::revert(NFTCoreError::InvalidMinter)
+                }
+            }
+            _ => runtime::revert(NFTCoreError::InvalidKey),
+        }
+    }
+
+    // Revert if minting is acl and caller is not whitelisted.
+    if MintingMode::Acl == config.minting_mode {
+        let is_whitelisted = utils::caller_has_role(Role::Minter, caller, contract_package);
+
+        match caller.tag() {
+            KeyTag::Hash => {
+                if !is_whitelisted {
+                    runtime::revert(NFTCoreError::UnlistedContractHash)
+                }
+            }
+            KeyTag::Account => {
+                if !is_whitelisted {
+                    runtime::revert(NFTCoreError::InvalidMinter);
+                }
+            }
+            _ => runtime::revert(NFTCoreError::InvalidKey),
+        }
+    }
+
+    caller
+}
+
+fn get_minted_token_identifier(
+    identifier_mode: NFTIdentifierMode,
+    token_index: u64,
+    token_metadata: &str,
+    optional_token_hash: String,
+) -> TokenIdentifier {
+    match identifier_mode {
+        NFTIdentifierMode::Ordinal => TokenIdentifier::Index(token_index),
+        NFTIdentifierMode::Hash => TokenIdentifier::Hash(if optional_token_hash.is_empty() {
+            base16::encode_lower(&runtime::blake2b(token_metadata))
+        } else {
+            optional_token_hash
+        }),
+    }
+}
+
+// Asks the mint filter contract whether the token may be minted for its owner by the caller.
+fn verify_token_mint(
+    mint_filter_contract: ContractHash,
+    token_owner_key: Key,
+    caller: Key,
+    token_identifier: &TokenIdentifier,
+    token_metadata: &str,
+) {
+    let mut args = get_token_id_args(token_identifier);
+    args.insert(ARG_TOKEN_OWNER, token_owner_key).unwrap();
+    args.insert(ARG_ISSUER, caller).unwrap();
+    args.insert(ARG_TOKEN_META_DATA, token_metadata.to_string())
+        .unwrap();
+
+    call_filter_contract(mint_filter_contract, MINT_FILTER_CONTRACT_METHOD, args);
+}
+
+// Returns the royalty override supplied at mint, if any. Whichever of the receiver or the basis
+// points is left out falls back on the collection default.
+fn get_royalty_override_from_runtime_args() -> Option<(Key, u64)> {
+    let royalty_receiver = utils::get_optional_named_arg_with_user_errors::<Key>(
+        ARG_ROYALTY_RECEIVER,
+        NFTCoreError::InvalidRoyaltyReceiver,
+    );
+    let royalty_basis_points = utils::get_optional_named_arg_with_user_errors::<u64>(
+        ARG_ROYALTY_BASIS_POINTS,
+        NFTCoreError::InvalidRoyaltyBasisPoints,
+    );
+
+    if royalty_receiver.is_none() && royalty_basis_points.is_none() {
+        return None;
+    }
+
+    let (default_royalty_receiver, default_royalty_basis_points) = get_default_royalty();
+    let royalty_basis_points = royalty_basis_points.unwrap_or(default_royalty_basis_points);
+
+    if royalty_basis_points > MAX_ROYALTY_BASIS_POINTS {
+        runtime::revert(NFTCoreError::InvalidRoyaltyBasisPoints)
+    }
+
+    Some((
+        royalty_receiver.unwrap_or(default_royalty_receiver),
+        royalty_basis_points,
+    ))
+}
+
+// Returns whether the tokens being minted are flagged as non transferable, regardless of the
+// ownership mode of the collection.
+fn is_non_transferable_from_runtime_args() -> bool {
+    utils::get_optional_named_arg_with_user_errors::<bool>(
+        ARG_NON_TRANSFERABLE,
+        NFTCoreError::InvalidNonTransferable,
+    )
+    .unwrap_or_default()
+}
+
+// Returns the data handed over to the contracts receiving the minted tokens if the mint is safe,
+// in which case they must acknowledge the receipt of their token.
+fn get_safe_mint_data_from_runtime_args() -> Option<Bytes> {
+    let safe =
+        utils::get_optional_named_arg_with_user_errors::<bool>(ARG_SAFE, NFTCoreError::InvalidSafe)
+            .unwrap_or_default();
+
+    safe.then(|| {
+        utils::get_optional_named_arg_with_user_errors::<Bytes>(
+            ARG_DATA,
+            NFTCoreError::InvalidTransferData,
+        )
+        .unwrap_or_default()
+    })
+}
+
+fn get_default_royalty() -> (Key, u64) {
+    let royalty_receiver = utils::get_stored_value_with_user_errors::<Key>(
+        ROYALTY_RECEIVER,
+        NFTCoreError::MissingRoyaltyReceiver,
+        NFTCoreError::InvalidRoyaltyReceiver,
+    );
+    let royalty_basis_points = utils::get_stored_value_with_user_errors::<u64>(
+        ROYALTY_BASIS_POINTS,
+        NFTCoreError::MissingRoyaltyBasisPoints,
+        NFTCoreError::InvalidRoyaltyBasisPoints,
+    );
+    (royalty_receiver, royalty_basis_points)
+}
+
+// Validates the metadata against every required or optional metadata kind and stores the
+// validated value in the matching dictionary, or in the compact mode stores the metadata once
+// along with the kinds it validated against.
+fn store_token_metadata(config: &Config, token_identifier: &TokenIdentifier, token_metadata: &str) {
+    // Bit `kind as u8` is set for each metadata kind the token metadata validated against.
+    let mut validated_kinds = 0u8;
+    for (metadata_kind, required) in &config.nft_metadata_kinds {
+        if required == &Requirement::Unneeded {
+            continue;
+        }
+        let token_metadata_validation =
+            metadata::validate_metadata(metadata_kind, token_metadata.to_string());
+        match token_metadata_validation {
+            Ok(validated_token_metadata) => match config.metadata_storage_mode {
+                MetadataStorageMode::PerKind => utils::upsert_dictionary_value_from_key(
+                    &metadata::get_metadata_dictionary_name(metadata_kind),
+                    &token_identifier.get_dictionary_item_key(),
+                    validated_token_metadata,
+                ),
+                MetadataStorageMode::Compact => validated_kinds |= 1 << *metadata_kind as u8,
+            },
             Err(err) => {
-                if required == Requirement::Required {
+                if required == &Requirement::Required {
                     runtime::revert(err);
                 }
             }
         }
     }
 
-    // The contract's ownership behavior (determined at installation) determines,
-    // who owns the NFT we are about to mint.()
-    let ownership_mode = utils::get_ownership_mode().unwrap_or_revert();
-    let token_owner_key: Key =
-        if let OwnershipMode::Assigned | OwnershipMode::Transferable = ownership_mode {
-            runtime::get_named_arg(ARG_TOKEN_OWNER)
+    if let MetadataStorageMode::Compact = config.metadata_storage_mode {
+        // Metadata only validated against JSON kinds is stored as compact JSON. The Raw view
+        // returns the metadata as submitted, so it is kept as is once it validated against Raw.
+        let stored_token_metadata = if validated_kinds & (1 << NFTMetadataKind::Raw as u8) == 0 {
+            metadata::compact_metadata(token_metadata)
         } else {
-            caller
+            token_metadata.to_string()
         };
+        utils::upsert_dictionary_value_from_key(
+            METADATA_COMPACT,
+            &token_identifier.get_dictionary_item_key(),
+            (stored_token_metadata, validated_kinds),
+        );
+    }
+}
 
+// Records the owner and issuer of a newly minted token and emits the Mint event.
+fn record_minted_token(
+    caller: Key,
+    token_owner_key: Key,
+    token_identifier: &TokenIdentifier,
+    token_index: u64,
+    token_metadata: String,
+    events_mode: EventsMode,
+) {
     utils::upsert_dictionary_value_from_key(
         TOKEN_OWNERS,
         &token_identifier.get_dictionary_item_key(),
@@ -796,42 +1581,13 @@ pub extern "C" fn mint() {
         &token_identifier.get_dictionary_item_key(),
         caller,
     );
-    let owned_tokens_item_key = utils::encode_dictionary_item_key(token_owner_key);
 
-    if let NFTIdentifierMode::Hash = identifier_mode {
+    if let TokenIdentifier::Hash(_) = token_identifier {
         // Update the forward and reverse trackers
-        utils::insert_hash_id_lookups(minted_tokens_count, token_identifier.clone());
+        utils::insert_hash_id_lookups(token_index, token_identifier.clone());
     }
 
-    //Increment the count of owned tokens.
-    let updated_token_count =
-        match utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &owned_tokens_item_key) {
-            Some(balance) => balance + 1u64,
-            None => 1u64,
-        };
-    utils::upsert_dictionary_value_from_key(
-        TOKEN_COUNT,
-        &owned_tokens_item_key,
-        updated_token_count,
-    );
-
-    // Increment number_of_minted_tokens by one
-    let number_of_minted_tokens_uref = utils::get_uref(
-        NUMBER_OF_MINTED_TOKENS,
-        NFTCoreError::MissingTotalTokenSupply,
-        NFTCoreError::InvalidTotalTokenSupply,
-    );
-    storage::write(number_of_minted_tokens_uref, minted_tokens_count + 1u64);
-
     // Emit Mint event.
-    let events_mode: EventsMode =
-        EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
-            EVENTS_MODE,
-            NFTCoreError::MissingEventsMode,
-            NFTCoreError::InvalidEventsMode,
-        ))
-        .unwrap_or_revert();
-
     match events_mode {
         EventsMode::NoEvents => {}
         EventsMode::CES => casper_event_standard::emit(Mint::new(
@@ -844,56 +1600,149 @@ pub extern "C" fn mint() {
             token_id: token_identifier.clone(),
         }),
     }
+}
 
-    if let OwnerReverseLookupMode::Complete = utils::get_reporting_mode() {
-        if (NFTIdentifierMode::Hash == identifier_mode)
-            && runtime::get_key(OWNED_TOKENS).is_some()
-            && utils::should_migrate_token_hashes(token_owner_key)
-        {
-            utils::migrate_token_hashes(token_owner_key)
-        }
-
-        let (page_table_entry, page_uref) = utils::add_page_entry_and_page_record(
-            minted_tokens_count,
-            &owned_tokens_item_key,
-            true,
+fn increment_token_count(token_owner_key: Key, amount: u64) {
+    let owned_tokens_item_key = utils::encode_dictionary_item_key(token_owner_key);
+    let balance = utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &owned_tokens_item_key)
+        .unwrap_or(0u64);
+    let updated_token_count = balance + amount;
+
+    // The owner becomes a holder with its first token.
+    if balance == 0u64 && updated_token_count > 0u64 {
+        update_collection_stat(
+            HOLDER_COUNT,
+            NFTCoreError::MissingHolderCount,
+            NFTCoreError::InvalidHolderCount,
+            |holder_count| holder_count.checked_add(1u64),
         );
+    }
 
-        let receipt_string = utils::get_receipt_name(page_table_entry);
-        let receipt_address = Key::dictionary(page_uref, owned_tokens_item_key.as_bytes());
-        let token_identifier_string = token_identifier.get_dictionary_item_key();
+    utils::upsert_dictionary_value_from_key(
+        TOKEN_COUNT,
+        &owned_tokens_item_key,
+        updated_token_count,
+    );
+}
 
-        let receipt = CLValue::from_t((receipt_string, receipt_address, token_identifier_string))
-            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
-        runtime::ret(receipt)
+// Marks the newly minted token in the owner's page and returns the matching receipt.
+fn add_mint_receipt(
+    token_owner_key: Key,
+    token_identifier: &TokenIdentifier,
+    token_index: u64,
+    config: &Config,
+) -> (String, Key, String) {
+    if (NFTIdentifierMode::Hash == config.identifier_mode)
+        && runtime::get_key(OWNED_TOKENS).is_some()
+        && utils::should_migrate_token_hashes(token_owner_key)
+    {
+        utils::migrate_token_hashes(token_owner_key, config)
     }
+
+    let owned_tokens_item_key = utils::encode_dictionary_item_key(token_owner_key);
+    let (page_table_entry, page_uref) =
+        utils::add_page_entry_and_page_record(token_index, &owned_tokens_item_key, true, config);
+
+    let receipt_string = utils::get_receipt_name(page_table_entry, config);
+    let receipt_address = Key::dictionary(page_uref, owned_tokens_item_key.as_bytes());
+    let token_identifier_string = token_identifier.get_dictionary_item_key();
+
+    (receipt_string, receipt_address, token_identifier_string)
 }
 
 // Marks token as burnt. This blocks any future call to transfer token.
 #[no_mangle]
 pub extern "C" fn burn() {
-    if let BurnMode::NonBurnable = utils::get_burn_mode() {
+    let config = utils::get_config();
+    if let BurnMode::NonBurnable = config.burn_mode {
         runtime::revert(NFTCoreError::InvalidBurnMode)
     }
 
-    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
-        IDENTIFIER_MODE,
-        NFTCoreError::MissingIdentifierMode,
-        NFTCoreError::InvalidIdentifierMode,
-    )
-    .try_into()
-    .unwrap_or_revert();
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&config.identifier_mode);
 
-    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+    let (caller, contract_package): (Key, Option<Key>) =
+        match utils::get_verified_caller(config.holder_mode).unwrap_or_revert() {
+            Caller::Session(account_hash) => (account_hash.into(), None),
+            Caller::StoredCaller(contract_hash, contract_package_hash) => {
+                (contract_hash.into(), Some(contract_package_hash.into()))
+            }
+        };
+
+    let token_owner = verify_token_burn(
+        &token_identifier,
+        caller,
+        contract_package,
+        utils::get_burn_filter_contract(),
+        &config,
+    );
+    burn_token(token_identifier, token_owner, caller, config.events_mode);
+
+    decrement_token_count(token_owner, 1u64);
+    record_burnt_tokens_count(1u64);
+}
+
+// Marks a batch of tokens as burnt, applying the same checks as burn() to every token. The
+// balance of every owner is only updated once.
+#[no_mangle]
+pub extern "C" fn burn_batch() {
+    let config = utils::get_config();
+    if let BurnMode::NonBurnable = config.burn_mode {
+        runtime::revert(NFTCoreError::InvalidBurnMode)
+    }
+
+    let token_identifiers = utils::get_token_identifiers_from_runtime_args(&config.identifier_mode);
 
     let (caller, contract_package): (Key, Option<Key>) =
-        match utils::get_verified_caller().unwrap_or_revert() {
+        match utils::get_verified_caller(config.holder_mode).unwrap_or_revert() {
             Caller::Session(account_hash) => (account_hash.into(), None),
             Caller::StoredCaller(contract_hash, contract_package_hash) => {
                 (contract_hash.into(), Some(contract_package_hash.into()))
             }
         };
 
+    let burn_filter_contract = utils::get_burn_filter_contract();
+
+    // Every token of the batch is checked and submitted to the burn filter contract before any is
+    // burnt. A token listed twice counts as previously burnt, as it would when burnt one by one.
+    let mut token_dictionary_item_keys: BTreeSet<String> = BTreeSet::new();
+    let mut tokens: Vec<(TokenIdentifier, Key)> = vec![];
+    for token_identifier in token_identifiers {
+        if !token_dictionary_item_keys.insert(token_identifier.get_dictionary_item_key()) {
+            runtime::revert(NFTCoreError::PreviouslyBurntToken)
+        }
+        let token_owner = verify_token_burn(
+            &token_identifier,
+            caller,
+            contract_package,
+            burn_filter_contract,
+            &config,
+        );
+        tokens.push((token_identifier, token_owner));
+    }
+
+    let mut burnt_token_counts: BTreeMap<Key, u64> = BTreeMap::new();
+    for (token_identifier, token_owner) in tokens {
+        burn_token(token_identifier, token_owner, caller, config.events_mode);
+        *burnt_token_counts.entry(token_owner).or_default() += 1u64;
+    }
+
+    let mut total_burnt_token_count = 0u64;
+    for (token_owner, burnt_token_count) in burnt_token_counts {
+        decrement_token_count(token_owner, burnt_token_count);
+        total_burnt_token_count += burnt_token_count;
+    }
+    record_burnt_tokens_count(total_burnt_token_count);
+}
+
+// Checks that the caller is allowed to burn the token and asks the burn filter contract, if any,
+// whether it may be burnt. Returns the owner of the token.
+fn verify_token_burn(
+    token_identifier: &TokenIdentifier,
+    caller: Key,
+    contract_package: Option<Key>,
+    burn_filter_contract: Option<ContractHash>,
+    config: &Config,
+) -> Key {
     let token_owner = match utils::get_dictionary_value_from_key::<Key>(
         TOKEN_OWNERS,
         &token_identifier.get_dictionary_item_key(),
@@ -907,9 +1756,7 @@ pub extern "C" fn burn() {
 
     // Check if caller is operator to execute burn
     let is_operator = if !is_owner {
-        let owner_operator_item_key = utils::encode_key_and_value(&token_owner, &caller);
-        utils::get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key)
-            .unwrap_or_default()
+        utils::is_operator(&token_owner, &caller)
     } else {
         false
     };
@@ -924,30 +1771,63 @@ pub extern "C" fn burn() {
             ),
             contract_package,
         ) {
-            (true, Some(contract_package)) => {
-                let owner_operator_item_key =
-                    utils::encode_key_and_value(&token_owner, &contract_package);
-                utils::get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key)
-                    .unwrap_or_default()
-            }
+            (true, Some(contract_package)) => utils::is_operator(&token_owner, &contract_package),
             _ => false,
         }
     } else {
         false
     };
 
-    // Revert if caller is not token_owner nor operator for the owner
-    if !is_owner && !is_operator && !is_package_operator {
+    // Burners may burn any token.
+    let is_burner = !is_owner
+        && !is_operator
+        && !is_package_operator
+        && utils::caller_has_role(Role::Burner, caller, contract_package);
+
+    // Revert if caller is not token_owner nor operator for the owner nor burner
+    if !is_owner && !is_operator && !is_package_operator && !is_burner {
         runtime::revert(NFTCoreError::InvalidTokenOwner)
     };
 
     // It makes sense to keep this token as owned by the caller. It just happens that the caller
     // owns a burnt token. That's all. Similarly, we should probably also not change the
     // owned_tokens dictionary.
-    if utils::is_token_burned(&token_identifier) {
+    if utils::is_token_burned(token_identifier) {
         runtime::revert(NFTCoreError::PreviouslyBurntToken)
     }
 
+    // Tokens minted before issuers were recorded cannot be submitted to the burn filter contract.
+    if let Some(burn_filter_contract) = burn_filter_contract {
+        let issuer = utils::get_dictionary_value_from_key::<Key>(
+            TOKEN_ISSUERS,
+            &token_identifier.get_dictionary_item_key(),
+        )
+        .unwrap_or_revert_with(NFTCoreError::MissingTokenIssuer);
+
+        let mut args = get_token_id_args(token_identifier);
+        args.insert(ARG_TOKEN_OWNER, token_owner).unwrap();
+        args.insert(ARG_ISSUER, issuer).unwrap();
+        args.insert(ARG_CALLER, caller).unwrap();
+        args.insert(
+            ARG_TOKEN_META_DATA,
+            get_token_metadata(token_identifier, config),
+        )
+        .unwrap();
+
+        call_filter_contract(burn_filter_contract, BURN_FILTER_CONTRACT_METHOD, args);
+    }
+
+    token_owner
+}
+
+// Marks the token as burnt and emits the Burn event. The balance of the owner is left to the
+// caller to update.
+fn burn_token(
+    token_identifier: TokenIdentifier,
+    token_owner: Key,
+    caller: Key,
+    events_mode: EventsMode,
+) {
     // Mark the token as burnt by adding the token_id to the burnt tokens dictionary.
     utils::upsert_dictionary_value_from_key::<()>(
         BURNT_TOKENS,
@@ -955,13 +1835,31 @@ pub extern "C" fn burn() {
         (),
     );
 
-    let owned_tokens_item_key = utils::encode_dictionary_item_key(token_owner);
+    // A burnt token keeps no approved spenders.
+    utils::set_approved_spenders(&token_identifier, Vec::new());
+
+    // Emit Burn event.
+    match events_mode {
+        EventsMode::NoEvents => {}
+        EventsMode::CES => {
+            casper_event_standard::emit(Burn::new(token_owner, token_identifier, caller))
+        }
+        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::Burn {
+            owner: token_owner,
+            token_id: token_identifier,
+            burner: caller,
+        }),
+    }
+}
+
+fn decrement_token_count(token_owner_key: Key, amount: u64) {
+    let owned_tokens_item_key = utils::encode_dictionary_item_key(token_owner_key);
 
     let updated_balance =
         match utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &owned_tokens_item_key) {
             Some(balance) => {
-                if balance > 0u64 {
-                    balance - 1u64
+                if balance >= amount {
+                    balance - amount
                 } else {
                     // This should never happen if contract is implemented correctly.
                     runtime::revert(NFTCoreError::FatalTokenIdDuplication);
@@ -973,72 +1871,213 @@ pub extern "C" fn burn() {
             }
         };
 
+    // The owner is no longer a holder once its last token is gone.
+    if updated_balance == 0u64 && amount > 0u64 {
+        update_collection_stat(
+            HOLDER_COUNT,
+            NFTCoreError::MissingHolderCount,
+            NFTCoreError::InvalidHolderCount,
+            |holder_count| holder_count.checked_sub(1u64),
+        );
+    }
+
     utils::upsert_dictionary_value_from_key(TOKEN_COUNT, &owned_tokens_item_key, updated_balance);
+}
 
-    // Emit Burn event.
-    let events_mode: EventsMode =
-        EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
-            EVENTS_MODE,
-            NFTCoreError::MissingEventsMode,
-            NFTCoreError::InvalidEventsMode,
-        ))
-        .unwrap_or_revert();
+// Moves burnt tokens out of the circulating supply.
+fn record_burnt_tokens_count(amount: u64) {
+    update_collection_stat(
+        BURNT_TOKENS_COUNT,
+        NFTCoreError::MissingBurntTokensCount,
+        NFTCoreError::InvalidBurntTokensCount,
+        |burnt_tokens_count| burnt_tokens_count.checked_add(amount),
+    );
+    update_collection_stat(
+        CIRCULATING_SUPPLY,
+        NFTCoreError::MissingCirculatingSupply,
+        NFTCoreError::InvalidCirculatingSupply,
+        |circulating_supply| circulating_supply.checked_sub(amount),
+    );
+}
 
-    match events_mode {
-        EventsMode::NoEvents => {}
-        EventsMode::CES => {
-            casper_event_standard::emit(Burn::new(token_owner, token_identifier, caller))
-        }
-        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::Burn {
-            owner: token_owner,
-            token_id: token_identifier,
-            burner: caller,
-        }),
+// Applies the update to the stored collection statistics counter. Counters left untracked by an
+// upgrade are not updated until they are seeded, and an update going out of range reverts as the
+// counters no longer match the collection.
+fn update_collection_stat(
+    name: &str,
+    missing: NFTCoreError,
+    invalid: NFTCoreError,
+    update: impl FnOnce(u64) -> Option<u64>,
+) {
+    if !collection_stats_tracked() {
+        return;
+    }
+    let counter_uref = utils::get_uref(name, missing, invalid);
+    let counter: u64 = utils::read_with_user_errors(counter_uref, missing, invalid);
+    let updated_counter =
+        update(counter).unwrap_or_revert_with(NFTCoreError::CollectionStatsUnderflow);
+    storage::write(counter_uref, updated_counter);
+}
+
+// Overwrites the collection statistics with the given counts, the circulating supply being the
+// minted tokens that were not burnt, and tracks them from then on.
+fn seed_collection_stats(burnt_tokens_count: u64, holder_count: u64) {
+    let number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
+        NUMBER_OF_MINTED_TOKENS,
+        NFTCoreError::MissingNumberOfMintedTokens,
+        NFTCoreError::InvalidNumberOfMintedTokens,
+    );
+    let circulating_supply = number_of_minted_tokens
+        .checked_sub(burnt_tokens_count)
+        .unwrap_or_revert_with(NFTCoreError::InvalidBurntTokensCount);
+    // Every holder owns at least one token in circulation.
+    if holder_count > circulating_supply {
+        runtime::revert(NFTCoreError::InvalidHolderCount)
+    }
+    for (name, value, missing, invalid) in [
+        (
+            BURNT_TOKENS_COUNT,
+            burnt_tokens_count,
+            NFTCoreError::MissingBurntTokensCount,
+            NFTCoreError::InvalidBurntTokensCount,
+        ),
+        (
+            CIRCULATING_SUPPLY,
+            circulating_supply,
+            NFTCoreError::MissingCirculatingSupply,
+            NFTCoreError::InvalidCirculatingSupply,
+        ),
+        (
+            HOLDER_COUNT,
+            holder_count,
+            NFTCoreError::MissingHolderCount,
+            NFTCoreError::InvalidHolderCount,
+        ),
+    ] {
+        storage::write(utils::get_uref(name, missing, invalid), value);
     }
+    let collection_stats_tracked_uref = utils::get_uref(
+        COLLECTION_STATS_TRACKED,
+        NFTCoreError::MissingCollectionStatsTracked,
+        NFTCoreError::InvalidCollectionStatsTracked,
+    );
+    storage::write(collection_stats_tracked_uref, true);
+}
+
+fn collection_stats_tracked() -> bool {
+    utils::get_stored_value_with_user_errors::<bool>(
+        COLLECTION_STATS_TRACKED,
+        NFTCoreError::MissingCollectionStatsTracked,
+        NFTCoreError::InvalidCollectionStatsTracked,
+    )
 }
 
 // Marks an account as approved for an identified token transfer
 #[no_mangle]
 pub extern "C" fn approve() {
+    let config = utils::get_config();
     // If we are in minter or assigned mode it makes no sense to approve an account. Hence we
     // revert.
-    if let OwnershipMode::Minter | OwnershipMode::Assigned =
-        utils::get_ownership_mode().unwrap_or_revert()
-    {
+    if let OwnershipMode::Minter | OwnershipMode::Assigned = config.ownership_mode {
         runtime::revert(NFTCoreError::InvalidOwnershipMode)
     }
 
     let (caller, contract_package): (Key, Option<Key>) =
-        match utils::get_verified_caller().unwrap_or_revert() {
+        match utils::get_verified_caller(config.holder_mode).unwrap_or_revert() {
             Caller::Session(account_hash) => (account_hash.into(), None),
             Caller::StoredCaller(contract_hash, contract_package_hash) => {
                 (contract_hash.into(), Some(contract_package_hash.into()))
             }
         };
 
-    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
-        IDENTIFIER_MODE,
-        NFTCoreError::MissingIdentifierMode,
-        NFTCoreError::InvalidIdentifierMode,
-    )
-    .try_into()
-    .unwrap_or_revert();
+    let token_id = utils::get_token_identifier_from_runtime_args(&config.identifier_mode);
 
-    let token_id = utils::get_token_identifier_from_runtime_args(&identifier_mode);
-    let token_identifier_dictionary_key = token_id.get_dictionary_item_key();
+    let spender = match utils::get_optional_named_arg_with_user_errors::<Key>(
+        ARG_OPERATOR, // Deprecated in favor of ARG_SPENDER
+        NFTCoreError::InvalidApprovedAccountHash,
+    ) {
+        Some(deprecated_operator) => deprecated_operator,
+        None => utils::get_named_arg_with_user_errors::<Key>(
+            ARG_SPENDER,
+            NFTCoreError::MissingSpenderAccountHash,
+            NFTCoreError::InvalidSpenderAccountHash,
+        )
+        .unwrap_or_revert(),
+    };
 
-    let number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
-        NUMBER_OF_MINTED_TOKENS,
-        NFTCoreError::MissingNumberOfMintedTokens,
-        NFTCoreError::InvalidNumberOfMintedTokens,
+    let expires_at = utils::get_expires_at_from_runtime_args();
+
+    approve_token(
+        token_id,
+        spender,
+        expires_at,
+        caller,
+        contract_package,
+        config.events_mode,
     );
+}
 
-    if let NFTIdentifierMode::Ordinal = identifier_mode {
-        // Revert if token_id is out of bounds
-        if let TokenIdentifier::Index(index) = &token_id {
-            if *index >= number_of_minted_tokens {
-                runtime::revert(NFTCoreError::InvalidTokenIdentifier);
+// Approves a spender for a batch of tokens, applying the same checks as approve() to every
+// token.
+#[no_mangle]
+pub extern "C" fn approve_batch() {
+    let config = utils::get_config();
+    if let OwnershipMode::Minter | OwnershipMode::Assigned = config.ownership_mode {
+        runtime::revert(NFTCoreError::InvalidOwnershipMode)
+    }
+
+    let (caller, contract_package): (Key, Option<Key>) =
+        match utils::get_verified_caller(config.holder_mode).unwrap_or_revert() {
+            Caller::Session(account_hash) => (account_hash.into(), None),
+            Caller::StoredCaller(contract_hash, contract_package_hash) => {
+                (contract_hash.into(), Some(contract_package_hash.into()))
             }
+        };
+
+    let token_identifiers = utils::get_token_identifiers_from_runtime_args(&config.identifier_mode);
+
+    let spender = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_SPENDER,
+        NFTCoreError::MissingSpenderAccountHash,
+        NFTCoreError::InvalidSpenderAccountHash,
+    )
+    .unwrap_or_revert();
+
+    let expires_at = utils::get_expires_at_from_runtime_args();
+
+    for token_identifier in token_identifiers {
+        approve_token(
+            token_identifier,
+            spender,
+            expires_at,
+            caller,
+            contract_package,
+            config.events_mode,
+        );
+    }
+}
+
+// Checks that the caller is allowed to approve a spender for the token, records the approval
+// along with its optional expiry and emits the Approval event.
+fn approve_token(
+    token_id: TokenIdentifier,
+    spender: Key,
+    expires_at: Option<u64>,
+    caller: Key,
+    contract_package: Option<Key>,
+    events_mode: EventsMode,
+) {
+    let token_identifier_dictionary_key = token_id.get_dictionary_item_key();
+
+    // Revert if token_id is out of bounds
+    if let TokenIdentifier::Index(index) = &token_id {
+        let number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
+            NUMBER_OF_MINTED_TOKENS,
+            NFTCoreError::MissingNumberOfMintedTokens,
+            NFTCoreError::InvalidNumberOfMintedTokens,
+        );
+        if *index >= number_of_minted_tokens {
+            runtime::revert(NFTCoreError::InvalidTokenIdentifier);
         }
     }
 
@@ -1053,12 +2092,7 @@ pub extern "C" fn approve() {
     // Revert if caller is not token owner nor operator.
     // Only the token owner or an operator can approve an account
     let is_owner = caller == owner;
-    let is_operator = !is_owner
-        && utils::get_dictionary_value_from_key::<bool>(
-            OPERATORS,
-            &utils::encode_key_and_value(&owner, &caller),
-        )
-        .unwrap_or_default();
+    let is_operator = !is_owner && utils::is_operator(&owner, &caller);
 
     let is_package_operator = if !is_owner && !is_operator {
         match (
@@ -1069,12 +2103,7 @@ pub extern "C" fn approve() {
             ),
             contract_package,
         ) {
-            (true, Some(contract_package)) => {
-                let owner_operator_item_key =
-                    utils::encode_key_and_value(&owner, &contract_package);
-                utils::get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key)
-                    .unwrap_or_default()
-            }
+            (true, Some(contract_package)) => utils::is_operator(&owner, &contract_package),
             _ => false,
         }
     } else {
@@ -1090,37 +2119,23 @@ pub extern "C" fn approve() {
         runtime::revert(NFTCoreError::PreviouslyBurntToken)
     }
 
-    let spender = match utils::get_optional_named_arg_with_user_errors::<Key>(
-        ARG_OPERATOR, // Deprecated in favor of ARG_SPENDER
-        NFTCoreError::InvalidApprovedAccountHash,
-    ) {
-        Some(deprecated_operator) => deprecated_operator,
-        None => utils::get_named_arg_with_user_errors::<Key>(
-            ARG_SPENDER,
-            NFTCoreError::MissingSpenderAccountHash,
-            NFTCoreError::InvalidSpenderAccountHash,
-        )
-        .unwrap_or_revert(),
-    };
-
     // If token owner or operator tries to approve itself that's probably a mistake and we revert.
     if caller == spender {
         runtime::revert(NFTCoreError::InvalidAccount);
     }
 
+    // The spender joins the spenders already approved, whose expired approvals are dropped.
+    let mut spenders = utils::get_active_approved_spenders(&token_id);
+    if !spenders.contains(&spender) {
+        spenders.push(spender);
+    }
+    utils::set_approved_spenders(&token_id, spenders);
     utils::upsert_dictionary_value_from_key(
-        APPROVED,
-        &token_identifier_dictionary_key,
-        Some(spender),
+        APPROVAL_EXPIRIES,
+        &utils::get_approval_expiry_item_key(&token_id, &spender),
+        expires_at,
     );
 
-    let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
-        crate::constants::EVENTS_MODE,
-        NFTCoreError::MissingEventsMode,
-        NFTCoreError::InvalidEventsMode,
-    ))
-    .unwrap_or_revert();
-
     // Emit Approval event.
     match events_mode {
         EventsMode::NoEvents => {}
@@ -1136,29 +2151,22 @@ pub extern "C" fn approve() {
 // Revokes an account as approved for an identified token transfer
 #[no_mangle]
 pub extern "C" fn revoke() {
+    let config = utils::get_config();
     // If we are in minter or assigned mode it makes no sense to approve an account. Hence we
     // revert.
-    if let OwnershipMode::Minter | OwnershipMode::Assigned =
-        utils::get_ownership_mode().unwrap_or_revert()
-    {
+    if let OwnershipMode::Minter | OwnershipMode::Assigned = config.ownership_mode {
         runtime::revert(NFTCoreError::InvalidOwnershipMode)
     }
 
     let (caller, contract_package): (Key, Option<Key>) =
-        match utils::get_verified_caller().unwrap_or_revert() {
+        match utils::get_verified_caller(config.holder_mode).unwrap_or_revert() {
             Caller::Session(account_hash) => (account_hash.into(), None),
             Caller::StoredCaller(contract_hash, contract_package_hash) => {
                 (contract_hash.into(), Some(contract_package_hash.into()))
             }
         };
 
-    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
-        IDENTIFIER_MODE,
-        NFTCoreError::MissingIdentifierMode,
-        NFTCoreError::InvalidIdentifierMode,
-    )
-    .try_into()
-    .unwrap_or_revert();
+    let identifier_mode = config.identifier_mode;
 
     let token_id = utils::get_token_identifier_from_runtime_args(&identifier_mode);
     let token_identifier_dictionary_key = token_id.get_dictionary_item_key();
@@ -1189,12 +2197,7 @@ pub extern "C" fn revoke() {
     // Revert if caller is not the token owner or an operator. Only the token owner / operators can
     // revoke an approved account
     let is_owner = caller == owner;
-    let is_operator = !is_owner
-        && utils::get_dictionary_value_from_key::<bool>(
-            OPERATORS,
-            &utils::encode_key_and_value(&owner, &caller),
-        )
-        .unwrap_or_default();
+    let is_operator = !is_owner && utils::is_operator(&owner, &caller);
 
     let is_package_operator = if !is_owner && !is_operator {
         match (
@@ -1205,12 +2208,7 @@ pub extern "C" fn revoke() {
             ),
             contract_package,
         ) {
-            (true, Some(contract_package)) => {
-                let owner_operator_item_key =
-                    utils::encode_key_and_value(&owner, &contract_package);
-                utils::get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key)
-                    .unwrap_or_default()
-            }
+            (true, Some(contract_package)) => utils::is_operator(&owner, &contract_package),
             _ => false,
         }
     } else {
@@ -1226,21 +2224,21 @@ pub extern "C" fn revoke() {
         runtime::revert(NFTCoreError::PreviouslyBurntToken)
     }
 
-    utils::upsert_dictionary_value_from_key(
-        APPROVED,
-        &token_identifier_dictionary_key,
-        Option::<Key>::None,
-    );
-
-    let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
-        crate::constants::EVENTS_MODE,
-        NFTCoreError::MissingEventsMode,
-        NFTCoreError::InvalidEventsMode,
-    ))
-    .unwrap_or_revert();
+    // Revoke the given spender only, or every approved spender if none is given.
+    let spenders = match utils::get_optional_named_arg_with_user_errors::<Key>(
+        ARG_SPENDER,
+        NFTCoreError::InvalidSpenderAccountHash,
+    ) {
+        Some(revoked_spender) => utils::get_approved_spenders(&token_id)
+            .into_iter()
+            .filter(|spender| spender != &revoked_spender)
+            .collect(),
+        None => Vec::new(),
+    };
+    utils::set_approved_spenders(&token_id, spenders);
 
     // Emit ApprovalRevoked event.
-    match events_mode {
+    match config.events_mode {
         EventsMode::NoEvents => {}
         EventsMode::CES => casper_event_standard::emit(ApprovalRevoked::new(owner, token_id)),
         EventsMode::CEP47 => {
@@ -1252,11 +2250,10 @@ pub extern "C" fn revoke() {
 // Approves the specified operator for transfer of owner's tokens.
 #[no_mangle]
 pub extern "C" fn set_approval_for_all() {
+    let config = utils::get_config();
     // If we are in minter or assigned mode it makes no sense to approve an operator. Hence we
     // revert.
-    if let OwnershipMode::Minter | OwnershipMode::Assigned =
-        utils::get_ownership_mode().unwrap_or_revert()
-    {
+    if let OwnershipMode::Minter | OwnershipMode::Assigned = config.ownership_mode {
         runtime::revert(NFTCoreError::InvalidOwnershipMode)
     }
 
@@ -1269,7 +2266,7 @@ pub extern "C" fn set_approval_for_all() {
     )
     .unwrap_or_revert();
 
-    let caller: Key = match utils::get_verified_caller().unwrap_or_revert() {
+    let caller: Key = match utils::get_verified_caller(config.holder_mode).unwrap_or_revert() {
         Caller::Session(account_hash) => account_hash.into(),
         Caller::StoredCaller(contract_hash, _) => contract_hash.into(),
     };
@@ -1286,19 +2283,23 @@ pub extern "C" fn set_approval_for_all() {
         runtime::revert(NFTCoreError::InvalidAccount);
     }
 
+    // An operator may be approved until a given block time, after which it lapses.
+    let expires_at = if approve_all {
+        utils::get_expires_at_from_runtime_args()
+    } else {
+        None
+    };
+
     // Depending on approve_all we either approve all or disapprove all.
     let owner_operator_item_key = utils::encode_key_and_value(&caller, &operator);
     utils::upsert_dictionary_value_from_key(OPERATORS, &owner_operator_item_key, approve_all);
+    utils::upsert_dictionary_value_from_key(
+        OPERATOR_EXPIRIES,
+        &owner_operator_item_key,
+        expires_at,
+    );
 
-    let events_mode: EventsMode = utils::get_stored_value_with_user_errors::<u8>(
-        EVENTS_MODE,
-        NFTCoreError::MissingEventsMode,
-        NFTCoreError::InvalidEventsMode,
-    )
-    .try_into()
-    .unwrap_or_revert();
-
-    match events_mode {
+    match config.events_mode {
         EventsMode::NoEvents => {}
         EventsMode::CES => {
             if approve_all {
@@ -1340,46 +2341,279 @@ pub extern "C" fn is_approved_for_all() {
     )
     .unwrap_or_revert();
 
-    let owner_operator_item_key = utils::encode_key_and_value(&owner_key, &operator);
+    let is_operator = utils::is_operator(&owner_key, &operator);
 
-    let is_operator =
-        utils::get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key)
-            .unwrap_or_default();
+    let operator_cl_value =
+        CLValue::from_t(is_operator).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+
+    runtime::ret(operator_cl_value);
+}
+
+// Transfers token from token owner to specified account. Transfer will go through if caller is
+// owner or an approved account or an operator. Transfer will fail if OwnershipMode is Minter or
+// Assigned.
+#[no_mangle]
+pub extern "C" fn transfer() {
+    transfer_token(false)
+}
+
+// Transfers a token like transfer(), after which a contract receiving the token must acknowledge
+// it through its on_cep78_received entrypoint. Transfers to accounts are not checked.
+#[no_mangle]
+pub extern "C" fn safe_transfer() {
+    transfer_token(true)
+}
+
+fn transfer_token(safe: bool) {
+    let config = utils::get_config();
+
+    // If we are in minter or assigned mode we are not allowed to transfer ownership of token, hence
+    // we revert.
+    if let OwnershipMode::Minter | OwnershipMode::Assigned = config.ownership_mode {
+        runtime::revert(NFTCoreError::InvalidOwnershipMode)
+    }
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&config.identifier_mode);
+
+    let source_owner_key = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_SOURCE_KEY,
+        NFTCoreError::MissingAccountHash,
+        NFTCoreError::InvalidAccountHash,
+    )
+    .unwrap_or_revert();
+
+    let (caller, contract_package): (Key, Option<Key>) =
+        match utils::get_verified_caller(config.holder_mode).unwrap_or_revert() {
+            Caller::Session(account_hash) => (account_hash.into(), None),
+            Caller::StoredCaller(contract_hash, contract_package_hash) => {
+                (contract_hash.into(), Some(contract_package_hash.into()))
+            }
+        };
+
+    let target_owner_key = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_TARGET_KEY,
+        NFTCoreError::MissingAccountHash,
+        NFTCoreError::InvalidAccountHash,
+    )
+    .unwrap_or_revert();
+
+    let data = utils::get_optional_named_arg_with_user_errors::<Bytes>(
+        ARG_DATA,
+        NFTCoreError::InvalidTransferData,
+    )
+    .unwrap_or_default();
+
+    verify_token_transfer(
+        &token_identifier,
+        source_owner_key,
+        target_owner_key,
+        caller,
+        contract_package,
+        &data,
+        config.transfer_filter_contract_version,
+    );
+
+    maybe_migrate_token_hashes(&config, source_owner_key, target_owner_key);
+
+    record_token_transfer(
+        &token_identifier,
+        source_owner_key,
+        target_owner_key,
+        caller,
+        config.events_mode,
+    );
+
+    // Update the from_account and to_account balances
+    decrement_token_count(source_owner_key, 1u64);
+    increment_token_count(target_owner_key, 1u64);
+
+    let reporting_mode = config.reporting_mode;
+
+    let receipt = if let OwnerReverseLookupMode::Complete | OwnerReverseLookupMode::TransfersOnly =
+        reporting_mode
+    {
+        let source_owner_item_key = utils::encode_dictionary_item_key(source_owner_key);
+        let target_owner_item_key = utils::encode_dictionary_item_key(target_owner_key);
+
+        // Update to_account owned_tokens. Revert if owned_tokens list is not found
+        let tokens_count = utils::get_token_index(&token_identifier);
+        if OwnerReverseLookupMode::TransfersOnly == reporting_mode {
+            utils::add_page_entry_and_page_record(
+                tokens_count,
+                &source_owner_item_key,
+                false,
+                &config,
+            );
+        }
+
+        let (page_table_entry, page_uref) = utils::update_page_entry_and_page_record(
+            tokens_count,
+            &source_owner_item_key,
+            &target_owner_item_key,
+            &config,
+        );
+
+        let owned_tokens_actual_key = Key::dictionary(page_uref, source_owner_item_key.as_bytes());
+
+        let receipt_string = utils::get_receipt_name(page_table_entry, &config);
+
+        Some(
+            CLValue::from_t((receipt_string, owned_tokens_actual_key))
+                .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+        )
+    } else {
+        None
+    };
+
+    // The receiving contract is only called once the transfer is fully recorded.
+    if safe {
+        verify_token_receipt(
+            target_owner_key,
+            caller,
+            Some(source_owner_key),
+            &token_identifier,
+            &data,
+        );
+    }
+
+    if let Some(receipt) = receipt {
+        runtime::ret(receipt)
+    }
+}
+
+// Transfers a batch of tokens from the same owner to the same account, applying the same checks
+// as transfer() to every token. Balances and pages of both owners are only written once, and one
+// receipt is returned per page of the source owner that was updated.
+#[no_mangle]
+pub extern "C" fn transfer_batch() {
+    let config = utils::get_config();
+    if let OwnershipMode::Minter | OwnershipMode::Assigned = config.ownership_mode {
+        runtime::revert(NFTCoreError::InvalidOwnershipMode)
+    }
+
+    let token_identifiers = utils::get_token_identifiers_from_runtime_args(&config.identifier_mode);
+
+    let source_owner_key = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_SOURCE_KEY,
+        NFTCoreError::MissingAccountHash,
+        NFTCoreError::InvalidAccountHash,
+    )
+    .unwrap_or_revert();
+
+    let target_owner_key = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_TARGET_KEY,
+        NFTCoreError::MissingAccountHash,
+        NFTCoreError::InvalidAccountHash,
+    )
+    .unwrap_or_revert();
+
+    // The page records of the source and target owners are updated one after the other, which
+    // would leave them inconsistent if both were the same owner.
+    if source_owner_key == target_owner_key {
+        runtime::revert(NFTCoreError::InvalidAccount)
+    }
+
+    let (caller, contract_package): (Key, Option<Key>) =
+        match utils::get_verified_caller(config.holder_mode).unwrap_or_revert() {
+            Caller::Session(account_hash) => (account_hash.into(), None),
+            Caller::StoredCaller(contract_hash, contract_package_hash) => {
+                (contract_hash.into(), Some(contract_package_hash.into()))
+            }
+        };
+
+    let data = utils::get_optional_named_arg_with_user_errors::<Bytes>(
+        ARG_DATA,
+        NFTCoreError::InvalidTransferData,
+    )
+    .unwrap_or_default();
+
+    maybe_migrate_token_hashes(&config, source_owner_key, target_owner_key);
+
+    for token_identifier in &token_identifiers {
+        verify_token_transfer(
+            token_identifier,
+            source_owner_key,
+            target_owner_key,
+            caller,
+            contract_package,
+            &data,
+            config.transfer_filter_contract_version,
+        );
+        record_token_transfer(
+            token_identifier,
+            source_owner_key,
+            target_owner_key,
+            caller,
+            config.events_mode,
+        );
+    }
 
-    let operator_cl_value =
-        CLValue::from_t(is_operator).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    let number_of_tokens = token_identifiers.len() as u64;
+    decrement_token_count(source_owner_key, number_of_tokens);
+    increment_token_count(target_owner_key, number_of_tokens);
 
-    runtime::ret(operator_cl_value);
-}
+    let reporting_mode = config.reporting_mode;
 
-// Transfers token from token owner to specified account. Transfer will go through if caller is
-// owner or an approved account or an operator. Transfer will fail if OwnershipMode is Minter or
-// Assigned.
-#[no_mangle]
-pub extern "C" fn transfer() {
-    // If we are in minter or assigned mode we are not allowed to transfer ownership of token, hence
-    // we revert.
-    if let OwnershipMode::Minter | OwnershipMode::Assigned =
-        utils::get_ownership_mode().unwrap_or_revert()
+    if let OwnerReverseLookupMode::Complete | OwnerReverseLookupMode::TransfersOnly = reporting_mode
     {
-        runtime::revert(NFTCoreError::InvalidOwnershipMode)
-    }
+        let source_owner_item_key = utils::encode_dictionary_item_key(source_owner_key);
+        let target_owner_item_key = utils::encode_dictionary_item_key(target_owner_key);
 
-    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
-        IDENTIFIER_MODE,
-        NFTCoreError::MissingIdentifierMode,
-        NFTCoreError::InvalidIdentifierMode,
-    )
-    .try_into()
-    .unwrap_or_revert();
+        let tokens_counts: Vec<u64> = token_identifiers
+            .iter()
+            .map(utils::get_token_index)
+            .collect();
+        if OwnerReverseLookupMode::TransfersOnly == reporting_mode {
+            utils::add_page_entries_and_page_records(
+                &tokens_counts,
+                &source_owner_item_key,
+                false,
+                &config,
+            );
+        }
+
+        let receipts: Vec<(String, Key)> = utils::update_page_entries_and_page_records(
+            &tokens_counts,
+            &source_owner_item_key,
+            &target_owner_item_key,
+            &config,
+        )
+        .into_iter()
+        .map(|(page_table_entry, page_uref)| {
+            (
+                utils::get_receipt_name(page_table_entry, &config),
+                Key::dictionary(page_uref, source_owner_item_key.as_bytes()),
+            )
+        })
+        .collect();
 
-    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+        runtime::ret(
+            CLValue::from_t(receipts).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+        )
+    }
+}
 
+// Reverts unless the token is held by the source owner and the caller is the owner, the approved
+// account or an operator of the owner. The transfer filter contract, if any, is consulted as well.
+fn verify_token_transfer(
+    token_identifier: &TokenIdentifier,
+    source_owner_key: Key,
+    target_owner_key: Key,
+    caller: Key,
+    contract_package: Option<Key>,
+    data: &Bytes,
+    transfer_filter_contract_version: TransferFilterContractVersion,
+) {
     // We assume we cannot transfer burnt tokens
-    if utils::is_token_burned(&token_identifier) {
+    if utils::is_token_burned(token_identifier) {
         runtime::revert(NFTCoreError::PreviouslyBurntToken)
     }
 
+    // Tokens flagged as non transferable at mint stay with their owner
+    if utils::is_token_non_transferable(token_identifier) {
+        runtime::revert(NFTCoreError::NonTransferableToken)
+    }
+
     let owner = match utils::get_dictionary_value_from_key::<Key>(
         TOKEN_OWNERS,
         &token_identifier.get_dictionary_item_key(),
@@ -1388,43 +2622,19 @@ pub extern "C" fn transfer() {
         None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
     };
 
-    let source_owner_key = utils::get_named_arg_with_user_errors::<Key>(
-        ARG_SOURCE_KEY,
-        NFTCoreError::MissingAccountHash,
-        NFTCoreError::InvalidAccountHash,
-    )
-    .unwrap_or_revert();
-
     if source_owner_key != owner {
         runtime::revert(NFTCoreError::InvalidAccount);
     }
 
-    let (caller, contract_package): (Key, Option<Key>) =
-        match utils::get_verified_caller().unwrap_or_revert() {
-            Caller::Session(account_hash) => (account_hash.into(), None),
-            Caller::StoredCaller(contract_hash, contract_package_hash) => {
-                (contract_hash.into(), Some(contract_package_hash.into()))
-            }
-        };
-
     // Check if caller is owner
     let is_owner = owner == caller;
 
     // Check if caller is approved to execute transfer
-    let is_approved = !is_owner
-        && match utils::get_dictionary_value_from_key::<Option<Key>>(
-            APPROVED,
-            &token_identifier.get_dictionary_item_key(),
-        ) {
-            Some(Some(maybe_approved)) => caller == maybe_approved,
-            Some(None) | None => false,
-        };
+    let is_approved = !is_owner && utils::is_approved_spender(token_identifier, &caller);
 
     // Check if caller is operator to execute transfer
     let is_operator = if !is_owner && !is_approved {
-        let owner_operator_item_key = utils::encode_key_and_value(&source_owner_key, &caller);
-        utils::get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key)
-            .unwrap_or_default()
+        utils::is_operator(&source_owner_key, &caller)
     } else {
         false
     };
@@ -1441,10 +2651,7 @@ pub extern "C" fn transfer() {
             contract_package,
         ) {
             (true, Some(contract_package)) => {
-                let owner_operator_item_key =
-                    utils::encode_key_and_value(&source_owner_key, &contract_package);
-                utils::get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key)
-                    .unwrap_or_default()
+                utils::is_operator(&source_owner_key, &contract_package)
             }
             _ => false,
         }
@@ -1453,23 +2660,25 @@ pub extern "C" fn transfer() {
     };
 
     if let Some(filter_contract) = utils::get_transfer_filter_contract() {
-        let mut args = RuntimeArgs::new();
+        let mut args = get_token_id_args(token_identifier);
         args.insert(ARG_SOURCE_KEY, source_owner_key).unwrap();
-        args.insert(ARG_TARGET_KEY, owner).unwrap();
-
-        match &token_identifier {
-            TokenIdentifier::Index(idx) => {
-                args.insert(ARG_TOKEN_ID, *idx).unwrap();
-            }
-            TokenIdentifier::Hash(hash) => {
-                args.insert(ARG_TOKEN_ID, hash.clone()).unwrap();
+        args.insert(ARG_TARGET_KEY, target_owner_key).unwrap();
+
+        match transfer_filter_contract_version {
+            TransferFilterContractVersion::V1 => {
+                let result: TransferFilterContractResult =
+                    call_contract::<u8>(filter_contract, TRANSFER_FILTER_CONTRACT_METHOD, args)
+                        .into();
+                if TransferFilterContractResult::DenyTransfer == result {
+                    revert(NFTCoreError::TransferFilterContractDenied);
+                }
             }
-        }
+            TransferFilterContractVersion::V2 => {
+                args.insert(ARG_CALLER, caller).unwrap();
+                args.insert(ARG_DATA, data.clone()).unwrap();
 
-        let result: TransferFilterContractResult =
-            call_contract::<u8>(filter_contract, TRANSFER_FILTER_CONTRACT_METHOD, args).into();
-        if TransferFilterContractResult::DenyTransfer == result {
-            revert(NFTCoreError::TransferFilterContractDenied);
+                call_filter_contract(filter_contract, TRANSFER_FILTER_CONTRACT_METHOD_V2, args);
+            }
         }
     }
 
@@ -1477,26 +2686,82 @@ pub extern "C" fn transfer() {
     if !is_owner && !is_approved && !is_operator && !is_package_operator {
         runtime::revert(NFTCoreError::InvalidTokenOwner);
     }
+}
 
-    let target_owner_key = utils::get_named_arg_with_user_errors::<Key>(
-        ARG_TARGET_KEY,
-        NFTCoreError::MissingAccountHash,
-        NFTCoreError::InvalidAccountHash,
-    )
-    .unwrap_or_revert();
+// Filter and receiver contracts receive the token id as a u64 or as a String depending on the
+// identifier mode.
+fn get_token_id_args(token_identifier: &TokenIdentifier) -> RuntimeArgs {
+    let mut args = RuntimeArgs::new();
+    match token_identifier {
+        TokenIdentifier::Index(idx) => {
+            args.insert(ARG_TOKEN_ID, *idx).unwrap();
+        }
+        TokenIdentifier::Hash(hash) => {
+            args.insert(ARG_TOKEN_ID, hash.clone()).unwrap();
+        }
+    }
+    args
+}
+
+// Asks a contract receiving a token whether it can handle it, reverting unless the receipt is
+// acknowledged. Tokens received by accounts are not checked.
+fn verify_token_receipt(
+    target_owner_key: Key,
+    operator: Key,
+    source_owner_key: Option<Key>,
+    token_identifier: &TokenIdentifier,
+    data: &Bytes,
+) {
+    if let Key::Hash(receiver_contract) = target_owner_key {
+        let mut args = get_token_id_args(token_identifier);
+        args.insert(ARG_OPERATOR, operator).unwrap();
+        args.insert(ARG_FROM, source_owner_key).unwrap();
+        args.insert(ARG_DATA, data.clone()).unwrap();
+
+        let acknowledgment = call_contract::<String>(
+            ContractHash::new(receiver_contract),
+            RECEIVER_CONTRACT_METHOD,
+            args,
+        );
+        if acknowledgment != RECEIVER_CONTRACT_ACKNOWLEDGMENT {
+            runtime::revert(NFTCoreError::UnacknowledgedTokenReceipt)
+        }
+    }
+}
+
+// Calls a filter contract answering 0 to let the operation proceed. Any other value is a deny
+// reason, surfaced in the revert.
+fn call_filter_contract(filter_contract: ContractHash, method: &str, args: RuntimeArgs) {
+    let deny_reason = call_contract::<u8>(filter_contract, method, args);
+    if deny_reason != 0 {
+        revert(ApiError::User(
+            FILTER_CONTRACT_DENY_REASON_OFFSET + u16::from(deny_reason),
+        ));
+    }
+}
 
-    if NFTIdentifierMode::Hash == identifier_mode && runtime::get_key(OWNED_TOKENS).is_some() {
+fn maybe_migrate_token_hashes(config: &Config, source_owner_key: Key, target_owner_key: Key) {
+    if NFTIdentifierMode::Hash == config.identifier_mode && runtime::get_key(OWNED_TOKENS).is_some()
+    {
         if utils::should_migrate_token_hashes(source_owner_key) {
-            utils::migrate_token_hashes(source_owner_key)
+            utils::migrate_token_hashes(source_owner_key, config)
         }
 
         if utils::should_migrate_token_hashes(target_owner_key) {
-            utils::migrate_token_hashes(target_owner_key)
+            utils::migrate_token_hashes(target_owner_key, config)
         }
     }
+}
 
-    let target_owner_item_key = utils::encode_dictionary_item_key(target_owner_key);
-
+// Hands the token over to the target owner, clears its approval and emits the Transfer event.
+// Balances and pages are left to the caller to update.
+fn record_token_transfer(
+    token_identifier: &TokenIdentifier,
+    source_owner_key: Key,
+    target_owner_key: Key,
+    caller: Key,
+    events_mode: EventsMode,
+) {
     // Updated token_owners dictionary. Revert if token_owner not found.
     match utils::get_dictionary_value_from_key::<Key>(
         TOKEN_OWNERS,
@@ -1515,55 +2780,7 @@ pub extern "C" fn transfer() {
         None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
     }
 
-    let source_owner_item_key = utils::encode_dictionary_item_key(source_owner_key);
-
-    // Update the from_account balance
-    let updated_from_account_balance =
-        match utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &source_owner_item_key) {
-            Some(balance) => {
-                if balance > 0u64 {
-                    balance - 1u64
-                } else {
-                    // This should never happen...
-                    runtime::revert(NFTCoreError::FatalTokenIdDuplication);
-                }
-            }
-            None => {
-                // This should never happen...
-                runtime::revert(NFTCoreError::FatalTokenIdDuplication);
-            }
-        };
-    utils::upsert_dictionary_value_from_key(
-        TOKEN_COUNT,
-        &source_owner_item_key,
-        updated_from_account_balance,
-    );
-
-    // Update the to_account balance
-    let updated_to_account_balance =
-        match utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &target_owner_item_key) {
-            Some(balance) => balance + 1u64,
-            None => 1u64,
-        };
-
-    utils::upsert_dictionary_value_from_key(
-        TOKEN_COUNT,
-        &target_owner_item_key,
-        updated_to_account_balance,
-    );
-
-    utils::upsert_dictionary_value_from_key(
-        APPROVED,
-        &token_identifier.get_dictionary_item_key(),
-        Option::<Key>::None,
-    );
-
-    let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
-        EVENTS_MODE,
-        NFTCoreError::MissingEventsMode,
-        NFTCoreError::InvalidEventsMode,
-    ))
-    .unwrap_or_revert();
+    utils::set_approved_spenders(token_identifier, Vec::new());
 
     match events_mode {
         EventsMode::NoEvents => {}
@@ -1574,167 +2791,482 @@ pub extern "C" fn transfer() {
         }),
         EventsMode::CES => {
             // Emit Transfer event.
-            let spender = if caller == owner { None } else { Some(caller) };
+            let spender = if caller == source_owner_key {
+                None
+            } else {
+                Some(caller)
+            };
             casper_event_standard::emit(Transfer::new(
-                owner,
+                source_owner_key,
                 spender,
                 target_owner_key,
                 token_identifier.clone(),
             ));
         }
     }
+}
+
+// Returns the length of the Vec<String> in OWNED_TOKENS dictionary. If key is not found
+// it returns 0.
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    let owner_key = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_TOKEN_OWNER,
+        NFTCoreError::MissingAccountHash,
+        NFTCoreError::InvalidAccountHash,
+    )
+    .unwrap_or_revert();
 
-    let reporting_mode = utils::get_reporting_mode();
+    let owner_key_item_string = utils::encode_dictionary_item_key(owner_key);
 
-    if let OwnerReverseLookupMode::Complete | OwnerReverseLookupMode::TransfersOnly = reporting_mode
-    {
-        // Update to_account owned_tokens. Revert if owned_tokens list is not found
-        let tokens_count = utils::get_token_index(&token_identifier);
-        if OwnerReverseLookupMode::TransfersOnly == reporting_mode {
-            utils::add_page_entry_and_page_record(tokens_count, &source_owner_item_key, false);
+    let balance = utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &owner_key_item_string)
+        .unwrap_or(0u64);
+
+    let balance_cl_value =
+        CLValue::from_t(balance).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    runtime::ret(balance_cl_value);
+}
+
+// Returns at most limit tokens held by the owner after skipping the first offset ones, listed in
+// token index order. Token ids are returned as u64 in the Ordinal identifier mode and as String in
+// the Hash identifier mode.
+#[no_mangle]
+pub extern "C" fn tokens_of_owner() {
+    let config = utils::get_config();
+
+    let owner_key = get_enumerated_owner_from_runtime_args(config.reporting_mode);
+
+    let offset = utils::get_named_arg_with_user_errors::<u64>(
+        ARG_OFFSET,
+        NFTCoreError::MissingOffset,
+        NFTCoreError::InvalidOffset,
+    )
+    .unwrap_or_revert();
+
+    let limit = utils::get_named_arg_with_user_errors::<u64>(
+        ARG_LIMIT,
+        NFTCoreError::MissingLimit,
+        NFTCoreError::InvalidLimit,
+    )
+    .unwrap_or_revert();
+
+    let token_identifiers = utils::get_owned_token_identifiers(owner_key, offset, limit, &config);
+
+    let token_ids_cl_value = match config.identifier_mode {
+        NFTIdentifierMode::Ordinal => CLValue::from_t(
+            token_identifiers
+                .iter()
+                .map(|token_identifier| token_identifier.get_index().unwrap_or_revert())
+                .collect::<Vec<u64>>(),
+        ),
+        NFTIdentifierMode::Hash => CLValue::from_t(
+            token_identifiers
+                .into_iter()
+                .map(|token_identifier| token_identifier.get_hash().unwrap_or_revert())
+                .collect::<Vec<String>>(),
+        ),
+    }
+    .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    runtime::ret(token_ids_cl_value);
+}
+
+// Returns the token held by the owner at the given position of its tokens, in the same order as
+// tokens_of_owner. Reverts if the owner holds no more than index tokens.
+#[no_mangle]
+pub extern "C" fn token_of_owner_by_index() {
+    let config = utils::get_config();
+
+    let owner_key = get_enumerated_owner_from_runtime_args(config.reporting_mode);
+
+    let index = utils::get_named_arg_with_user_errors::<u64>(
+        ARG_INDEX,
+        NFTCoreError::MissingIndex,
+        NFTCoreError::InvalidIndex,
+    )
+    .unwrap_or_revert();
+
+    let token_identifier =
+        match utils::get_owned_token_identifiers(owner_key, index, 1u64, &config).pop() {
+            Some(token_identifier) => token_identifier,
+            None => runtime::revert(NFTCoreError::OwnerTokenIndexOutOfBounds),
+        };
+
+    let token_id_cl_value = match token_identifier {
+        TokenIdentifier::Index(token_index) => CLValue::from_t(token_index),
+        TokenIdentifier::Hash(token_hash) => CLValue::from_t(token_hash),
+    }
+    .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    runtime::ret(token_id_cl_value);
+}
+
+// Owned tokens can only be enumerated when the owner reverse lookup records every one of them in
+// pages, which the TransfersOnly mode does not do for tokens that were never transferred.
+fn get_enumerated_owner_from_runtime_args(reporting_mode: OwnerReverseLookupMode) -> Key {
+    if OwnerReverseLookupMode::Complete != reporting_mode {
+        runtime::revert(NFTCoreError::OwnerEnumerationNeedsReverseLookup)
+    }
+
+    utils::get_named_arg_with_user_errors::<Key>(
+        ARG_TOKEN_OWNER,
+        NFTCoreError::MissingAccountHash,
+        NFTCoreError::InvalidAccountHash,
+    )
+    .unwrap_or_revert()
+}
+
+// Returns the owner for a specified token identifier, throws error if token id is not valid
+#[no_mangle]
+pub extern "C" fn owner_of() {
+    let config = utils::get_config();
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&config.identifier_mode);
+
+    let number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
+        NUMBER_OF_MINTED_TOKENS,
+        NFTCoreError::MissingNumberOfMintedTokens,
+        NFTCoreError::InvalidNumberOfMintedTokens,
+    );
+
+    if let NFTIdentifierMode::Ordinal = config.identifier_mode {
+        // Revert if token_id is out of bounds
+        if token_identifier.get_index().unwrap_or_revert() >= number_of_minted_tokens {
+            runtime::revert(NFTCoreError::InvalidTokenIdentifier);
         }
+    }
 
-        let (page_table_entry, page_uref) = utils::update_page_entry_and_page_record(
-            tokens_count,
-            &source_owner_item_key,
-            &target_owner_item_key,
-        );
+    let token_owner = match utils::get_dictionary_value_from_key::<Key>(
+        TOKEN_OWNERS,
+        &token_identifier.get_dictionary_item_key(),
+    ) {
+        Some(token_owner) => token_owner,
+        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+    };
 
-        let owned_tokens_actual_key = Key::dictionary(page_uref, source_owner_item_key.as_bytes());
+    let token_owner_cl_value =
+        CLValue::from_t(token_owner).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+
+    runtime::ret(token_owner_cl_value);
+}
+
+// Returns the receiver of the royalty owed on a sale of the token at the given price, along with
+// the royalty amount. Tokens without a royalty set at mint use the collection default.
+#[no_mangle]
+pub extern "C" fn royalty_info() {
+    let config = utils::get_config();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&config.identifier_mode);
+
+    if utils::get_dictionary_value_from_key::<Key>(
+        TOKEN_OWNERS,
+        &token_identifier.get_dictionary_item_key(),
+    )
+    .is_none()
+    {
+        runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey)
+    }
+
+    let sale_price = utils::get_named_arg_with_user_errors::<U512>(
+        ARG_SALE_PRICE,
+        NFTCoreError::MissingSalePrice,
+        NFTCoreError::InvalidSalePrice,
+    )
+    .unwrap_or_revert();
+
+    let (royalty_receiver, royalty_basis_points) =
+        utils::get_dictionary_value_from_key::<(Key, u64)>(
+            TOKEN_ROYALTIES,
+            &token_identifier.get_dictionary_item_key(),
+        )
+        .unwrap_or_else(get_default_royalty);
+
+    // Split the price to compute the royalty without overflowing on large sale prices.
+    let royalty_basis_points = U512::from(royalty_basis_points);
+    let max_royalty_basis_points = U512::from(MAX_ROYALTY_BASIS_POINTS);
+    let royalty_amount = sale_price / max_royalty_basis_points * royalty_basis_points
+        + sale_price % max_royalty_basis_points * royalty_basis_points / max_royalty_basis_points;
+
+    runtime::ret(
+        CLValue::from_t((royalty_receiver, royalty_amount))
+            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    )
+}
+
+// Updates the base the token URIs are built from. Can only be called by the installer.
+#[no_mangle]
+pub extern "C" fn set_base_uri() {
+    let installer = utils::get_account_hash(
+        INSTALLER,
+        NFTCoreError::MissingInstaller,
+        NFTCoreError::InvalidInstaller,
+    );
+
+    if installer != runtime::get_caller() {
+        runtime::revert(NFTCoreError::InvalidAccount);
+    }
+
+    let base_uri: String = utils::get_named_arg_with_user_errors(
+        ARG_BASE_URI,
+        NFTCoreError::MissingBaseUri,
+        NFTCoreError::InvalidBaseUri,
+    )
+    .unwrap_or_revert();
+
+    let base_uri_uref = utils::get_uref(
+        BASE_URI,
+        NFTCoreError::MissingBaseUri,
+        NFTCoreError::InvalidBaseUri,
+    );
+    storage::write(base_uri_uref, base_uri);
+}
 
-        let receipt_string = utils::get_receipt_name(page_table_entry);
+// Updates the JSON document describing the collection. Can only be called by the installer.
+#[no_mangle]
+pub extern "C" fn set_collection_metadata() {
+    let config = utils::get_config();
+    let installer = utils::get_account_hash(
+        INSTALLER,
+        NFTCoreError::MissingInstaller,
+        NFTCoreError::InvalidInstaller,
+    );
+
+    if installer != runtime::get_caller() {
+        runtime::revert(NFTCoreError::InvalidAccount);
+    }
+
+    let collection_metadata: String = utils::get_named_arg_with_user_errors(
+        ARG_COLLECTION_METADATA,
+        NFTCoreError::MissingCollectionMetadata,
+        NFTCoreError::InvalidCollectionMetadata,
+    )
+    .unwrap_or_revert();
+
+    metadata::validate_collection_metadata(&collection_metadata).unwrap_or_revert();
+
+    let collection_metadata_uref = utils::get_uref(
+        COLLECTION_METADATA,
+        NFTCoreError::MissingCollectionMetadata,
+        NFTCoreError::InvalidCollectionMetadata,
+    );
+    storage::write(collection_metadata_uref, collection_metadata.clone());
 
-        let receipt = CLValue::from_t((receipt_string, owned_tokens_actual_key))
-            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
-        runtime::ret(receipt)
+    // Emit CollectionMetadataUpdated event.
+    match config.events_mode {
+        EventsMode::NoEvents => {}
+        EventsMode::CES => {
+            casper_event_standard::emit(CollectionMetadataUpdated::new(collection_metadata));
+        }
+        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::CollectionMetadataUpdate),
     }
 }
 
-// Returns the length of the Vec<String> in OWNED_TOKENS dictionary. If key is not found
-// it returns 0.
+// Returns the JSON document describing the collection. Throws error if no collection metadata was
+// set.
 #[no_mangle]
-pub extern "C" fn balance_of() {
-    let owner_key = utils::get_named_arg_with_user_errors::<Key>(
-        ARG_TOKEN_OWNER,
-        NFTCoreError::MissingAccountHash,
-        NFTCoreError::InvalidAccountHash,
-    )
-    .unwrap_or_revert();
+pub extern "C" fn collection_metadata() {
+    let collection_metadata: String = utils::get_stored_value_with_user_errors(
+        COLLECTION_METADATA,
+        NFTCoreError::MissingCollectionMetadata,
+        NFTCoreError::InvalidCollectionMetadata,
+    );
 
-    let owner_key_item_string = utils::encode_dictionary_item_key(owner_key);
+    if collection_metadata.is_empty() {
+        runtime::revert(NFTCoreError::MissingCollectionMetadata)
+    }
 
-    let balance = utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &owner_key_item_string)
-        .unwrap_or(0u64);
+    let collection_metadata_cl_value = CLValue::from_t(collection_metadata)
+        .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    runtime::ret(collection_metadata_cl_value);
+}
 
-    let balance_cl_value =
-        CLValue::from_t(balance).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
-    runtime::ret(balance_cl_value);
+// Returns the number of minted and burnt tokens, the circulating supply and the number of holders
+// of the collection, keyed by the names of the named keys holding them. Throws error if the
+// statistics of an upgraded contract were not seeded.
+#[no_mangle]
+pub extern "C" fn collection_stats() {
+    if !collection_stats_tracked() {
+        runtime::revert(NFTCoreError::UntrackedCollectionStats)
+    }
+
+    let mut collection_stats: BTreeMap<String, u64> = BTreeMap::new();
+    for (name, missing, invalid) in [
+        (
+            NUMBER_OF_MINTED_TOKENS,
+            NFTCoreError::MissingNumberOfMintedTokens,
+            NFTCoreError::InvalidNumberOfMintedTokens,
+        ),
+        (
+            BURNT_TOKENS_COUNT,
+            NFTCoreError::MissingBurntTokensCount,
+            NFTCoreError::InvalidBurntTokensCount,
+        ),
+        (
+            CIRCULATING_SUPPLY,
+            NFTCoreError::MissingCirculatingSupply,
+            NFTCoreError::InvalidCirculatingSupply,
+        ),
+        (
+            HOLDER_COUNT,
+            NFTCoreError::MissingHolderCount,
+            NFTCoreError::InvalidHolderCount,
+        ),
+    ] {
+        collection_stats.insert(
+            name.to_string(),
+            utils::get_stored_value_with_user_errors::<u64>(name, missing, invalid),
+        );
+    }
+
+    let collection_stats_cl_value = CLValue::from_t(collection_stats)
+        .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    runtime::ret(collection_stats_cl_value);
 }
 
-// Returns the owner for a specified token identifier, throws error if token id is not valid
+// Returns the URI of the token built from the base URI. Every `{id}` placeholder of the base URI
+// is replaced by the token id or hash, which is otherwise appended to it. Throws error if token
+// id is not valid or no base URI was set.
 #[no_mangle]
-pub extern "C" fn owner_of() {
-    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
-        IDENTIFIER_MODE,
-        NFTCoreError::MissingIdentifierMode,
-        NFTCoreError::InvalidIdentifierMode,
-    )
-    .try_into()
-    .unwrap_or_revert();
+pub extern "C" fn token_uri() {
+    let config = utils::get_config();
 
-    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&config.identifier_mode);
 
-    let number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
-        NUMBER_OF_MINTED_TOKENS,
-        NFTCoreError::MissingNumberOfMintedTokens,
-        NFTCoreError::InvalidNumberOfMintedTokens,
+    if utils::get_dictionary_value_from_key::<Key>(
+        TOKEN_OWNERS,
+        &token_identifier.get_dictionary_item_key(),
+    )
+    .is_none()
+    {
+        runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey)
+    }
+
+    let base_uri: String = utils::get_stored_value_with_user_errors(
+        BASE_URI,
+        NFTCoreError::MissingBaseUri,
+        NFTCoreError::InvalidBaseUri,
     );
 
-    if let NFTIdentifierMode::Ordinal = identifier_mode {
-        // Revert if token_id is out of bounds
-        if token_identifier.get_index().unwrap_or_revert() >= number_of_minted_tokens {
-            runtime::revert(NFTCoreError::InvalidTokenIdentifier);
-        }
+    if base_uri.is_empty() {
+        runtime::revert(NFTCoreError::MissingBaseUri)
     }
 
-    let token_owner = match utils::get_dictionary_value_from_key::<Key>(
+    let token_id = token_identifier.to_string();
+    let token_uri = if base_uri.contains(TOKEN_URI_ID_PLACEHOLDER) {
+        base_uri.replace(TOKEN_URI_ID_PLACEHOLDER, &token_id)
+    } else {
+        format!("{base_uri}{token_id}")
+    };
+
+    let token_uri_cl_value =
+        CLValue::from_t(token_uri).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    runtime::ret(token_uri_cl_value);
+}
+
+// Returns whether the token was flagged as non transferable at mint, throws error if token id is
+// not valid
+#[no_mangle]
+pub extern "C" fn is_non_transferable() {
+    let config = utils::get_config();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&config.identifier_mode);
+
+    if utils::get_dictionary_value_from_key::<Key>(
         TOKEN_OWNERS,
         &token_identifier.get_dictionary_item_key(),
-    ) {
-        Some(token_owner) => token_owner,
-        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
-    };
+    )
+    .is_none()
+    {
+        runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey)
+    }
 
-    let token_owner_cl_value =
-        CLValue::from_t(token_owner).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    let non_transferable_cl_value =
+        CLValue::from_t(utils::is_token_non_transferable(&token_identifier))
+            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
 
-    runtime::ret(token_owner_cl_value);
+    runtime::ret(non_transferable_cl_value);
 }
 
 #[no_mangle]
 pub extern "C" fn metadata() {
+    let config = utils::get_config();
     let number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
         NUMBER_OF_MINTED_TOKENS,
         NFTCoreError::MissingNumberOfMintedTokens,
         NFTCoreError::InvalidNumberOfMintedTokens,
     );
 
-    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
-        IDENTIFIER_MODE,
-        NFTCoreError::MissingIdentifierMode,
-        NFTCoreError::InvalidIdentifierMode,
-    )
-    .try_into()
-    .unwrap_or_revert();
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&config.identifier_mode);
 
-    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
-
-    if let NFTIdentifierMode::Ordinal = identifier_mode {
+    if let NFTIdentifierMode::Ordinal = config.identifier_mode {
         // Revert if token_id is out of bounds
         if token_identifier.get_index().unwrap_or_revert() >= number_of_minted_tokens {
             runtime::revert(NFTCoreError::InvalidTokenIdentifier);
         }
     }
 
-    let metadata_kind_list: BTreeMap<NFTMetadataKind, Requirement> =
-        utils::get_stored_value_with_user_errors(
-            NFT_METADATA_KINDS,
-            NFTCoreError::MissingNFTMetadataKind,
-            NFTCoreError::InvalidNFTMetadataKind,
-        );
+    // A metadata kind may be passed to read the token metadata as validated against that kind
+    // instead of the first required kind.
+    let metadata = match utils::get_optional_named_arg_with_user_errors::<u8>(
+        ARG_NFT_METADATA_KIND,
+        NFTCoreError::InvalidNFTMetadataKind,
+    ) {
+        Some(metadata_kind) => {
+            let metadata_kind: NFTMetadataKind = metadata_kind.try_into().unwrap_or_revert();
+            get_token_metadata_of_kind(&token_identifier, metadata_kind, &config)
+        }
+        None => get_token_metadata(&token_identifier, &config),
+    };
+    runtime::ret(
+        CLValue::from_t(metadata).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    );
+}
 
-    for (&metadata_kind, required) in metadata_kind_list.iter() {
+// Returns the metadata of a token stored under the required metadata kind.
+fn get_token_metadata(token_identifier: &TokenIdentifier, config: &Config) -> String {
+    for (&metadata_kind, required) in config.nft_metadata_kinds.iter() {
         match required {
             &Requirement::Required => {
-                let metadata = utils::get_dictionary_value_from_key::<String>(
-                    &metadata::get_metadata_dictionary_name(&metadata_kind),
+                return get_token_metadata_of_kind(token_identifier, metadata_kind, config);
+            }
+            _ => continue,
+        }
+    }
+    runtime::revert(NFTCoreError::MissingTokenMetaData)
+}
+
+// Returns the metadata of a token as validated against the given metadata kind. Compact records
+// are rendered into the view of the kind, provided the metadata validated against it at mint or on
+// the last update, in which case it is not validated again.
+fn get_token_metadata_of_kind(
+    token_identifier: &TokenIdentifier,
+    metadata_kind: NFTMetadataKind,
+    config: &Config,
+) -> String {
+    match config.metadata_storage_mode {
+        MetadataStorageMode::PerKind => utils::get_dictionary_value_from_key::<String>(
+            &metadata::get_metadata_dictionary_name(&metadata_kind),
+            &token_identifier.get_dictionary_item_key(),
+        )
+        .unwrap_or_revert_with(NFTCoreError::InvalidTokenIdentifier),
+        MetadataStorageMode::Compact => {
+            let (token_metadata, validated_kinds) =
+                utils::get_dictionary_value_from_key::<(String, u8)>(
+                    METADATA_COMPACT,
                     &token_identifier.get_dictionary_item_key(),
                 )
                 .unwrap_or_revert_with(NFTCoreError::InvalidTokenIdentifier);
-                runtime::ret(
-                    CLValue::from_t(metadata)
-                        .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
-                );
+            if validated_kinds & (1 << metadata_kind as u8) == 0 {
+                runtime::revert(NFTCoreError::MissingTokenMetaData)
             }
-            _ => continue,
+            metadata::render_metadata(&metadata_kind, token_metadata).unwrap_or_revert()
         }
     }
-    runtime::revert(NFTCoreError::MissingTokenMetaData)
 }
 
 // Returns approved account hash for a specified token identifier, throws error if token id is not
 // valid
 #[no_mangle]
 pub extern "C" fn get_approved() {
-    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
-        IDENTIFIER_MODE,
-        NFTCoreError::MissingIdentifierMode,
-        NFTCoreError::InvalidIdentifierMode,
-    )
-    .try_into()
-    .unwrap_or_revert();
-
-    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+    let config = utils::get_config();
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&config.identifier_mode);
 
     // Revert if token_id is out of bounds.
     let number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
@@ -1743,7 +3275,7 @@ pub extern "C" fn get_approved() {
         NFTCoreError::InvalidNumberOfMintedTokens,
     );
 
-    if let NFTIdentifierMode::Ordinal = identifier_mode {
+    if let NFTIdentifierMode::Ordinal = config.identifier_mode {
         // Revert if token_id is out of bounds
         if token_identifier.get_index().unwrap_or_revert() >= number_of_minted_tokens {
             runtime::revert(NFTCoreError::InvalidTokenIdentifier);
@@ -1755,13 +3287,9 @@ pub extern "C" fn get_approved() {
         runtime::revert(NFTCoreError::PreviouslyBurntToken)
     }
 
-    let maybe_approved = utils::get_dictionary_value_from_key::<Option<Key>>(
-        APPROVED,
-        &token_identifier.get_dictionary_item_key(),
-    )
-    .unwrap_or(None);
+    let approved_spenders = utils::get_active_approved_spenders(&token_identifier);
 
-    let approved_cl_value = CLValue::from_t(maybe_approved)
+    let approved_cl_value = CLValue::from_t(approved_spenders)
         .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
 
     runtime::ret(approved_cl_value);
@@ -1769,51 +3297,71 @@ pub extern "C" fn get_approved() {
 
 #[no_mangle]
 pub extern "C" fn set_token_metadata() {
-    let metadata_mutability: MetadataMutability = utils::get_stored_value_with_user_errors::<u8>(
-        METADATA_MUTABILITY,
-        NFTCoreError::MissingMetadataMutability,
-        NFTCoreError::InvalidMetadataMutability,
-    )
-    .try_into()
-    .unwrap_or_revert();
+    let config = utils::get_config();
+    let metadata_mutability = config.metadata_mutability;
 
     if let MetadataMutability::Immutable = metadata_mutability {
         runtime::revert(NFTCoreError::ForbiddenMetadataUpdate)
     }
 
-    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
-        IDENTIFIER_MODE,
-        NFTCoreError::MissingIdentifierMode,
-        NFTCoreError::InvalidIdentifierMode,
-    )
-    .try_into()
-    .unwrap_or_revert();
-
-    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&config.identifier_mode);
 
     let token_owner = utils::get_dictionary_value_from_key::<Key>(
         TOKEN_OWNERS,
         &token_identifier.get_dictionary_item_key(),
     );
 
-    if let Some(token_owner_key) = token_owner {
-        let caller: Key = match utils::get_verified_caller().unwrap_or_revert() {
-            Caller::Session(account_hash) => account_hash.into(),
-            Caller::StoredCaller(contract_hash, _) => contract_hash.into(),
-        };
-        if caller != token_owner_key {
-            runtime::revert(NFTCoreError::InvalidTokenOwner)
-        }
-    } else {
-        runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey)
+    // Frozen metadata is final whoever the caller is.
+    if utils::is_token_metadata_frozen(&token_identifier) {
+        runtime::revert(NFTCoreError::FrozenMetadata)
     }
 
-    let metadata_kinds: BTreeMap<NFTMetadataKind, Requirement> =
-        utils::get_stored_value_with_user_errors(
-            NFT_METADATA_KINDS,
-            NFTCoreError::MissingNFTMetadataKind,
-            NFTCoreError::InvalidNFTMetadataKind,
-        );
+    let token_owner_key = match token_owner {
+        Some(token_owner_key) => token_owner_key,
+        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+    };
+
+    let (caller, contract_package): (Key, Option<Key>) =
+        match utils::get_verified_caller(config.holder_mode).unwrap_or_revert() {
+            Caller::Session(account_hash) => (account_hash.into(), None),
+            Caller::StoredCaller(contract_hash, contract_package_hash) => {
+                (contract_hash.into(), Some(contract_package_hash.into()))
+            }
+        };
+
+    let is_owner = || caller == token_owner_key;
+    let is_issuer = || {
+        utils::get_dictionary_value_from_key::<Key>(
+            TOKEN_ISSUERS,
+            &token_identifier.get_dictionary_item_key(),
+        ) == Some(caller)
+    };
+    let is_installer = || {
+        let installer: Key = utils::get_account_hash(
+            INSTALLER,
+            NFTCoreError::MissingInstaller,
+            NFTCoreError::InvalidInstaller,
+        )
+        .into();
+        caller == installer
+    };
+
+    let is_allowed = match metadata_mutability {
+        MetadataMutability::Immutable => false,
+        MetadataMutability::Mutable => is_owner(),
+        MetadataMutability::IssuerOnly => is_issuer(),
+        MetadataMutability::OwnerOrIssuer => is_owner() || is_issuer(),
+        MetadataMutability::Installer => is_installer(),
+    };
+
+    // Metadata editors may update the metadata of any token.
+    if !is_allowed && !utils::caller_has_role(Role::MetadataEditor, caller, contract_package) {
+        let error = match metadata_mutability {
+            MetadataMutability::Mutable => NFTCoreError::InvalidTokenOwner,
+            _ => NFTCoreError::InvalidMetadataEditor,
+        };
+        runtime::revert(error)
+    }
 
     let updated_token_metadata: String = utils::get_named_arg_with_user_errors(
         ARG_TOKEN_META_DATA,
@@ -1822,37 +3370,10 @@ pub extern "C" fn set_token_metadata() {
     )
     .unwrap_or_revert();
 
-    for (metadata_kind, required) in metadata_kinds {
-        if required == Requirement::Unneeded {
-            continue;
-        }
-        let token_metadata_validation =
-            metadata::validate_metadata(&metadata_kind, updated_token_metadata.clone());
-        match token_metadata_validation {
-            Ok(validated_token_metadata) => {
-                utils::upsert_dictionary_value_from_key(
-                    &metadata::get_metadata_dictionary_name(&metadata_kind),
-                    &token_identifier.get_dictionary_item_key(),
-                    validated_token_metadata,
-                );
-            }
-            Err(err) => {
-                if required == Requirement::Required {
-                    runtime::revert(err);
-                }
-            }
-        }
-    }
-
-    let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
-        EVENTS_MODE,
-        NFTCoreError::MissingEventsMode,
-        NFTCoreError::InvalidEventsMode,
-    ))
-    .unwrap_or_revert();
+    store_token_metadata(&config, &token_identifier, &updated_token_metadata);
 
     // Emit MetadataUpdate event.
-    match events_mode {
+    match config.events_mode {
         EventsMode::NoEvents => {}
         EventsMode::CES => {
             casper_event_standard::emit(MetadataUpdated::new(
@@ -1866,22 +3387,75 @@ pub extern "C" fn set_token_metadata() {
     }
 }
 
+// Marks the metadata of a token as final. Only the owner or the issuer of the token can freeze
+// it, after which set_token_metadata reverts for that token.
+#[no_mangle]
+pub extern "C" fn freeze_metadata() {
+    let config = utils::get_config();
+
+    if let MetadataMutability::Immutable = config.metadata_mutability {
+        runtime::revert(NFTCoreError::ForbiddenMetadataUpdate)
+    }
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&config.identifier_mode);
+
+    if utils::is_token_burned(&token_identifier) {
+        runtime::revert(NFTCoreError::PreviouslyBurntToken)
+    }
+
+    if utils::is_token_metadata_frozen(&token_identifier) {
+        runtime::revert(NFTCoreError::FrozenMetadata)
+    }
+
+    let token_owner_key = match utils::get_dictionary_value_from_key::<Key>(
+        TOKEN_OWNERS,
+        &token_identifier.get_dictionary_item_key(),
+    ) {
+        Some(token_owner_key) => token_owner_key,
+        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+    };
+
+    let caller: Key = match utils::get_verified_caller(config.holder_mode).unwrap_or_revert() {
+        Caller::Session(account_hash) => account_hash.into(),
+        Caller::StoredCaller(contract_hash, _) => contract_hash.into(),
+    };
+
+    let is_issuer = || {
+        utils::get_dictionary_value_from_key::<Key>(
+            TOKEN_ISSUERS,
+            &token_identifier.get_dictionary_item_key(),
+        ) == Some(caller)
+    };
+
+    if caller != token_owner_key && !is_issuer() {
+        runtime::revert(NFTCoreError::InvalidTokenOwner)
+    }
+
+    utils::upsert_dictionary_value_from_key(
+        FROZEN_METADATA,
+        &token_identifier.get_dictionary_item_key(),
+        (),
+    );
+
+    // Emit MetadataFrozen event.
+    match config.events_mode {
+        EventsMode::NoEvents => {}
+        EventsMode::CES => {
+            casper_event_standard::emit(MetadataFrozen::new(token_identifier));
+        }
+        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::MetadataFreeze {
+            token_id: token_identifier,
+        }),
+    }
+}
+
 fn update_token_supply() -> (u64, u64) {
     let total_token_supply: u64 = match utils::get_optional_named_arg_with_user_errors(
         ARG_TOTAL_TOKEN_SUPPLY,
         NFTCoreError::InvalidTotalTokenSupply,
     ) {
         Some(total_token_supply_arg) => {
-            if total_token_supply_arg
-                > utils::get_stored_value_with_user_errors::<u64>(
-                    TOTAL_TOKEN_SUPPLY,
-                    NFTCoreError::MissingTotalTokenSupply,
-                    NFTCoreError::InvalidTotalTokenSupply,
-                )
-            {
-                runtime::revert(NFTCoreError::CannotUpgradeToMoreSupply)
-            }
-
+            utils::check_total_token_supply(total_token_supply_arg, utils::get_page_size());
             let total_token_supply_uref = utils::get_uref(
                 ARG_TOTAL_TOKEN_SUPPLY,
                 NFTCoreError::MissingTotalTokenSupply,
@@ -1913,11 +3487,45 @@ fn update_token_supply() -> (u64, u64) {
     (total_token_supply, current_number_of_minted_tokens)
 }
 
+// Collections keeping owner pages only take a raised total token supply on upgrade, for which the
+// page limit is raised. A lower total token supply reverts, as the pages already sized for the
+// current supply are not shrunk.
+fn raise_token_supply() {
+    let total_token_supply: u64 = match utils::get_optional_named_arg_with_user_errors(
+        ARG_TOTAL_TOKEN_SUPPLY,
+        NFTCoreError::InvalidTotalTokenSupply,
+    ) {
+        Some(total_token_supply) => total_token_supply,
+        None => return,
+    };
+
+    let total_token_supply_uref = utils::get_uref(
+        TOTAL_TOKEN_SUPPLY,
+        NFTCoreError::MissingTotalTokenSupply,
+        NFTCoreError::InvalidTotalTokenSupply,
+    );
+    let current_total_token_supply: u64 = utils::read_with_user_errors(
+        total_token_supply_uref,
+        NFTCoreError::MissingTotalTokenSupply,
+        NFTCoreError::InvalidTotalTokenSupply,
+    );
+
+    if total_token_supply < current_total_token_supply {
+        runtime::revert(NFTCoreError::CannotUpgradeToLessSupply)
+    }
+
+    if total_token_supply > current_total_token_supply {
+        utils::check_total_token_supply(total_token_supply, utils::get_page_size());
+        storage::write(total_token_supply_uref, total_token_supply);
+        utils::raise_page_limit(total_token_supply);
+    }
+}
+
 fn do_migration() {
     let (total_token_supply, current_number_of_minted_tokens) = update_token_supply();
     storage::new_dictionary(PAGE_TABLE)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
-    let page_table_width = utils::max_number_of_pages(total_token_supply);
+    let page_table_width = utils::max_number_of_pages(total_token_supply, utils::get_page_size());
     runtime::put_key(PAGE_LIMIT, storage::new_uref(page_table_width).into());
     runtime::put_key(
         REPORTING_MODE,
@@ -1971,18 +3579,64 @@ fn do_migration() {
 
 #[no_mangle]
 pub extern "C" fn migrate() {
+    // Only the current admin can upgrade the contract, so an admin transfer also takes the upgrades
+    // away from the previous admin even if it still holds the package access URef.
+    let admin = utils::get_account_hash(
+        INSTALLER,
+        NFTCoreError::MissingInstaller,
+        NFTCoreError::InvalidInstaller,
+    );
+    if admin != runtime::get_caller() {
+        runtime::revert(NFTCoreError::InvalidAccount);
+    }
+
     let reporting_mode = if runtime::get_key(REPORTING_MODE).is_some() {
         Some(utils::get_reporting_mode())
     } else {
         None
     };
 
+    // Contracts installed before the bitmap page encoding keep writing bool list pages unless the
+    // upgrade opts into the bitmap encoding. Existing records are converted as they are rewritten.
+    match utils::get_optional_named_arg_with_user_errors::<u8>(
+        ARG_PAGE_ENCODING,
+        NFTCoreError::InvalidPageEncoding,
+    ) {
+        Some(page_encoding) => {
+            let page_encoding: PageEncoding = page_encoding.try_into().unwrap_or_revert();
+            runtime::put_key(PAGE_ENCODING, storage::new_uref(page_encoding as u8).into());
+        }
+        None => {
+            if runtime::get_key(PAGE_ENCODING).is_none() {
+                runtime::put_key(
+                    PAGE_ENCODING,
+                    storage::new_uref(PageEncoding::BoolList as u8).into(),
+                );
+            }
+        }
+    }
+
+    // Contracts installed before the page size was configurable track 1000 tokens per page.
+    if runtime::get_key(PAGE_SIZE).is_none() {
+        runtime::put_key(PAGE_SIZE, storage::new_uref(DEFAULT_PAGE_SIZE).into());
+    }
+
+    // Contracts installed before the compact metadata storage keep a copy per metadata kind.
+    if runtime::get_key(METADATA_STORAGE_MODE).is_none() {
+        runtime::put_key(
+            METADATA_STORAGE_MODE,
+            storage::new_uref(MetadataStorageMode::PerKind as u8).into(),
+        );
+    }
+
     if [None, Some(OwnerReverseLookupMode::NoLookUp)].contains(&reporting_mode) {
         if utils::requires_rlo_migration() && runtime::get_key(RLO_MFLAG).is_none() {
             do_migration();
         } else {
             update_token_supply();
         }
+    } else {
+        raise_token_supply();
     }
 
     let metadata_kind: NFTMetadataKind = utils::get_stored_value_with_user_errors(
@@ -2101,36 +3755,158 @@ pub extern "C" fn migrate() {
         storage::new_dictionary(OPERATORS)
             .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     }
+    // Add APPROVED_SPENDERS dict
+    if runtime::get_key(APPROVED_SPENDERS).is_none() {
+        storage::new_dictionary(APPROVED_SPENDERS)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    // Add APPROVAL_EXPIRIES dict
+    if runtime::get_key(APPROVAL_EXPIRIES).is_none() {
+        storage::new_dictionary(APPROVAL_EXPIRIES)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    // Add OPERATOR_EXPIRIES dict
+    if runtime::get_key(OPERATOR_EXPIRIES).is_none() {
+        storage::new_dictionary(OPERATOR_EXPIRIES)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+
+    // Contracts installed before royalties were introduced default to paying no royalties to
+    // the installer. The default receiver and basis points can be set on any upgrade.
+    if runtime::get_key(ROYALTY_RECEIVER).is_none() {
+        let installer =
+            runtime::get_key(INSTALLER).unwrap_or_revert_with(NFTCoreError::MissingInstallerKey);
+        runtime::put_key(ROYALTY_RECEIVER, storage::new_uref(installer).into());
+        runtime::put_key(ROYALTY_BASIS_POINTS, storage::new_uref(0u64).into());
+        storage::new_dictionary(TOKEN_ROYALTIES)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    if let Some(royalty_receiver) = utils::get_optional_named_arg_with_user_errors::<Key>(
+        ARG_ROYALTY_RECEIVER,
+        NFTCoreError::InvalidRoyaltyReceiver,
+    ) {
+        let royalty_receiver_uref = utils::get_uref(
+            ROYALTY_RECEIVER,
+            NFTCoreError::MissingRoyaltyReceiver,
+            NFTCoreError::InvalidRoyaltyReceiver,
+        );
+        storage::write(royalty_receiver_uref, royalty_receiver);
+    }
+    if let Some(royalty_basis_points) = utils::get_optional_named_arg_with_user_errors::<u64>(
+        ARG_ROYALTY_BASIS_POINTS,
+        NFTCoreError::InvalidRoyaltyBasisPoints,
+    ) {
+        if royalty_basis_points > MAX_ROYALTY_BASIS_POINTS {
+            runtime::revert(NFTCoreError::InvalidRoyaltyBasisPoints)
+        }
+        let royalty_basis_points_uref = utils::get_uref(
+            ROYALTY_BASIS_POINTS,
+            NFTCoreError::MissingRoyaltyBasisPoints,
+            NFTCoreError::InvalidRoyaltyBasisPoints,
+        );
+        storage::write(royalty_basis_points_uref, royalty_basis_points);
+    }
+    // Add PENDING_ADMIN named key
+    if runtime::get_key(PENDING_ADMIN).is_none() {
+        runtime::put_key(PENDING_ADMIN, storage::new_uref(None::<Key>).into());
+    }
+    // Add role dicts, the minter role being held through ACL_WHITELIST
+    for role_dictionary_name in [METADATA_EDITORS, PAUSERS, BURNERS] {
+        if runtime::get_key(role_dictionary_name).is_none() {
+            storage::new_dictionary(role_dictionary_name)
+                .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+        }
+    }
+    // Add BASE_URI
+    if runtime::get_key(BASE_URI).is_none() {
+        runtime::put_key(BASE_URI, storage::new_uref(String::new()).into());
+    }
+    // Add COLLECTION_METADATA
+    if runtime::get_key(COLLECTION_METADATA).is_none() {
+        runtime::put_key(COLLECTION_METADATA, storage::new_uref(String::new()).into());
+    }
+    // Add FROZEN_METADATA dict
+    if runtime::get_key(FROZEN_METADATA).is_none() {
+        storage::new_dictionary(FROZEN_METADATA)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    // Add NON_TRANSFERABLE_TOKENS dict
+    if runtime::get_key(NON_TRANSFERABLE_TOKENS).is_none() {
+        storage::new_dictionary(NON_TRANSFERABLE_TOKENS)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    // Add TRANSFER_FILTER_CONTRACT_VERSION
+    if runtime::get_key(TRANSFER_FILTER_CONTRACT_VERSION).is_none() {
+        runtime::put_key(
+            TRANSFER_FILTER_CONTRACT_VERSION,
+            storage::new_uref(TransferFilterContractVersion::V1 as u8).into(),
+        );
+    }
+    // Add MINT_FILTER_CONTRACT and BURN_FILTER_CONTRACT
+    for filter_contract_name in [MINT_FILTER_CONTRACT, BURN_FILTER_CONTRACT] {
+        if runtime::get_key(filter_contract_name).is_none() {
+            runtime::put_key(
+                filter_contract_name,
+                storage::new_uref(None::<ContractHash>).into(),
+            );
+        }
+    }
+
+    // Add collection statistics. The burnt tokens and holders of earlier versions are not
+    // enumerable, so the statistics stay untracked until the upgrade seeds them.
+    if runtime::get_key(CIRCULATING_SUPPLY).is_none() {
+        runtime::put_key(BURNT_TOKENS_COUNT, storage::new_uref(0u64).into());
+        runtime::put_key(CIRCULATING_SUPPLY, storage::new_uref(0u64).into());
+        runtime::put_key(HOLDER_COUNT, storage::new_uref(0u64).into());
+        runtime::put_key(COLLECTION_STATS_TRACKED, storage::new_uref(false).into());
+    }
+    let burnt_tokens_count = utils::get_optional_named_arg_with_user_errors::<u64>(
+        ARG_BURNT_TOKENS_COUNT,
+        NFTCoreError::InvalidBurntTokensCount,
+    );
+    let holder_count = utils::get_optional_named_arg_with_user_errors::<u64>(
+        ARG_HOLDER_COUNT,
+        NFTCoreError::InvalidHolderCount,
+    );
+    match (burnt_tokens_count, holder_count) {
+        (Some(burnt_tokens_count), Some(holder_count)) => {
+            seed_collection_stats(burnt_tokens_count, holder_count)
+        }
+        (None, None) => {}
+        (None, Some(_)) => runtime::revert(NFTCoreError::MissingBurntTokensCount),
+        (Some(_), None) => runtime::revert(NFTCoreError::MissingHolderCount),
+    }
 
     utils::migrate_contract_whitelist_to_acl_whitelist();
+
+    // Pack the migrated modalities into the config record read by the entry points.
+    utils::put_config_from_named_keys();
 }
 
 #[no_mangle]
 pub extern "C" fn updated_receipts() {
-    if let OwnerReverseLookupMode::Complete = utils::get_reporting_mode() {
-        let caller: Key = match utils::get_verified_caller().unwrap_or_revert() {
+    let config = utils::get_config();
+    if let OwnerReverseLookupMode::Complete = config.reporting_mode {
+        let caller: Key = match utils::get_verified_caller(config.holder_mode).unwrap_or_revert() {
             Caller::Session(account_hash) => account_hash.into(),
             Caller::StoredCaller(contract_hash, _) => contract_hash.into(),
         };
 
-        let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
-            IDENTIFIER_MODE,
-            NFTCoreError::MissingIdentifierMode,
-            NFTCoreError::InvalidIdentifierMode,
-        )
-        .try_into()
-        .unwrap_or_revert();
-
-        if identifier_mode == NFTIdentifierMode::Hash && utils::should_migrate_token_hashes(caller)
+        if config.identifier_mode == NFTIdentifierMode::Hash
+            && utils::should_migrate_token_hashes(caller)
         {
-            utils::migrate_token_hashes(caller);
+            utils::migrate_token_hashes(caller, &config);
         }
 
         let token_owner_item_key = utils::encode_dictionary_item_key(caller);
 
+        let page_table_uref = utils::get_uref(
+            PAGE_TABLE,
+            NFTCoreError::MissingPageTableURef,
+            NFTCoreError::InvalidPageTableURef,
+        );
         let page_table =
-            utils::get_dictionary_value_from_key::<Vec<bool>>(PAGE_TABLE, &token_owner_item_key)
-                .unwrap_or_default();
+            utils::get_page_record(page_table_uref, &token_owner_item_key).unwrap_or_default();
 
         let mut updated_receipts: Vec<(String, Key)> = vec![];
 
@@ -2145,7 +3921,7 @@ pub extern "C" fn updated_receipts() {
             );
             let page_dictionary_address =
                 Key::dictionary(page_uref, token_owner_item_key.as_bytes());
-            let receipt_name = utils::get_receipt_name(page_table_entry as u64);
+            let receipt_name = utils::get_receipt_name(page_table_entry as u64, &config);
             updated_receipts.push((receipt_name, page_dictionary_address))
         }
 
@@ -2155,17 +3931,20 @@ pub extern "C" fn updated_receipts() {
 
 #[no_mangle]
 pub extern "C" fn register_owner() {
+    let config = utils::get_config();
     if [
         OwnerReverseLookupMode::Complete,
         OwnerReverseLookupMode::TransfersOnly,
     ]
-    .contains(&utils::get_reporting_mode())
+    .contains(&config.reporting_mode)
     {
-        let owner_key = match utils::get_ownership_mode().unwrap_or_revert() {
-            OwnershipMode::Minter => match utils::get_verified_caller().unwrap_or_revert() {
-                Caller::Session(account_hash) => account_hash.into(),
-                Caller::StoredCaller(contract_hash, _) => contract_hash.into(),
-            },
+        let owner_key = match config.ownership_mode {
+            OwnershipMode::Minter => {
+                match utils::get_verified_caller(config.holder_mode).unwrap_or_revert() {
+                    Caller::Session(account_hash) => account_hash.into(),
+                    Caller::StoredCaller(contract_hash, _) => contract_hash.into(),
+                }
+            }
             OwnershipMode::Assigned | OwnershipMode::Transferable => {
                 utils::get_named_arg_with_user_errors::<Key>(
                     ARG_TOKEN_OWNER,
@@ -2184,19 +3963,17 @@ pub extern "C" fn register_owner() {
 
         let owner_item_key = utils::encode_dictionary_item_key(owner_key);
 
-        if storage::dictionary_get::<Vec<bool>>(page_table_uref, &owner_item_key)
-            .unwrap_or_revert()
-            .is_none()
-        {
+        if utils::get_page_record(page_table_uref, &owner_item_key).is_none() {
             let page_table_width = utils::get_stored_value_with_user_errors::<u64>(
                 PAGE_LIMIT,
                 NFTCoreError::MissingPageLimit,
                 NFTCoreError::InvalidPageLimit,
             );
-            storage::dictionary_put(
+            utils::put_page_record(
                 page_table_uref,
                 &owner_item_key,
                 vec![false; page_table_width as usize],
+                config.page_encoding,
             );
         }
         let collection_name = utils::get_stored_value_with_user_errors::<String>(
@@ -2249,6 +4026,22 @@ fn generate_entry_points() -> EntryPoints {
                 ARG_TRANSFER_FILTER_CONTRACT,
                 CLType::Option(Box::new(CLType::Key)),
             ),
+            Parameter::new(ARG_TRANSFER_FILTER_CONTRACT_VERSION, CLType::U8),
+            Parameter::new(ARG_PAGE_ENCODING, CLType::U8),
+            Parameter::new(ARG_PAGE_SIZE, CLType::U64),
+            Parameter::new(ARG_METADATA_STORAGE_MODE, CLType::U8),
+            Parameter::new(
+                ARG_MINT_FILTER_CONTRACT,
+                CLType::Option(Box::new(CLType::Key)),
+            ),
+            Parameter::new(
+                ARG_BURN_FILTER_CONTRACT,
+                CLType::Option(Box::new(CLType::Key)),
+            ),
+            Parameter::new(ARG_ROYALTY_RECEIVER, CLType::Option(Box::new(CLType::Key))),
+            Parameter::new(ARG_ROYALTY_BASIS_POINTS, CLType::U64),
+            Parameter::new(ARG_BASE_URI, CLType::String),
+            Parameter::new(ARG_COLLECTION_METADATA, CLType::String),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -2277,12 +4070,86 @@ fn generate_entry_points() -> EntryPoints {
             Parameter::new(ARG_ACL_PACKAGE_MODE, CLType::Bool),
             Parameter::new(ARG_PACKAGE_OPERATOR_MODE, CLType::Bool),
             Parameter::new(ARG_OPERATOR_BURN_MODE, CLType::Bool),
+            Parameter::new(
+                ARG_MINT_FILTER_CONTRACT,
+                CLType::Option(Box::new(CLType::Key)),
+            ),
+            Parameter::new(
+                ARG_BURN_FILTER_CONTRACT,
+                CLType::Option(Box::new(CLType::Key)),
+            ),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint starts the transfer of the admin rights held by the installer to another
+    // account, optionally along with the package access URef. Can only be called by the current
+    // admin (INSTALLER).
+    let transfer_admin = EntryPoint::new(
+        ENTRY_POINT_TRANSFER_ADMIN,
+        vec![
+            Parameter::new(ARG_ADMIN, CLType::Key),
+            Parameter::new(
+                ARG_PACKAGE_ACCESS_UREF,
+                CLType::Option(Box::new(CLType::URef)),
+            ),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint completes the transfer of the admin rights and returns the package access
+    // URef handed over with them, if any. Can only be called by the account set as pending admin
+    // through transfer_admin.
+    let accept_admin = EntryPoint::new(
+        ENTRY_POINT_ACCEPT_ADMIN,
+        vec![],
+        CLType::Option(Box::new(CLType::URef)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint grants a role (minter, metadata editor, pauser or burner) to a key. Can only
+    // be called by the installer. The minter role is the ACL whitelist and cannot be granted if
+    // the whitelist is locked.
+    let grant_role = EntryPoint::new(
+        ENTRY_POINT_GRANT_ROLE,
+        vec![
+            Parameter::new(ARG_ROLE, CLType::U8),
+            Parameter::new(ARG_ROLE_HOLDER, CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint revokes a role from a key. Can only be called by the installer.
+    let revoke_role = EntryPoint::new(
+        ENTRY_POINT_REVOKE_ROLE,
+        vec![
+            Parameter::new(ARG_ROLE, CLType::U8),
+            Parameter::new(ARG_ROLE_HOLDER, CLType::Key),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
 
+    // This entrypoint returns whether a role was granted to a key.
+    let has_role = EntryPoint::new(
+        ENTRY_POINT_HAS_ROLE,
+        vec![
+            Parameter::new(ARG_ROLE, CLType::U8),
+            Parameter::new(ARG_ROLE_HOLDER, CLType::Key),
+        ],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint mints a new token with provided metadata.
     // Meant to be called post installation.
     // Reverts with MintingIsPaused error if allow_minting is false.
@@ -2296,7 +4163,8 @@ fn generate_entry_points() -> EntryPoints {
     // automatically assigned a U64 ID equal to the current number_of_minted_tokens. The
     // account is listed as the token owner, as well as added to the accounts list of owned
     // tokens. After minting is successful the number_of_minted_tokens is incremented by
-    // one.
+    // one. With the optional safe arg, a contract receiving the token must acknowledge it as with
+    // the safe_transfer entrypoint.
     let mint = EntryPoint::new(
         ENTRY_POINT_MINT,
         vec![
@@ -2312,6 +4180,90 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint mints a batch of tokens in a single call, applying the same checks
+    // as the mint entrypoint to every token. Token hashes are optional and only used in the
+    // Hash identifier mode, while token owners are only used in the Assigned and Transferable
+    // ownership modes. Both lists must match the length of the metadata list. One receipt is
+    // returned per minted token.
+    let mint_batch = EntryPoint::new(
+        ENTRY_POINT_MINT_BATCH,
+        vec![
+            Parameter::new(ARG_TOKEN_META_DATAS, CLType::List(Box::new(CLType::String))),
+            Parameter::new(ARG_TOKEN_HASHES, CLType::List(Box::new(CLType::String))),
+            Parameter::new(ARG_TOKEN_OWNERS, CLType::List(Box::new(CLType::Key))),
+        ],
+        CLType::List(Box::new(CLType::Tuple3([
+            Box::new(CLType::String),
+            Box::new(CLType::Key),
+            Box::new(CLType::String),
+        ]))),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint updates the base the token URIs are built from. Can only be called by the
+    // installer.
+    let set_base_uri = EntryPoint::new(
+        ENTRY_POINT_SET_BASE_URI,
+        vec![Parameter::new(ARG_BASE_URI, CLType::String)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint updates the JSON document describing the collection. Can only be called by
+    // the installer.
+    let set_collection_metadata = EntryPoint::new(
+        ENTRY_POINT_SET_COLLECTION_METADATA,
+        vec![Parameter::new(ARG_COLLECTION_METADATA, CLType::String)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint returns the JSON document describing the collection. It reverts if no
+    // collection metadata was set.
+    let collection_metadata = EntryPoint::new(
+        ENTRY_POINT_COLLECTION_METADATA,
+        vec![],
+        CLType::String,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint returns the number_of_minted_tokens, burnt_tokens_count, circulating_supply
+    // and holder_count statistics of the collection.
+    let collection_stats = EntryPoint::new(
+        ENTRY_POINT_COLLECTION_STATS,
+        vec![],
+        CLType::Map {
+            key: Box::new(CLType::String),
+            value: Box::new(CLType::U64),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint returns the URI of the token built from the base URI and the token id or
+    // hash. It reverts if the token does not exist or no base URI was set.
+    let token_uri = EntryPoint::new(
+        ENTRY_POINT_TOKEN_URI,
+        vec![], // <- either HASH or INDEX
+        CLType::String,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint returns the receiver and the amount of the royalty owed on a sale of the
+    // token at the given price. It reverts if the token does not exist.
+    let royalty_info = EntryPoint::new(
+        ENTRY_POINT_ROYALTY_INFO,
+        vec![Parameter::new(ARG_SALE_PRICE, CLType::U512)],
+        CLType::Tuple2([Box::new(CLType::Key), Box::new(CLType::U512)]),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint burns the token with provided token_id argument, after which it is no
     // longer possible to transfer it.
     // Looks up the owner of the supplied token_id arg. If caller is not owner we revert with
@@ -2330,6 +4282,7 @@ fn generate_entry_points() -> EntryPoints {
     // It looks up the owner of the supplied token_id arg. Revert if token is already burnt,
     // token_id is invalid, or if caller is not owner nor an approved account nor operator.
     // If token id is invalid it reverts with error InvalidTokenID.
+    // An optional data arg of type Bytes is handed over to a v2 transfer filter contract.
     let transfer = EntryPoint::new(
         ENTRY_POINT_TRANSFER,
         vec![
@@ -2341,9 +4294,25 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint transfers a token like the transfer entrypoint, after which a contract
+    // receiving the token is called on its on_cep78_received entrypoint with the operator, the
+    // source account, the token id and the optional data arg. It reverts with error
+    // UnacknowledgedTokenReceipt unless the receiving contract acknowledges the token.
+    let safe_transfer = EntryPoint::new(
+        ENTRY_POINT_SAFE_TRANSFER,
+        vec![
+            Parameter::new(ARG_SOURCE_KEY, CLType::Key),
+            Parameter::new(ARG_TARGET_KEY, CLType::Key),
+        ],
+        CLType::Tuple2([Box::new(CLType::String), Box::new(CLType::Key)]),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint approves another token holder (an approved account) to transfer tokens. It
     // reverts if token_id is invalid, if caller is not the owner nor operator, if token has already
-    // been burnt, or if caller tries to approve themselves as an approved account.
+    // been burnt, or if caller tries to approve themselves as an approved account. An optional
+    // expires_at block time makes the approval lapse once reached.
     let approve = EntryPoint::new(
         ENTRY_POINT_APPROVE,
         vec![Parameter::new(ARG_SPENDER, CLType::Key)],
@@ -2352,9 +4321,46 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
-    // This entrypoint revokes an approved account to transfer tokens. It reverts
-    // if token_id is invalid, if caller is not the owner, if token has already
-    // been burnt, if caller tries to approve itself.
+    // This entrypoint burns every token of the supplied token_ids (or token_hashes) list,
+    // applying the same checks as the burn entrypoint to each token.
+    let burn_batch = EntryPoint::new(
+        ENTRY_POINT_BURN_BATCH,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint transfers every token of the supplied token_ids (or token_hashes) list
+    // from the source account to the target account, applying the same checks as the transfer
+    // entrypoint to each token. It returns one receipt per page of the source account.
+    let transfer_batch = EntryPoint::new(
+        ENTRY_POINT_TRANSFER_BATCH,
+        vec![
+            Parameter::new(ARG_SOURCE_KEY, CLType::Key),
+            Parameter::new(ARG_TARGET_KEY, CLType::Key),
+        ],
+        CLType::List(Box::new(CLType::Tuple2([
+            Box::new(CLType::String),
+            Box::new(CLType::Key),
+        ]))),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint approves the spender for every token of the supplied token_ids (or
+    // token_hashes) list, applying the same checks as the approve entrypoint to each token.
+    let approve_batch = EntryPoint::new(
+        ENTRY_POINT_APPROVE_BATCH,
+        vec![Parameter::new(ARG_SPENDER, CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint revokes an approved account to transfer tokens, or every approved account
+    // if no spender is given. It reverts if token_id is invalid, if caller is not the owner, if
+    // token has already been burnt, if caller tries to approve itself.
     let revoke = EntryPoint::new(
         ENTRY_POINT_REVOKE,
         vec![],
@@ -2365,7 +4371,8 @@ fn generate_entry_points() -> EntryPoints {
 
     // This entrypoint approves all tokens owned by the caller and future to another token holder
     // (an operator) to transfer tokens. It reverts if token_id is invalid, if caller is not the
-    // owner, if caller tries to approve itself as an operator.
+    // owner, if caller tries to approve itself as an operator. An optional expires_at block time
+    // makes the approval lapse once reached.
     let set_approval_for_all = EntryPoint::new(
         ENTRY_POINT_SET_APPROVALL_FOR_ALL,
         vec![
@@ -2399,12 +4406,22 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
-    // This entrypoint returns the approved account (if any) associated with the provided token_id
-    // Reverts if token has been burnt.
+    // This entrypoint returns whether the token was flagged as non transferable at mint. It
+    // reverts if the token does not exist.
+    let is_non_transferable = EntryPoint::new(
+        ENTRY_POINT_IS_NON_TRANSFERABLE,
+        vec![], // <- either HASH or INDEX
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint returns the approved accounts whose approval has not expired associated with
+    // the provided token_id. Reverts if token has been burnt.
     let get_approved = EntryPoint::new(
         ENTRY_POINT_GET_APPROVED,
         vec![], // <- either HASH or INDEX
-        CLType::Option(Box::new(CLType::Key)),
+        CLType::List(Box::new(CLType::Key)),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
@@ -2418,6 +4435,36 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint returns at most limit tokens held by the token_owner after skipping the
+    // first offset ones, walking the page table and pages of the owner. Token ids are u64 in the
+    // Ordinal identifier mode and String in the Hash identifier mode. It reverts if the owner
+    // reverse lookup is disabled.
+    let tokens_of_owner = EntryPoint::new(
+        ENTRY_POINT_TOKENS_OF_OWNER,
+        vec![
+            Parameter::new(ARG_TOKEN_OWNER, CLType::Key),
+            Parameter::new(ARG_OFFSET, CLType::U64),
+            Parameter::new(ARG_LIMIT, CLType::U64),
+        ],
+        CLType::List(Box::new(CLType::Any)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint returns the token held by the token_owner at the given index, in the order
+    // of the tokens_of_owner entrypoint. It reverts if the index is out of bounds or if the owner
+    // reverse lookup is disabled.
+    let token_of_owner_by_index = EntryPoint::new(
+        ENTRY_POINT_TOKEN_OF_OWNER_BY_INDEX,
+        vec![
+            Parameter::new(ARG_TOKEN_OWNER, CLType::Key),
+            Parameter::new(ARG_INDEX, CLType::U64),
+        ],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint returns the metadata associated with the provided token_id
     let metadata = EntryPoint::new(
         ENTRY_POINT_METADATA,
@@ -2436,6 +4483,16 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint permanently freezes the metadata of a token. Can only be called by the
+    // owner or the issuer of the token.
+    let freeze_metadata = EntryPoint::new(
+        ENTRY_POINT_FREEZE_METADATA,
+        vec![], // <- either HASH or INDEX
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint will upgrade the contract from the 1_0 version to the
     // 1_1 version. The contract will insert any addition dictionaries and
     // sentinel values that were absent in the previous version of the contract.
@@ -2486,18 +4543,38 @@ fn generate_entry_points() -> EntryPoints {
 
     entry_points.add_entry_point(init_contract);
     entry_points.add_entry_point(set_variables);
+    entry_points.add_entry_point(transfer_admin);
+    entry_points.add_entry_point(accept_admin);
+    entry_points.add_entry_point(grant_role);
+    entry_points.add_entry_point(revoke_role);
+    entry_points.add_entry_point(has_role);
     entry_points.add_entry_point(mint);
+    entry_points.add_entry_point(mint_batch);
+    entry_points.add_entry_point(royalty_info);
+    entry_points.add_entry_point(set_base_uri);
+    entry_points.add_entry_point(token_uri);
+    entry_points.add_entry_point(set_collection_metadata);
+    entry_points.add_entry_point(collection_metadata);
+    entry_points.add_entry_point(collection_stats);
     entry_points.add_entry_point(burn);
+    entry_points.add_entry_point(burn_batch);
     entry_points.add_entry_point(transfer);
+    entry_points.add_entry_point(safe_transfer);
+    entry_points.add_entry_point(transfer_batch);
     entry_points.add_entry_point(approve);
+    entry_points.add_entry_point(approve_batch);
     entry_points.add_entry_point(revoke);
     entry_points.add_entry_point(owner_of);
+    entry_points.add_entry_point(is_non_transferable);
     entry_points.add_entry_point(balance_of);
+    entry_points.add_entry_point(tokens_of_owner);
+    entry_points.add_entry_point(token_of_owner_by_index);
     entry_points.add_entry_point(get_approved);
     entry_points.add_entry_point(metadata);
     entry_points.add_entry_point(set_approval_for_all);
     entry_points.add_entry_point(is_approved_for_all);
     entry_points.add_entry_point(set_token_metadata);
+    entry_points.add_entry_point(freeze_metadata);
     entry_points.add_entry_point(migrate);
     entry_points.add_entry_point(updated_receipts);
     entry_points.add_entry_point(register_owner);
@@ -2538,10 +4615,6 @@ fn install_contract() {
         runtime::revert(NFTCoreError::CannotInstallWithZeroSupply)
     }
 
-    if total_token_supply > MAX_TOTAL_TOKEN_SUPPLY {
-        runtime::revert(NFTCoreError::ExceededMaxTotalSupply)
-    }
-
     let allow_minting: bool = utils::get_optional_named_arg_with_user_errors(
         ARG_ALLOW_MINTING,
         NFTCoreError::InvalidMintingStatus,
@@ -2682,7 +4755,8 @@ fn install_contract() {
     )
     .unwrap_or_revert();
 
-    if identifier_mode == 1 && metadata_mutability == 1 {
+    // Only immutable metadata can be used with hash identifiers.
+    if identifier_mode == 1 && metadata_mutability != 0 {
         runtime::revert(NFTCoreError::InvalidMetadataMutability)
     }
 
@@ -2719,10 +4793,73 @@ fn install_contract() {
             NFTCoreError::InvalidTransferFilterContract,
         );
 
+    let mint_filter_contract_key: Option<Key> = utils::get_optional_named_arg_with_user_errors(
+        ARG_MINT_FILTER_CONTRACT,
+        NFTCoreError::InvalidMintFilterContract,
+    );
+
+    let burn_filter_contract_key: Option<Key> = utils::get_optional_named_arg_with_user_errors(
+        ARG_BURN_FILTER_CONTRACT,
+        NFTCoreError::InvalidBurnFilterContract,
+    );
+
+    let transfer_filter_contract_version: u8 = utils::get_optional_named_arg_with_user_errors(
+        ARG_TRANSFER_FILTER_CONTRACT_VERSION,
+        NFTCoreError::InvalidTransferFilterContractVersion,
+    )
+    .unwrap_or(TransferFilterContractVersion::V1 as u8);
+
+    // New installs store their reverse lookup pages as bitmaps unless told otherwise.
+    let page_encoding: u8 = utils::get_optional_named_arg_with_user_errors(
+        ARG_PAGE_ENCODING,
+        NFTCoreError::InvalidPageEncoding,
+    )
+    .unwrap_or(PageEncoding::Bitmap as u8);
+
+    let page_size: u64 = utils::get_optional_named_arg_with_user_errors(
+        ARG_PAGE_SIZE,
+        NFTCoreError::InvalidPageSize,
+    )
+    .unwrap_or(DEFAULT_PAGE_SIZE);
+
+    let metadata_storage_mode: u8 = utils::get_optional_named_arg_with_user_errors(
+        ARG_METADATA_STORAGE_MODE,
+        NFTCoreError::InvalidMetadataStorageMode,
+    )
+    .unwrap_or(MetadataStorageMode::PerKind as u8);
+
     if ownership_mode == 0 && minting_mode == 0 && reporting_mode == 1 {
         runtime::revert(NFTCoreError::InvalidReportingMode)
     }
 
+    // Represents the default receiver of the royalties and their share of a sale price in basis
+    // points. Both can be overridden per token at mint. The receiver defaults to the installer
+    // and the basis points to 0.
+    let royalty_receiver: Option<Key> = utils::get_optional_named_arg_with_user_errors(
+        ARG_ROYALTY_RECEIVER,
+        NFTCoreError::InvalidRoyaltyReceiver,
+    );
+
+    let royalty_basis_points: u64 = utils::get_optional_named_arg_with_user_errors(
+        ARG_ROYALTY_BASIS_POINTS,
+        NFTCoreError::InvalidRoyaltyBasisPoints,
+    )
+    .unwrap_or_default();
+
+    // Represents the base the token URIs are built from, which can be changed post installation.
+    // Refer to the `token_uri` entrypoint for further details.
+    let base_uri: String =
+        utils::get_optional_named_arg_with_user_errors(ARG_BASE_URI, NFTCoreError::InvalidBaseUri)
+            .unwrap_or_default();
+
+    // The JSON document describing the collection as a whole, e.g. its description, image or
+    // external link, which can be changed post installation. It is validated on init.
+    let collection_metadata: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_COLLECTION_METADATA,
+        NFTCoreError::InvalidCollectionMetadata,
+    )
+    .unwrap_or_default();
+
     let entry_points = generate_entry_points();
 
     let named_keys = {
@@ -2796,6 +4933,16 @@ fn install_contract() {
         ARG_PACKAGE_OPERATOR_MODE => package_operator_mode,
         ARG_TRANSFER_FILTER_CONTRACT =>
         transfer_filter_contract_contract_key,
+        ARG_TRANSFER_FILTER_CONTRACT_VERSION => transfer_filter_contract_version,
+        ARG_PAGE_ENCODING => page_encoding,
+        ARG_PAGE_SIZE => page_size,
+        ARG_METADATA_STORAGE_MODE => metadata_storage_mode,
+        ARG_MINT_FILTER_CONTRACT => mint_filter_contract_key,
+        ARG_BURN_FILTER_CONTRACT => burn_filter_contract_key,
+        ARG_ROYALTY_RECEIVER => royalty_receiver,
+        ARG_ROYALTY_BASIS_POINTS => royalty_basis_points,
+        ARG_BASE_URI => base_uri,
+        ARG_COLLECTION_METADATA => collection_metadata,
     };
 
     // Call contract to initialize it
@@ -2884,6 +5031,51 @@ fn migrate_contract(access_key_name: String, package_key_name: String) {
             .unwrap_or_revert();
     }
 
+    if let Some(page_encoding) = utils::get_optional_named_arg_with_user_errors::<u8>(
+        ARG_PAGE_ENCODING,
+        NFTCoreError::InvalidPageEncoding,
+    ) {
+        runtime_args
+            .insert(ARG_PAGE_ENCODING, page_encoding)
+            .unwrap_or_revert();
+    }
+
+    if let Some(burnt_tokens_count) = utils::get_optional_named_arg_with_user_errors::<u64>(
+        ARG_BURNT_TOKENS_COUNT,
+        NFTCoreError::InvalidBurntTokensCount,
+    ) {
+        runtime_args
+            .insert(ARG_BURNT_TOKENS_COUNT, burnt_tokens_count)
+            .unwrap_or_revert();
+    }
+
+    if let Some(holder_count) = utils::get_optional_named_arg_with_user_errors::<u64>(
+        ARG_HOLDER_COUNT,
+        NFTCoreError::InvalidHolderCount,
+    ) {
+        runtime_args
+            .insert(ARG_HOLDER_COUNT, holder_count)
+            .unwrap_or_revert();
+    }
+
+    if let Some(royalty_receiver) = utils::get_optional_named_arg_with_user_errors::<Key>(
+        ARG_ROYALTY_RECEIVER,
+        NFTCoreError::InvalidRoyaltyReceiver,
+    ) {
+        runtime_args
+            .insert(ARG_ROYALTY_RECEIVER, royalty_receiver)
+            .unwrap_or_revert();
+    }
+
+    if let Some(royalty_basis_points) = utils::get_optional_named_arg_with_user_errors::<u64>(
+        ARG_ROYALTY_BASIS_POINTS,
+        NFTCoreError::InvalidRoyaltyBasisPoints,
+    ) {
+        runtime_args
+            .insert(ARG_ROYALTY_BASIS_POINTS, royalty_basis_points)
+            .unwrap_or_revert();
+    }
+
     runtime::call_contract::<()>(contract_hash, ENTRY_POINT_MIGRATE, runtime_args);
 }
 
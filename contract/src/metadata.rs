@@ -1,15 +1,21 @@
 use alloc::{
+    boxed::Box,
     collections::BTreeMap,
     string::{String, ToString},
+    vec,
     vec::Vec,
 };
+use core::{fmt, iter::Peekable, str::Chars};
 
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 
 use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
 use casper_types::{
     bytesrepr,
-    bytesrepr::{FromBytes, ToBytes},
+    bytesrepr::{FromBytes, ToBytes, OPTION_NONE_TAG, OPTION_SOME_TAG, U8_SERIALIZED_LENGTH},
     CLType, CLTyped,
 };
 
@@ -18,12 +24,181 @@ use crate::{
     METADATA_CUSTOM_VALIDATED, METADATA_NFT721, METADATA_RAW,
 };
 
+// The type a custom metadata value must have. Properties without a type accept a value of any type.
+#[repr(u8)]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum MetadataPropertyType {
+    String = 0,
+    Number = 1,
+    Bool = 2,
+    Enum = 3,
+    Object = 4,
+    Array = 5,
+}
+
+impl TryFrom<u8> for MetadataPropertyType {
+    type Error = NFTCoreError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MetadataPropertyType::String),
+            1 => Ok(MetadataPropertyType::Number),
+            2 => Ok(MetadataPropertyType::Bool),
+            3 => Ok(MetadataPropertyType::Enum),
+            4 => Ok(MetadataPropertyType::Object),
+            5 => Ok(MetadataPropertyType::Array),
+            _ => Err(NFTCoreError::InvalidJsonSchema),
+        }
+    }
+}
+
+impl ToBytes for MetadataPropertyType {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        (*self as u8).to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        (*self as u8).serialized_length()
+    }
+}
+
+impl FromBytes for MetadataPropertyType {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (property_type, remainder) = u8::from_bytes(bytes)?;
+        let property_type = MetadataPropertyType::try_from(property_type)
+            .map_err(|_| bytesrepr::Error::Formatting)?;
+        Ok((property_type, remainder))
+    }
+}
+
 // Metadata mutability is different from schema mutability.
+// All constraints but name, description and required are optional so that schemas predating
+// them remain valid. Numbers are integers as the contract cannot use floating point. Objects
+// describe their own properties and arrays the schema shared by their items.
 #[derive(Serialize, Deserialize, Clone)]
 pub(crate) struct MetadataSchemaProperty {
     name: String,
     description: String,
     required: bool,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    property_type: Option<MetadataPropertyType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_length: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max: Option<i64>,
+    #[serde(rename = "enum", default, skip_serializing_if = "Option::is_none")]
+    values: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pattern: Option<Pattern>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    properties: Option<BTreeMap<String, MetadataSchemaProperty>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    items: Option<Box<MetadataSchemaProperty>>,
+}
+
+impl MetadataSchemaProperty {
+    fn new(name: &str, description: &str) -> Self {
+        MetadataSchemaProperty {
+            name: name.to_string(),
+            description: description.to_string(),
+            required: true,
+            property_type: None,
+            max_length: None,
+            min: None,
+            max: None,
+            values: None,
+            pattern: None,
+            properties: None,
+            items: None,
+        }
+    }
+
+    // Reverts with InvalidJsonSchema if the constraints of the property contradict each other.
+    fn validate(&self) -> Result<(), NFTCoreError> {
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            if min > max {
+                return Err(NFTCoreError::InvalidJsonSchema);
+            }
+        }
+        let has_values = self
+            .values
+            .as_ref()
+            .is_some_and(|values| !values.is_empty());
+        if has_values != (self.property_type == Some(MetadataPropertyType::Enum)) {
+            return Err(NFTCoreError::InvalidJsonSchema);
+        }
+        if self.properties.is_some() != (self.property_type == Some(MetadataPropertyType::Object))
+            || (self.items.is_some() && self.property_type != Some(MetadataPropertyType::Array))
+        {
+            return Err(NFTCoreError::InvalidJsonSchema);
+        }
+        if let Some(properties) = &self.properties {
+            properties
+                .values()
+                .try_for_each(MetadataSchemaProperty::validate)?;
+        }
+        if let Some(items) = &self.items {
+            items.validate()?;
+        }
+        Ok(())
+    }
+
+    fn is_valid_value(&self, value: &MetadataValue) -> bool {
+        let has_valid_type = match (self.property_type, value) {
+            (None, _)
+            | (Some(MetadataPropertyType::String), MetadataValue::String(_))
+            | (Some(MetadataPropertyType::Bool), MetadataValue::Bool(_)) => true,
+            (Some(MetadataPropertyType::Number), MetadataValue::Number(number)) => {
+                !matches!(self.min, Some(min) if *number < min as i128)
+                    && !matches!(self.max, Some(max) if *number > max as i128)
+            }
+            (Some(MetadataPropertyType::Enum), MetadataValue::String(value)) => self
+                .values
+                .as_ref()
+                .is_some_and(|values| values.contains(value)),
+            (Some(MetadataPropertyType::Object), MetadataValue::Object(object)) => self
+                .properties
+                .as_ref()
+                .is_some_and(|properties| is_valid_object(properties, object)),
+            (Some(MetadataPropertyType::Array), MetadataValue::Array(items)) => match &self.items {
+                Some(item_property) => items.iter().all(|item| item_property.is_valid_value(item)),
+                None => true,
+            },
+            _ => false,
+        };
+        // The maximum length counts the characters of strings and the items of arrays.
+        let length = match value {
+            MetadataValue::String(value) => value.chars().count(),
+            MetadataValue::Array(items) => items.len(),
+            _ => 0,
+        };
+        let has_valid_length =
+            !matches!(self.max_length, Some(max_length) if length as u64 > max_length);
+        // Patterns only match strings.
+        let matches_pattern = match (&self.pattern, value) {
+            (Some(pattern), MetadataValue::String(value)) => pattern
+                .is_match(value)
+                .unwrap_or_revert_with(NFTCoreError::InvalidCustomMetadata),
+            (Some(_), _) => false,
+            (None, _) => true,
+        };
+        has_valid_type && has_valid_length && matches_pattern
+    }
+}
+
+fn is_valid_object(
+    properties: &BTreeMap<String, MetadataSchemaProperty>,
+    object: &BTreeMap<String, MetadataValue>,
+) -> bool {
+    properties.iter().all(
+        |(property_name, property)| match object.get(property_name) {
+            Some(value) => property.is_valid_value(value),
+            None => !property.required,
+        },
+    )
 }
 
 impl ToBytes for MetadataSchemaProperty {
@@ -32,6 +207,20 @@ impl ToBytes for MetadataSchemaProperty {
         result.extend(self.name.to_bytes()?);
         result.extend(self.description.to_bytes()?);
         result.extend(self.required.to_bytes()?);
+        result.extend(self.property_type.to_bytes()?);
+        result.extend(self.max_length.to_bytes()?);
+        result.extend(self.min.to_bytes()?);
+        result.extend(self.max.to_bytes()?);
+        result.extend(self.values.to_bytes()?);
+        result.extend(self.pattern.to_bytes()?);
+        result.extend(self.properties.to_bytes()?);
+        match &self.items {
+            Some(items) => {
+                result.push(OPTION_SOME_TAG);
+                result.extend(items.to_bytes()?);
+            }
+            None => result.push(OPTION_NONE_TAG),
+        }
         Ok(result)
     }
 
@@ -39,6 +228,18 @@ impl ToBytes for MetadataSchemaProperty {
         self.name.serialized_length()
             + self.description.serialized_length()
             + self.required.serialized_length()
+            + self.property_type.serialized_length()
+            + self.max_length.serialized_length()
+            + self.min.serialized_length()
+            + self.max.serialized_length()
+            + self.values.serialized_length()
+            + self.pattern.serialized_length()
+            + self.properties.serialized_length()
+            + U8_SERIALIZED_LENGTH
+            + self
+                .items
+                .as_ref()
+                .map_or(0, |items| items.serialized_length())
     }
 }
 
@@ -47,10 +248,27 @@ impl FromBytes for MetadataSchemaProperty {
         let (name, remainder) = String::from_bytes(bytes)?;
         let (description, remainder) = String::from_bytes(remainder)?;
         let (required, remainder) = bool::from_bytes(remainder)?;
+        let (property_type, remainder) = Option::<MetadataPropertyType>::from_bytes(remainder)?;
+        let (max_length, remainder) = Option::<u64>::from_bytes(remainder)?;
+        let (min, remainder) = Option::<i64>::from_bytes(remainder)?;
+        let (max, remainder) = Option::<i64>::from_bytes(remainder)?;
+        let (values, remainder) = Option::<Vec<String>>::from_bytes(remainder)?;
+        let (pattern, remainder) = Option::<Pattern>::from_bytes(remainder)?;
+        let (properties, remainder) =
+            Option::<BTreeMap<String, MetadataSchemaProperty>>::from_bytes(remainder)?;
+        let (items, remainder) = Option::<MetadataSchemaProperty>::from_bytes(remainder)?;
         let metadata_schema_property = MetadataSchemaProperty {
             name,
             description,
             required,
+            property_type,
+            max_length,
+            min,
+            max,
+            values,
+            pattern,
+            properties,
+            items: items.map(Box::new),
         };
         Ok((metadata_schema_property, remainder))
     }
@@ -67,6 +285,14 @@ pub(crate) struct CustomMetadataSchema {
     properties: BTreeMap<String, MetadataSchemaProperty>,
 }
 
+impl CustomMetadataSchema {
+    pub(crate) fn validate(&self) -> Result<(), NFTCoreError> {
+        self.properties
+            .values()
+            .try_for_each(MetadataSchemaProperty::validate)
+    }
+}
+
 pub(crate) fn get_metadata_schema(kind: &NFTMetadataKind) -> CustomMetadataSchema {
     match kind {
         NFTMetadataKind::Raw => CustomMetadataSchema {
@@ -76,27 +302,18 @@ pub(crate) fn get_metadata_schema(kind: &NFTMetadataKind) -> CustomMetadataSchem
             let mut properties = BTreeMap::new();
             properties.insert(
                 "name".to_string(),
-                MetadataSchemaProperty {
-                    name: "name".to_string(),
-                    description: "The name of the NFT".to_string(),
-                    required: true,
-                },
+                MetadataSchemaProperty::new("name", "The name of the NFT"),
             );
             properties.insert(
                 "symbol".to_string(),
-                MetadataSchemaProperty {
-                    name: "symbol".to_string(),
-                    description: "The symbol of the NFT collection".to_string(),
-                    required: true,
-                },
+                MetadataSchemaProperty::new("symbol", "The symbol of the NFT collection"),
             );
             properties.insert(
                 "token_uri".to_string(),
-                MetadataSchemaProperty {
-                    name: "token_uri".to_string(),
-                    description: "The URI pointing to an off chain resource".to_string(),
-                    required: true,
-                },
+                MetadataSchemaProperty::new(
+                    "token_uri",
+                    "The URI pointing to an off chain resource",
+                ),
             );
             CustomMetadataSchema { properties }
         }
@@ -104,27 +321,21 @@ pub(crate) fn get_metadata_schema(kind: &NFTMetadataKind) -> CustomMetadataSchem
             let mut properties = BTreeMap::new();
             properties.insert(
                 "name".to_string(),
-                MetadataSchemaProperty {
-                    name: "name".to_string(),
-                    description: "The name of the NFT".to_string(),
-                    required: true,
-                },
+                MetadataSchemaProperty::new("name", "The name of the NFT"),
             );
             properties.insert(
                 "token_uri".to_string(),
-                MetadataSchemaProperty {
-                    name: "token_uri".to_string(),
-                    description: "The URI pointing to an off chain resource".to_string(),
-                    required: true,
-                },
+                MetadataSchemaProperty::new(
+                    "token_uri",
+                    "The URI pointing to an off chain resource",
+                ),
             );
             properties.insert(
                 "checksum".to_string(),
-                MetadataSchemaProperty {
-                    name: "checksum".to_string(),
-                    description: "A SHA256 hash of the content at the token_uri".to_string(),
-                    required: true,
-                },
+                MetadataSchemaProperty::new(
+                    "checksum",
+                    "A SHA256 hash of the content at the token_uri",
+                ),
             );
             CustomMetadataSchema { properties }
         }
@@ -187,7 +398,92 @@ pub(crate) struct MetadataCEP78 {
 // Using a structure for the purposes of serialization formatting.
 #[derive(Serialize, Deserialize)]
 pub(crate) struct CustomMetadata {
-    attributes: BTreeMap<String, String>,
+    attributes: BTreeMap<String, MetadataValue>,
+}
+
+// Any JSON value of custom metadata, kept with its original type. Numbers are integers as the
+// contract cannot use floating point.
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) enum MetadataValue {
+    Null,
+    Bool(bool),
+    Number(i128),
+    String(String),
+    Array(Vec<MetadataValue>),
+    Object(BTreeMap<String, MetadataValue>),
+}
+
+impl Serialize for MetadataValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            MetadataValue::Null => serializer.serialize_unit(),
+            MetadataValue::Bool(value) => serializer.serialize_bool(*value),
+            MetadataValue::Number(value) => serializer.serialize_i128(*value),
+            MetadataValue::String(value) => serializer.serialize_str(value),
+            MetadataValue::Array(items) => serializer.collect_seq(items),
+            MetadataValue::Object(object) => serializer.collect_map(object),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MetadataValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(MetadataValueVisitor)
+    }
+}
+
+struct MetadataValueVisitor;
+
+impl<'de> Visitor<'de> for MetadataValueVisitor {
+    type Value = MetadataValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON value without floating point numbers")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(MetadataValue::Null)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(MetadataValue::Null)
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(MetadataValue::Bool(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(MetadataValue::Number(value.into()))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(MetadataValue::Number(value.into()))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(MetadataValue::String(value.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+        Ok(MetadataValue::String(value))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(MetadataValue::Array(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut object = BTreeMap::new();
+        while let Some((key, value)) = map.next_entry::<String, MetadataValue>()? {
+            object.insert(key, value);
+        }
+        Ok(MetadataValue::Object(object))
+    }
 }
 
 pub(crate) fn validate_metadata(
@@ -243,15 +539,12 @@ pub(crate) fn validate_metadata(
         NFTMetadataKind::Raw => Ok(token_metadata),
         NFTMetadataKind::CustomValidated => {
             let custom_metadata =
-                serde_json_wasm::from_str::<BTreeMap<String, String>>(&token_metadata)
+                serde_json_wasm::from_str::<BTreeMap<String, MetadataValue>>(&token_metadata)
                     .map(|attributes| CustomMetadata { attributes })
                     .map_err(|_| NFTCoreError::FailedToParseCustomMetadata)?;
 
-            for (property_name, property_type) in token_schema.properties.iter() {
-                if property_type.required && !custom_metadata.attributes.contains_key(property_name)
-                {
-                    runtime::revert(NFTCoreError::InvalidCustomMetadata)
-                }
+            if !is_valid_object(&token_schema.properties, &custom_metadata.attributes) {
+                runtime::revert(NFTCoreError::InvalidCustomMetadata)
             }
             serde_json::to_string_pretty(&custom_metadata.attributes)
                 .map_err(|_| NFTCoreError::FailedToJsonifyCustomMetadata)
@@ -259,6 +552,53 @@ pub(crate) fn validate_metadata(
     }
 }
 
+// The collection metadata must be a JSON object, of any shape.
+pub(crate) fn validate_collection_metadata(collection_metadata: &str) -> Result<(), NFTCoreError> {
+    serde_json_wasm::from_str::<BTreeMap<String, MetadataValue>>(collection_metadata)
+        .map(|_| ())
+        .map_err(|_| NFTCoreError::InvalidCollectionMetadata)
+}
+
+// Renders token metadata that already validated against the metadata kind into the view of that
+// kind, without checking it against the schema again. Raw metadata is returned as given.
+pub(crate) fn render_metadata(
+    metadata_kind: &NFTMetadataKind,
+    token_metadata: String,
+) -> Result<String, NFTCoreError> {
+    match metadata_kind {
+        NFTMetadataKind::CEP78 => {
+            let metadata = serde_json_wasm::from_str::<MetadataCEP78>(&token_metadata)
+                .map_err(|_| NFTCoreError::FailedToParseCep99Metadata)?;
+            serde_json::to_string_pretty(&metadata)
+                .map_err(|_| NFTCoreError::FailedToJsonifyCEP99Metadata)
+        }
+        NFTMetadataKind::NFT721 => {
+            let metadata = serde_json_wasm::from_str::<MetadataNFT721>(&token_metadata)
+                .map_err(|_| NFTCoreError::FailedToParse721Metadata)?;
+            serde_json::to_string_pretty(&metadata)
+                .map_err(|_| NFTCoreError::FailedToJsonifyNFT721Metadata)
+        }
+        NFTMetadataKind::Raw => Ok(token_metadata),
+        NFTMetadataKind::CustomValidated => {
+            let attributes =
+                serde_json_wasm::from_str::<BTreeMap<String, MetadataValue>>(&token_metadata)
+                    .map_err(|_| NFTCoreError::FailedToParseCustomMetadata)?;
+            serde_json::to_string_pretty(&attributes)
+                .map_err(|_| NFTCoreError::FailedToJsonifyCustomMetadata)
+        }
+    }
+}
+
+// Returns the token metadata as compact JSON for the compact metadata storage, object keys being
+// sorted, so that the same metadata is always stored the same way. Objects the contract cannot
+// parse, such as objects holding floating point numbers, are kept as given.
+pub(crate) fn compact_metadata(token_metadata: &str) -> String {
+    serde_json_wasm::from_str::<BTreeMap<String, MetadataValue>>(token_metadata)
+        .ok()
+        .and_then(|object| serde_json::to_string(&object).ok())
+        .unwrap_or_else(|| token_metadata.to_string())
+}
+
 pub(crate) fn get_metadata_dictionary_name(metadata_kind: &NFTMetadataKind) -> String {
     let name = match metadata_kind {
         NFTMetadataKind::CEP78 => METADATA_CEP78,
@@ -268,3 +608,250 @@ pub(crate) fn get_metadata_dictionary_name(metadata_kind: &NFTMetadataKind) -> S
     };
     name.to_string()
 }
+
+// The number of characters the pattern matcher may examine for a single value, bounding the
+// backtracking of patterns such as `a*a*a*b` on long values.
+const MAX_PATTERN_MATCH_STEPS: usize = 100_000;
+
+#[derive(Clone)]
+enum PatternAtom {
+    Any,
+    Char(char),
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+impl PatternAtom {
+    fn matches(&self, value: char) -> bool {
+        match self {
+            PatternAtom::Any => true,
+            PatternAtom::Char(char) => *char == value,
+            PatternAtom::Class { negated, ranges } => {
+                ranges
+                    .iter()
+                    .any(|(start, end)| (*start..=*end).contains(&value))
+                    != *negated
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct PatternToken {
+    atom: PatternAtom,
+    min: usize,
+    max: Option<usize>,
+}
+
+// A regex-like pattern for custom metadata values. It supports literals, `.`, character classes
+// such as `[a-z_]` or `[^0-9]`, the `\d`, `\w` and `\s` shorthands and their negations, the `*`,
+// `+`, `?` and `{n}`, `{n,}`, `{n,m}` quantifiers and the `^` and `$` anchors. Groups and
+// alternations are not supported. Patterns are parsed along with the schema holding them, which
+// fails to parse if one is not supported.
+#[derive(Clone)]
+struct Pattern {
+    source: String,
+    tokens: Vec<PatternToken>,
+    anchored_start: bool,
+    anchored_end: bool,
+}
+
+impl Pattern {
+    fn parse(pattern: &str) -> Option<Self> {
+        let mut chars = pattern.chars().peekable();
+        let anchored_start = chars.next_if_eq(&'^').is_some();
+        let mut anchored_end = false;
+        let mut tokens = Vec::new();
+        while let Some(char) = chars.next() {
+            // Nothing can follow the end anchor.
+            if anchored_end {
+                return None;
+            }
+            let atom = match char {
+                '$' => {
+                    anchored_end = true;
+                    continue;
+                }
+                '.' => PatternAtom::Any,
+                '\\' => Self::parse_escape(chars.next()?),
+                '[' => Self::parse_class(&mut chars)?,
+                '*' | '+' | '?' | '{' | '}' | '(' | ')' | '|' | ']' | '^' => return None,
+                char => PatternAtom::Char(char),
+            };
+            let (min, max) = match chars.next_if(|char| matches!(char, '*' | '+' | '?' | '{')) {
+                Some('*') => (0, None),
+                Some('+') => (1, None),
+                Some('?') => (0, Some(1)),
+                Some(_) => Self::parse_repetition(&mut chars)?,
+                None => (1, Some(1)),
+            };
+            tokens.push(PatternToken { atom, min, max });
+        }
+        Some(Pattern {
+            source: pattern.to_string(),
+            tokens,
+            anchored_start,
+            anchored_end,
+        })
+    }
+
+    fn shorthand_ranges(char: char) -> Option<Vec<(char, char)>> {
+        match char {
+            'd' => Some(vec![('0', '9')]),
+            'w' => Some(vec![('a', 'z'), ('A', 'Z'), ('0', '9'), ('_', '_')]),
+            's' => Some(vec![(' ', ' '), ('\t', '\t'), ('\n', '\n'), ('\r', '\r')]),
+            _ => None,
+        }
+    }
+
+    fn parse_escape(char: char) -> PatternAtom {
+        match Self::shorthand_ranges(char.to_ascii_lowercase()) {
+            Some(ranges) => PatternAtom::Class {
+                negated: char.is_ascii_uppercase(),
+                ranges,
+            },
+            None => PatternAtom::Char(char),
+        }
+    }
+
+    fn parse_class(chars: &mut Peekable<Chars>) -> Option<PatternAtom> {
+        let negated = chars.next_if_eq(&'^').is_some();
+        let mut ranges = Vec::new();
+        loop {
+            let start = match chars.next()? {
+                ']' => break,
+                '\\' => {
+                    let escaped = chars.next()?;
+                    if let Some(shorthand_ranges) = Self::shorthand_ranges(escaped) {
+                        ranges.extend(shorthand_ranges);
+                        continue;
+                    }
+                    escaped
+                }
+                char => char,
+            };
+            // A trailing dash is a literal dash.
+            if chars.peek() == Some(&'-') {
+                chars.next();
+                if chars.peek() == Some(&']') {
+                    ranges.push((start, start));
+                    ranges.push(('-', '-'));
+                    continue;
+                }
+                let end = match chars.next()? {
+                    '\\' => chars.next()?,
+                    char => char,
+                };
+                if end < start {
+                    return None;
+                }
+                ranges.push((start, end));
+            } else {
+                ranges.push((start, start));
+            }
+        }
+        if ranges.is_empty() {
+            return None;
+        }
+        Some(PatternAtom::Class { negated, ranges })
+    }
+
+    fn parse_repetition(chars: &mut Peekable<Chars>) -> Option<(usize, Option<usize>)> {
+        let mut repetition = String::new();
+        loop {
+            match chars.next()? {
+                '}' => break,
+                char => repetition.push(char),
+            }
+        }
+        match repetition.split_once(',') {
+            None => {
+                let count = repetition.parse().ok()?;
+                Some((count, Some(count)))
+            }
+            Some((min, "")) => Some((min.parse().ok()?, None)),
+            Some((min, max)) => {
+                let (min, max): (usize, usize) = (min.parse().ok()?, max.parse().ok()?);
+                if max < min {
+                    return None;
+                }
+                Some((min, Some(max)))
+            }
+        }
+    }
+
+    // Returns None if matching the value takes more than MAX_PATTERN_MATCH_STEPS.
+    fn is_match(&self, value: &str) -> Option<bool> {
+        let chars: Vec<char> = value.chars().collect();
+        let mut steps = 0;
+        if self.anchored_start {
+            return self.is_match_at(0, &chars, &mut steps);
+        }
+        for start in 0..=chars.len() {
+            if self.is_match_at(0, &chars[start..], &mut steps)? {
+                return Some(true);
+            }
+        }
+        Some(false)
+    }
+
+    // Greedily consumes as many characters as the token allows, backtracking on failure. Every
+    // character examined counts as a step.
+    fn is_match_at(&self, token_index: usize, chars: &[char], steps: &mut usize) -> Option<bool> {
+        let token = match self.tokens.get(token_index) {
+            Some(token) => token,
+            None => return Some(!self.anchored_end || chars.is_empty()),
+        };
+        let max = token.max.map_or(chars.len(), |max| max.min(chars.len()));
+        let mut count = 0;
+        while count < max && token.atom.matches(chars[count]) {
+            count += 1;
+        }
+        *steps += count + 1;
+        if *steps > MAX_PATTERN_MATCH_STEPS {
+            return None;
+        }
+        if count < token.min {
+            return Some(false);
+        }
+        for taken in (token.min..=count).rev() {
+            if self.is_match_at(token_index + 1, &chars[taken..], steps)? {
+                return Some(true);
+            }
+        }
+        Some(false)
+    }
+}
+
+impl Serialize for Pattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+impl<'de> Deserialize<'de> for Pattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let source = String::deserialize(deserializer)?;
+        Pattern::parse(&source).ok_or_else(|| de::Error::custom("unsupported pattern"))
+    }
+}
+
+impl ToBytes for Pattern {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.source.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.source.serialized_length()
+    }
+}
+
+impl FromBytes for Pattern {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (source, remainder) = String::from_bytes(bytes)?;
+        let pattern = Pattern::parse(&source).ok_or(bytesrepr::Error::Formatting)?;
+        Ok((pattern, remainder))
+    }
+}
@@ -12,10 +12,10 @@ use casper_types::{
 
 use core::convert::TryFrom;
 
-use crate::error::NFTCoreError;
+use crate::{constants::PAGE_BITMAP_HEADER, error::NFTCoreError};
 
 #[repr(u8)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub enum WhitelistMode {
     Unlocked = 0,
     Locked = 1,
@@ -54,7 +54,7 @@ impl TryFrom<u8> for NFTHolderMode {
     }
 }
 
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 #[repr(u8)]
 pub enum MintingMode {
     /// The ability to mint NFTs is restricted to the installing account only.
@@ -210,7 +210,7 @@ impl ToBytes for NFTMetadataKind {
 }
 
 #[repr(u8)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub enum OwnershipMode {
     /// The minter owns it and can never transfer it.
     Minter = 0,
@@ -253,9 +253,13 @@ impl TryFrom<u8> for NFTIdentifierMode {
 }
 
 #[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub enum MetadataMutability {
     Immutable = 0,
     Mutable = 1,
+    IssuerOnly = 2,
+    OwnerOrIssuer = 3,
+    Installer = 4,
 }
 
 impl TryFrom<u8> for MetadataMutability {
@@ -265,6 +269,9 @@ impl TryFrom<u8> for MetadataMutability {
         match value {
             0 => Ok(MetadataMutability::Immutable),
             1 => Ok(MetadataMutability::Mutable),
+            2 => Ok(MetadataMutability::IssuerOnly),
+            3 => Ok(MetadataMutability::OwnerOrIssuer),
+            4 => Ok(MetadataMutability::Installer),
             _ => Err(NFTCoreError::InvalidMetadataMutability),
         }
     }
@@ -367,6 +374,7 @@ impl CLTyped for TokenIdentifier {
 }
 
 #[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub enum BurnMode {
     Burnable = 0,
     NonBurnable = 1,
@@ -385,7 +393,7 @@ impl TryFrom<u8> for BurnMode {
 }
 
 #[repr(u8)]
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum OwnerReverseLookupMode {
     NoLookUp = 0,
     Complete = 1,
@@ -463,3 +471,205 @@ impl From<u8> for TransferFilterContractResult {
         }
     }
 }
+
+// Protocol spoken by the transfer filter contract. V1 filters expose `can_transfer` and answer a
+// TransferFilterContractResult, V2 filters expose `can_transfer_v2`, receive the caller and the
+// transfer data as well, and answer 0 to let the transfer proceed or a non zero deny reason.
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum TransferFilterContractVersion {
+    V1 = 1,
+    V2 = 2,
+}
+
+impl TryFrom<u8> for TransferFilterContractVersion {
+    type Error = NFTCoreError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(TransferFilterContractVersion::V1),
+            2 => Ok(TransferFilterContractVersion::V2),
+            _ => Err(NFTCoreError::InvalidTransferFilterContractVersion),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum Role {
+    Minter = 0,
+    MetadataEditor = 1,
+    Pauser = 2,
+    Burner = 3,
+}
+
+impl TryFrom<u8> for Role {
+    type Error = NFTCoreError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Role::Minter),
+            1 => Ok(Role::MetadataEditor),
+            2 => Ok(Role::Pauser),
+            3 => Ok(Role::Burner),
+            _ => Err(NFTCoreError::InvalidRole),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum PageEncoding {
+    BoolList = 0,
+    Bitmap = 1,
+}
+
+impl PageEncoding {
+    // Packs the flags of a page or page table record eight to a byte behind the bitmap header,
+    // flag `i` being bit `i % 8` of byte `i / 8`.
+    pub fn encode(flags: &[bool]) -> Vec<u8> {
+        let mut record = vec![0u8; 1 + flags.len().div_ceil(8)];
+        record[0] = PAGE_BITMAP_HEADER;
+        for (index, _) in flags.iter().enumerate().filter(|(_, flag)| **flag) {
+            record[1 + index / 8] |= 1 << (index % 8);
+        }
+        record
+    }
+
+    // Unpacks the flags of a page or page table record stored in either encoding. Bitmap records
+    // are padded to a multiple of eight flags.
+    pub fn decode(record: &[u8]) -> Vec<bool> {
+        match record.split_first() {
+            Some((&PAGE_BITMAP_HEADER, bitmap)) => (0..bitmap.len() * 8)
+                .map(|index| bitmap[index / 8] & (1 << (index % 8)) != 0)
+                .collect(),
+            _ => record.iter().map(|flag| *flag != 0).collect(),
+        }
+    }
+}
+
+impl TryFrom<u8> for PageEncoding {
+    type Error = NFTCoreError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(PageEncoding::BoolList),
+            1 => Ok(PageEncoding::Bitmap),
+            _ => Err(NFTCoreError::InvalidPageEncoding),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum MetadataStorageMode {
+    /// Each metadata kind keeps its own copy of the token metadata in its dictionary.
+    PerKind = 0,
+    /// The token metadata is kept once, with the metadata kinds it validated against.
+    Compact = 1,
+}
+
+impl TryFrom<u8> for MetadataStorageMode {
+    type Error = NFTCoreError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MetadataStorageMode::PerKind),
+            1 => Ok(MetadataStorageMode::Compact),
+            _ => Err(NFTCoreError::InvalidMetadataStorageMode),
+        }
+    }
+}
+
+// The modalities entry points act on, fixed at install or changed only by migrate, stored
+// together under the config named key so that each call reads them at once. The individual named
+// keys of the modalities are kept for off-chain readers and for migrate to rebuild the record from.
+// The record is stored as `Bytes` holding its serialization, laid out as documented in
+// docs/modalities.md.
+pub struct Config {
+    pub ownership_mode: OwnershipMode,
+    pub holder_mode: NFTHolderMode,
+    pub whitelist_mode: WhitelistMode,
+    pub minting_mode: MintingMode,
+    pub identifier_mode: NFTIdentifierMode,
+    pub metadata_mutability: MetadataMutability,
+    pub burn_mode: BurnMode,
+    pub reporting_mode: OwnerReverseLookupMode,
+    pub events_mode: EventsMode,
+    pub transfer_filter_contract_version: TransferFilterContractVersion,
+    pub page_encoding: PageEncoding,
+    pub page_size: u64,
+    pub nft_metadata_kinds: MetadataRequirement,
+    pub metadata_storage_mode: MetadataStorageMode,
+}
+
+fn modality_from_bytes<T: TryFrom<u8>>(bytes: &[u8]) -> Result<(T, &[u8]), bytesrepr::Error> {
+    let (value, remainder) = u8::from_bytes(bytes)?;
+    let modality = T::try_from(value).map_err(|_| bytesrepr::Error::Formatting)?;
+    Ok((modality, remainder))
+}
+
+impl ToBytes for Config {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend([
+            self.ownership_mode as u8,
+            self.holder_mode as u8,
+            self.whitelist_mode as u8,
+            self.minting_mode as u8,
+            self.identifier_mode as u8,
+            self.metadata_mutability as u8,
+            self.burn_mode as u8,
+            self.reporting_mode as u8,
+            self.events_mode as u8,
+            self.transfer_filter_contract_version as u8,
+            self.page_encoding as u8,
+            self.metadata_storage_mode as u8,
+        ]);
+        result.extend(self.page_size.to_bytes()?);
+        result.extend(self.nft_metadata_kinds.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        12 * U8_SERIALIZED_LENGTH
+            + U64_SERIALIZED_LENGTH
+            + self.nft_metadata_kinds.serialized_length()
+    }
+}
+
+impl FromBytes for Config {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (ownership_mode, remainder) = modality_from_bytes(bytes)?;
+        let (holder_mode, remainder) = modality_from_bytes(remainder)?;
+        let (whitelist_mode, remainder) = modality_from_bytes(remainder)?;
+        let (minting_mode, remainder) = modality_from_bytes(remainder)?;
+        let (identifier_mode, remainder) = modality_from_bytes(remainder)?;
+        let (metadata_mutability, remainder) = modality_from_bytes(remainder)?;
+        let (burn_mode, remainder) = modality_from_bytes(remainder)?;
+        let (reporting_mode, remainder) = modality_from_bytes(remainder)?;
+        let (events_mode, remainder) = modality_from_bytes(remainder)?;
+        let (transfer_filter_contract_version, remainder) = modality_from_bytes(remainder)?;
+        let (page_encoding, remainder) = modality_from_bytes(remainder)?;
+        let (metadata_storage_mode, remainder) = modality_from_bytes(remainder)?;
+        let (page_size, remainder) = u64::from_bytes(remainder)?;
+        let (nft_metadata_kinds, remainder) = MetadataRequirement::from_bytes(remainder)?;
+        let config = Config {
+            ownership_mode,
+            holder_mode,
+            whitelist_mode,
+            minting_mode,
+            identifier_mode,
+            metadata_mutability,
+            burn_mode,
+            reporting_mode,
+            events_mode,
+            transfer_filter_contract_version,
+            page_encoding,
+            page_size,
+            nft_metadata_kinds,
+            metadata_storage_mode,
+        };
+        Ok((config, remainder))
+    }
+}
@@ -1,5 +1,6 @@
 use alloc::{
     borrow::ToOwned,
+    collections::BTreeMap,
     format,
     string::{String, ToString},
     vec,
@@ -16,35 +17,39 @@ use casper_contract::{
 use casper_types::{
     account::AccountHash,
     api_error,
-    bytesrepr::{self, FromBytes, ToBytes},
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
     system::CallStackElement,
     ApiError, CLTyped, ContractHash, ContractPackageHash, Key, URef,
 };
 
 use crate::{
     constants::{
-        ACL_WHITELIST, ARG_TOKEN_HASH, ARG_TOKEN_ID, BURNT_TOKENS, BURN_MODE, CONTRACT_WHITELIST,
-        HASH_BY_INDEX, HOLDER_MODE, INDEX_BY_HASH, MIGRATION_FLAG, MINTING_MODE,
-        NUMBER_OF_MINTED_TOKENS, OWNED_TOKENS, OWNERSHIP_MODE, PAGE_LIMIT, PAGE_TABLE,
+        ACL_PACKAGE_MODE, ACL_WHITELIST, APPROVAL_EXPIRIES, APPROVED, APPROVED_SPENDERS,
+        ARG_EXPIRES_AT, ARG_TOKEN_HASH, ARG_TOKEN_HASHES, ARG_TOKEN_ID, ARG_TOKEN_IDS, BURNERS,
+        BURNT_TOKENS, BURN_FILTER_CONTRACT, BURN_MODE, CONFIG, CONTRACT_WHITELIST, EVENTS_MODE,
+        FROZEN_METADATA, HASH_BY_INDEX, HOLDER_MODE, IDENTIFIER_MODE, INDEX_BY_HASH,
+        MAX_PAGE_LIMIT, METADATA_EDITORS, METADATA_MUTABILITY, METADATA_STORAGE_MODE,
+        MIGRATION_FLAG, MINTING_MODE, MINT_FILTER_CONTRACT, NFT_METADATA_KINDS,
+        NON_TRANSFERABLE_TOKENS, NUMBER_OF_MINTED_TOKENS, OPERATORS, OPERATOR_EXPIRIES,
+        OWNED_TOKENS, OWNERSHIP_MODE, PAGE_ENCODING, PAGE_LIMIT, PAGE_SIZE, PAGE_TABLE, PAUSERS,
         PREFIX_PAGE_DICTIONARY, RECEIPT_NAME, REPORTING_MODE, RLO_MFLAG, TOKEN_OWNERS,
-        TRANSFER_FILTER_CONTRACT, UNMATCHED_HASH_COUNT,
+        TRANSFER_FILTER_CONTRACT, TRANSFER_FILTER_CONTRACT_VERSION, UNMATCHED_HASH_COUNT,
+        WHITELIST_MODE,
     },
     error::NFTCoreError,
     events::events_ces::{
-        Approval, ApprovalForAll, ApprovalRevoked, Burn, MetadataUpdated, Migration, Mint,
-        Transfer, VariablesSet,
+        AdminTransferStarted, AdminTransferred, Approval, ApprovalForAll, ApprovalRevoked, Burn,
+        CollectionMetadataUpdated, MetadataFrozen, MetadataUpdated, Migration, Mint, Transfer,
+        VariablesSet,
     },
     modalities::{
-        BurnMode, MetadataRequirement, MintingMode, NFTHolderMode, NFTIdentifierMode,
-        NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode, Requirement, TokenIdentifier,
+        BurnMode, Config, MetadataRequirement, MintingMode, NFTHolderMode, NFTIdentifierMode,
+        NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode, PageEncoding, Requirement, Role,
+        TokenIdentifier, TransferFilterContractVersion,
     },
     utils,
 };
 
-// The size of a given page, it is currently set to 1000
-// to ease the math around addressing newly minted tokens.
-pub const PAGE_SIZE: u64 = 1000;
-
 pub fn upsert_dictionary_value_from_key<T: CLTyped + FromBytes + ToBytes>(
     dictionary_name: &str,
     key: &str,
@@ -80,6 +85,90 @@ pub fn get_holder_mode() -> Result<NFTHolderMode, NFTCoreError> {
     .try_into()
 }
 
+// Entry points read the modalities once from the config record instead of their individual named
+// keys. The record is stored as the raw bytes of the serialized config.
+pub fn get_config() -> Config {
+    let config_bytes = get_stored_value_with_user_errors::<Bytes>(
+        CONFIG,
+        NFTCoreError::MissingConfig,
+        NFTCoreError::InvalidConfig,
+    );
+    bytesrepr::deserialize_from_slice(config_bytes)
+        .unwrap_or_revert_with(NFTCoreError::InvalidConfig)
+}
+
+pub fn put_config(config: &Config) {
+    let config_bytes = Bytes::from(config.to_bytes().unwrap_or_revert());
+    runtime::put_key(CONFIG, storage::new_uref(config_bytes).into());
+}
+
+// Packs the modalities into the config record from their individual named keys. Called at the end
+// of migrate, once every named key is up to date.
+pub fn put_config_from_named_keys() {
+    let config = Config {
+        ownership_mode: get_ownership_mode().unwrap_or_revert(),
+        holder_mode: get_holder_mode().unwrap_or_revert(),
+        whitelist_mode: get_stored_value_with_user_errors::<u8>(
+            WHITELIST_MODE,
+            NFTCoreError::MissingWhitelistMode,
+            NFTCoreError::InvalidWhitelistMode,
+        )
+        .try_into()
+        .unwrap_or_revert(),
+        minting_mode: get_stored_value_with_user_errors::<u8>(
+            MINTING_MODE,
+            NFTCoreError::MissingMintingMode,
+            NFTCoreError::InvalidMintingMode,
+        )
+        .try_into()
+        .unwrap_or_revert(),
+        identifier_mode: get_stored_value_with_user_errors::<u8>(
+            IDENTIFIER_MODE,
+            NFTCoreError::MissingIdentifierMode,
+            NFTCoreError::InvalidIdentifierMode,
+        )
+        .try_into()
+        .unwrap_or_revert(),
+        metadata_mutability: get_stored_value_with_user_errors::<u8>(
+            METADATA_MUTABILITY,
+            NFTCoreError::MissingMetadataMutability,
+            NFTCoreError::InvalidMetadataMutability,
+        )
+        .try_into()
+        .unwrap_or_revert(),
+        burn_mode: get_burn_mode(),
+        // Contracts migrated without an owner reverse lookup may not record a reporting mode.
+        reporting_mode: if named_uref_exists(REPORTING_MODE) {
+            get_reporting_mode()
+        } else {
+            OwnerReverseLookupMode::NoLookUp
+        },
+        events_mode: get_stored_value_with_user_errors::<u8>(
+            EVENTS_MODE,
+            NFTCoreError::MissingEventsMode,
+            NFTCoreError::InvalidEventsMode,
+        )
+        .try_into()
+        .unwrap_or_revert(),
+        transfer_filter_contract_version: get_transfer_filter_contract_version(),
+        page_encoding: get_page_encoding(),
+        page_size: get_page_size(),
+        nft_metadata_kinds: get_stored_value_with_user_errors(
+            NFT_METADATA_KINDS,
+            NFTCoreError::MissingNFTMetadataKind,
+            NFTCoreError::InvalidNFTMetadataKind,
+        ),
+        metadata_storage_mode: get_stored_value_with_user_errors::<u8>(
+            METADATA_STORAGE_MODE,
+            NFTCoreError::MissingMetadataStorageMode,
+            NFTCoreError::InvalidMetadataStorageMode,
+        )
+        .try_into()
+        .unwrap_or_revert(),
+    };
+    put_config(&config);
+}
+
 pub fn encode_dictionary_item_key(key: Key) -> String {
     match key {
         Key::Account(account_hash) => account_hash.to_string(),
@@ -294,8 +383,7 @@ pub enum Caller {
     StoredCaller(ContractHash, ContractPackageHash),
 }
 
-pub fn get_verified_caller() -> Result<Caller, NFTCoreError> {
-    let holder_mode = get_holder_mode()?;
+pub fn get_verified_caller(holder_mode: NFTHolderMode) -> Result<Caller, NFTCoreError> {
     match *runtime::get_call_stack()
         .iter()
         .nth_back(1)
@@ -348,6 +436,35 @@ pub fn get_token_identifier_from_runtime_args(
     }
 }
 
+pub fn get_token_identifiers_from_runtime_args(
+    identifier_mode: &NFTIdentifierMode,
+) -> Vec<TokenIdentifier> {
+    let token_identifiers: Vec<TokenIdentifier> = match identifier_mode {
+        NFTIdentifierMode::Ordinal => get_named_arg_with_user_errors::<Vec<u64>>(
+            ARG_TOKEN_IDS,
+            NFTCoreError::MissingTokenID,
+            NFTCoreError::InvalidTokenIdentifier,
+        )
+        .unwrap_or_revert()
+        .into_iter()
+        .map(TokenIdentifier::new_index)
+        .collect(),
+        NFTIdentifierMode::Hash => get_named_arg_with_user_errors::<Vec<String>>(
+            ARG_TOKEN_HASHES,
+            NFTCoreError::MissingTokenID,
+            NFTCoreError::InvalidTokenIdentifier,
+        )
+        .unwrap_or_revert()
+        .into_iter()
+        .map(TokenIdentifier::new_hash)
+        .collect(),
+    };
+    if token_identifiers.is_empty() {
+        runtime::revert(NFTCoreError::EmptyTokenBatch)
+    }
+    token_identifiers
+}
+
 pub fn get_token_identifiers_from_dictionary(
     identifier_mode: &NFTIdentifierMode,
     owners_item_key: &str,
@@ -392,6 +509,130 @@ pub fn is_token_burned(token_identifier: &TokenIdentifier) -> bool {
         .is_some()
 }
 
+pub fn is_token_non_transferable(token_identifier: &TokenIdentifier) -> bool {
+    get_dictionary_value_from_key::<()>(
+        NON_TRANSFERABLE_TOKENS,
+        &token_identifier.get_dictionary_item_key(),
+    )
+    .is_some()
+}
+
+pub fn is_token_metadata_frozen(token_identifier: &TokenIdentifier) -> bool {
+    get_dictionary_value_from_key::<()>(
+        FROZEN_METADATA,
+        &token_identifier.get_dictionary_item_key(),
+    )
+    .is_some()
+}
+
+// Reads the optional block time at which an approval lapses, which must lie in the future.
+pub fn get_expires_at_from_runtime_args() -> Option<u64> {
+    let expires_at = get_optional_named_arg_with_user_errors::<u64>(
+        ARG_EXPIRES_AT,
+        NFTCoreError::InvalidExpiresAt,
+    )?;
+    if expires_at <= u64::from(runtime::get_blocktime()) {
+        runtime::revert(NFTCoreError::InvalidExpiresAt)
+    }
+    Some(expires_at)
+}
+
+// Approvals recorded without an expiry never lapse.
+pub fn is_approval_expired(dictionary_name: &str, item_key: &str) -> bool {
+    match get_dictionary_value_from_key::<Option<u64>>(dictionary_name, item_key) {
+        Some(Some(expires_at)) => u64::from(runtime::get_blocktime()) >= expires_at,
+        Some(None) | None => false,
+    }
+}
+
+pub fn get_approval_expiry_item_key(token_identifier: &TokenIdentifier, spender: &Key) -> String {
+    encode_key_and_value(spender, &token_identifier.get_dictionary_item_key())
+}
+
+// Returns the spenders approved for the token, falling back to the single spender recorded in
+// APPROVED before several spenders could be approved.
+pub fn get_approved_spenders(token_identifier: &TokenIdentifier) -> Vec<Key> {
+    let token_identifier_dictionary_key = token_identifier.get_dictionary_item_key();
+    match get_dictionary_value_from_key::<Vec<Key>>(
+        APPROVED_SPENDERS,
+        &token_identifier_dictionary_key,
+    ) {
+        Some(spenders) => spenders,
+        None => {
+            get_dictionary_value_from_key::<Option<Key>>(APPROVED, &token_identifier_dictionary_key)
+                .flatten()
+                .into_iter()
+                .collect()
+        }
+    }
+}
+
+// Returns the spenders approved for the token whose approval has not expired.
+pub fn get_active_approved_spenders(token_identifier: &TokenIdentifier) -> Vec<Key> {
+    get_approved_spenders(token_identifier)
+        .into_iter()
+        .filter(|spender| {
+            !is_approval_expired(
+                APPROVAL_EXPIRIES,
+                &get_approval_expiry_item_key(token_identifier, spender),
+            )
+        })
+        .collect()
+}
+
+pub fn is_approved_spender(token_identifier: &TokenIdentifier, spender: &Key) -> bool {
+    get_approved_spenders(token_identifier).contains(spender)
+        && !is_approval_expired(
+            APPROVAL_EXPIRIES,
+            &get_approval_expiry_item_key(token_identifier, spender),
+        )
+}
+
+pub fn set_approved_spenders(token_identifier: &TokenIdentifier, spenders: Vec<Key>) {
+    upsert_dictionary_value_from_key(
+        APPROVED_SPENDERS,
+        &token_identifier.get_dictionary_item_key(),
+        spenders,
+    );
+}
+
+pub fn is_operator(owner: &Key, operator: &Key) -> bool {
+    let owner_operator_item_key = encode_key_and_value(owner, operator);
+    get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key).unwrap_or_default()
+        && !is_approval_expired(OPERATOR_EXPIRIES, &owner_operator_item_key)
+}
+
+// The minter role is held through the ACL whitelist, which predates roles.
+pub fn get_role_dictionary_name(role: Role) -> &'static str {
+    match role {
+        Role::Minter => ACL_WHITELIST,
+        Role::MetadataEditor => METADATA_EDITORS,
+        Role::Pauser => PAUSERS,
+        Role::Burner => BURNERS,
+    }
+}
+
+pub fn has_role(role: Role, role_holder: Key) -> bool {
+    get_dictionary_value_from_key::<bool>(
+        get_role_dictionary_name(role),
+        &encode_dictionary_item_key(role_holder),
+    )
+    .unwrap_or_default()
+}
+
+// With ACL package mode, a contract caller holds the roles granted to its package.
+pub fn caller_has_role(role: Role, caller: Key, contract_package: Option<Key>) -> bool {
+    let acl_package_mode: bool = get_stored_value_with_user_errors::<bool>(
+        ACL_PACKAGE_MODE,
+        NFTCoreError::MissingACLPackageMode,
+        NFTCoreError::InvalidACLPackageMode,
+    );
+    match (acl_package_mode, contract_package) {
+        (true, Some(contract_package)) => has_role(role, contract_package),
+        _ => has_role(role, caller),
+    }
+}
+
 pub fn get_transfer_filter_contract() -> Option<ContractHash> {
     if !named_uref_exists(TRANSFER_FILTER_CONTRACT) {
         None
@@ -404,20 +645,53 @@ pub fn get_transfer_filter_contract() -> Option<ContractHash> {
     }
 }
 
-pub fn max_number_of_pages(total_token_supply: u64) -> u64 {
-    if total_token_supply < PAGE_SIZE {
-        let dictionary_name = format!("{PREFIX_PAGE_DICTIONARY}_{}", 0);
-        storage::new_dictionary(&dictionary_name)
-            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+pub fn get_filter_contract_hash(filter_contract_key: Key, invalid: NFTCoreError) -> ContractHash {
+    ContractHash::from(
+        filter_contract_key
+            .into_hash()
+            .unwrap_or_revert_with(invalid),
+    )
+}
+
+pub fn get_mint_filter_contract() -> Option<ContractHash> {
+    get_stored_value_with_user_errors::<Option<ContractHash>>(
+        MINT_FILTER_CONTRACT,
+        NFTCoreError::MissingMintFilterContract,
+        NFTCoreError::InvalidMintFilterContract,
+    )
+}
+
+pub fn get_burn_filter_contract() -> Option<ContractHash> {
+    get_stored_value_with_user_errors::<Option<ContractHash>>(
+        BURN_FILTER_CONTRACT,
+        NFTCoreError::MissingBurnFilterContract,
+        NFTCoreError::InvalidBurnFilterContract,
+    )
+}
+
+// Contracts installed before the v2 filter protocol only know v1 filters.
+pub fn get_transfer_filter_contract_version() -> TransferFilterContractVersion {
+    if !named_uref_exists(TRANSFER_FILTER_CONTRACT_VERSION) {
+        TransferFilterContractVersion::V1
+    } else {
+        get_stored_value_with_user_errors::<u8>(
+            TRANSFER_FILTER_CONTRACT_VERSION,
+            NFTCoreError::MissingTransferFilterContractVersion,
+            NFTCoreError::InvalidTransferFilterContractVersion,
+        )
+        .try_into()
+        .unwrap_or_revert()
+    }
+}
+
+// The page dictionaries themselves are only created once a token of their range is recorded, see
+// get_or_create_page_uref.
+pub fn max_number_of_pages(total_token_supply: u64, page_size: u64) -> u64 {
+    if total_token_supply < page_size {
         1
     } else {
-        let max_number_of_pages = total_token_supply / PAGE_SIZE;
-        let overflow = total_token_supply % PAGE_SIZE;
-        for page_number in 0..max_number_of_pages {
-            let dictionary_name = format!("{PREFIX_PAGE_DICTIONARY}_{page_number}");
-            storage::new_dictionary(&dictionary_name)
-                .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
-        }
+        let max_number_of_pages = total_token_supply / page_size;
+        let overflow = total_token_supply % page_size;
         // With a page size of say 1000 and a token supply of 1050
         // max_number_of_pages = 1, but we need an additional page
         // to track the overflow
@@ -429,6 +703,59 @@ pub fn max_number_of_pages(total_token_supply: u64) -> u64 {
     }
 }
 
+// Reverts if the total token supply spans more pages than MAX_PAGE_LIMIT at the given page size.
+pub fn check_total_token_supply(total_token_supply: u64, page_size: u64) {
+    if max_number_of_pages(total_token_supply, page_size) > MAX_PAGE_LIMIT {
+        runtime::revert(NFTCoreError::ExceededMaxTotalSupply)
+    }
+}
+
+// Raises the page limit to the number of pages a raised total token supply needs. The page tables
+// of registered owners are widened to the new page limit as they are next updated.
+pub fn raise_page_limit(total_token_supply: u64) {
+    let page_limit_uref = get_uref(
+        PAGE_LIMIT,
+        NFTCoreError::MissingPageLimit,
+        NFTCoreError::InvalidPageLimit,
+    );
+    let page_limit: u64 = read_with_user_errors(
+        page_limit_uref,
+        NFTCoreError::MissingPageLimit,
+        NFTCoreError::InvalidPageLimit,
+    );
+
+    let required_number_of_pages = max_number_of_pages(total_token_supply, get_page_size());
+    if required_number_of_pages > page_limit {
+        storage::write(page_limit_uref, required_number_of_pages);
+    }
+}
+
+// Returns the page dictionary tracking the given page table entry, creating it the first time a
+// token of its range is recorded. The dictionaries keep the page_{page_table_entry} naming.
+pub fn get_or_create_page_uref(page_table_entry: u64) -> URef {
+    let dictionary_name = format!("{PREFIX_PAGE_DICTIONARY}_{page_table_entry}");
+    match runtime::get_key(&dictionary_name) {
+        Some(page_key) => page_key
+            .into_uref()
+            .unwrap_or_revert_with(NFTCoreError::InvalidPageUref),
+        None => storage::new_dictionary(&dictionary_name)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary),
+    }
+}
+
+// Page tables registered before the total token supply was raised are narrower than the page
+// limit, they are widened before a page past their width is allocated.
+fn widen_page_table(page_table: &mut Vec<bool>, page_table_entry: u64) {
+    if page_table_entry as usize >= page_table.len() {
+        let page_table_width = get_stored_value_with_user_errors::<u64>(
+            PAGE_LIMIT,
+            NFTCoreError::MissingPageLimit,
+            NFTCoreError::InvalidPageLimit,
+        );
+        page_table.resize(page_table_width as usize, false);
+    }
+}
+
 pub fn insert_hash_id_lookups(
     current_number_of_minted_tokens: u64,
     token_identifier: TokenIdentifier,
@@ -495,7 +822,87 @@ pub fn get_token_index(token_identifier: &TokenIdentifier) -> u64 {
     }
 }
 
+// Maps a token index back to the identifier of the token, through the hash by index lookup in the
+// Hash identifier mode.
+pub fn get_token_identifier_by_index(
+    token_index: u64,
+    identifier_mode: &NFTIdentifierMode,
+) -> TokenIdentifier {
+    match identifier_mode {
+        NFTIdentifierMode::Ordinal => TokenIdentifier::new_index(token_index),
+        NFTIdentifierMode::Hash => {
+            let token_hash =
+                get_dictionary_value_from_key::<String>(HASH_BY_INDEX, &token_index.to_string())
+                    .unwrap_or_revert_with(NFTCoreError::InvalidTokenIdentifier);
+            TokenIdentifier::new_hash(token_hash)
+        }
+    }
+}
+
+// Walks the page table and the pages of the owner in token index order and returns at most
+// `limit` of the tokens it holds after skipping the first `offset` ones. Burnt tokens stay
+// marked on the pages of their last owner and are left out.
+pub fn get_owned_token_identifiers(
+    owner_key: Key,
+    offset: u64,
+    limit: u64,
+    config: &Config,
+) -> Vec<TokenIdentifier> {
+    let page_size = config.page_size;
+    let owner_item_key = encode_dictionary_item_key(owner_key);
+
+    let page_table_uref = get_uref(
+        PAGE_TABLE,
+        NFTCoreError::MissingPageTableURef,
+        NFTCoreError::InvalidPageTableURef,
+    );
+
+    // An owner that was never registered holds no token.
+    let page_table = match get_page_record(page_table_uref, &owner_item_key) {
+        Some(page_table) => page_table,
+        None => return vec![],
+    };
+
+    let mut skipped = 0u64;
+    let mut token_identifiers: Vec<TokenIdentifier> = vec![];
+    for (page_table_entry, _) in page_table
+        .iter()
+        .enumerate()
+        .filter(|(_, is_allocated)| **is_allocated)
+    {
+        let page_uref = get_uref(
+            &format!("{PREFIX_PAGE_DICTIONARY}_{page_table_entry}"),
+            NFTCoreError::MissingPageUref,
+            NFTCoreError::InvalidPageUref,
+        );
+        let page = get_page_record(page_uref, &owner_item_key)
+            .unwrap_or_revert_with(NFTCoreError::MissingPage);
+
+        for (page_address, _) in page.iter().enumerate().filter(|(_, is_owned)| **is_owned) {
+            let token_index = page_table_entry as u64 * page_size + page_address as u64;
+            let token_identifier =
+                get_token_identifier_by_index(token_index, &config.identifier_mode);
+            if is_token_burned(&token_identifier) {
+                continue;
+            }
+            if skipped < offset {
+                skipped += 1;
+                continue;
+            }
+            if token_identifiers.len() as u64 == limit {
+                return token_identifiers;
+            }
+            token_identifiers.push(token_identifier);
+        }
+    }
+    token_identifiers
+}
+
+// Runs within migrate, before the config record is written, and reads the page settings from
+// their named keys.
 pub fn migrate_owned_tokens_in_ordinal_mode() {
+    let page_size = get_page_size();
+    let page_encoding = get_page_encoding();
     let current_number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
         NUMBER_OF_MINTED_TOKENS,
         NFTCoreError::MissingTotalTokenSupply,
@@ -528,37 +935,31 @@ pub fn migrate_owned_tokens_in_ordinal_mode() {
             .unwrap_or_revert();
             for token_identifier in owned_tokens_list.into_iter() {
                 let token_id = token_identifier.get_index().unwrap_or_revert();
-                let page_number = token_id / PAGE_SIZE;
-                let page_index = token_id % PAGE_SIZE;
-                let mut page_record = match storage::dictionary_get::<Vec<bool>>(
-                    page_table_uref,
-                    &token_owner_item_key,
-                )
-                .unwrap_or_revert()
+                let page_number = token_id / page_size;
+                let page_index = token_id % page_size;
+                let mut page_record = match get_page_record(page_table_uref, &token_owner_item_key)
                 {
                     Some(page_record) => page_record,
                     None => vec![false; page_table_width as usize],
                 };
-                let page_uref = get_uref(
-                    &format!("{PREFIX_PAGE_DICTIONARY}_{page_number}"),
-                    NFTCoreError::MissingStorageUref,
-                    NFTCoreError::InvalidStorageUref,
-                );
+                let page_uref = get_or_create_page_uref(page_number);
                 let _ = core::mem::replace(&mut page_record[page_number as usize], true);
-                storage::dictionary_put(page_table_uref, &token_owner_item_key, page_record);
-                let mut page =
-                    match storage::dictionary_get::<Vec<bool>>(page_uref, &token_owner_item_key)
-                        .unwrap_or_revert()
-                    {
-                        None => vec![false; PAGE_SIZE as usize],
-                        Some(single_page) => single_page,
-                    };
+                put_page_record(
+                    page_table_uref,
+                    &token_owner_item_key,
+                    page_record,
+                    page_encoding,
+                );
+                let mut page = match get_page_record(page_uref, &token_owner_item_key) {
+                    None => vec![false; page_size as usize],
+                    Some(single_page) => single_page,
+                };
                 let is_already_marked_as_owned =
                     core::mem::replace(&mut page[page_index as usize], true);
                 if is_already_marked_as_owned {
                     runtime::revert(NFTCoreError::InvalidPageIndex)
                 }
-                storage::dictionary_put(page_uref, &token_owner_item_key, page);
+                put_page_record(page_uref, &token_owner_item_key, page, page_encoding);
                 searched_token_ids.push(token_id)
             }
         }
@@ -581,19 +982,16 @@ pub fn should_migrate_token_hashes(token_owner: Key) -> bool {
     );
     // If the owner has registered, then they will have an page table entry
     // but it will contain no bits set.
-    let page_table = storage::dictionary_get::<Vec<bool>>(
-        page_table_uref,
-        &encode_dictionary_item_key(token_owner),
-    )
-    .unwrap_or_revert()
-    .unwrap_or_revert_with(NFTCoreError::UnregisteredOwnerFromMigration);
+    let page_table = get_page_record(page_table_uref, &encode_dictionary_item_key(token_owner))
+        .unwrap_or_revert_with(NFTCoreError::UnregisteredOwnerFromMigration);
     if page_table.contains(&true) {
         return false;
     }
     true
 }
 
-pub fn migrate_token_hashes(token_owner: Key) {
+pub fn migrate_token_hashes(token_owner: Key, config: &Config) {
+    let page_size = config.page_size;
     let mut unmatched_hash_count = get_stored_value_with_user_errors::<u64>(
         UNMATCHED_HASH_COUNT,
         NFTCoreError::MissingUnmatchedHashCount,
@@ -623,30 +1021,26 @@ pub fn migrate_token_hashes(token_owner: Key) {
 
     for token_identifier in owned_tokens_list.into_iter() {
         let token_address = unmatched_hash_count - 1;
-        let page_table_entry = token_address / PAGE_SIZE;
-        let page_address = token_address % PAGE_SIZE;
-        let mut page_table =
-            match storage::dictionary_get::<Vec<bool>>(page_table_uref, &token_owner_item_key)
-                .unwrap_or_revert()
-            {
-                Some(page_record) => page_record,
-                None => vec![false; page_table_width as usize],
-            };
+        let page_table_entry = token_address / page_size;
+        let page_address = token_address % page_size;
+        let mut page_table = match get_page_record(page_table_uref, &token_owner_item_key) {
+            Some(page_record) => page_record,
+            None => vec![false; page_table_width as usize],
+        };
         let _ = core::mem::replace(&mut page_table[page_table_entry as usize], true);
-        storage::dictionary_put(page_table_uref, &token_owner_item_key, page_table);
-        let page_uref = get_uref(
-            &format!("{PREFIX_PAGE_DICTIONARY}_{page_table_entry}"),
-            NFTCoreError::MissingStorageUref,
-            NFTCoreError::InvalidStorageUref,
+        put_page_record(
+            page_table_uref,
+            &token_owner_item_key,
+            page_table,
+            config.page_encoding,
         );
-        let mut page = match storage::dictionary_get::<Vec<bool>>(page_uref, &token_owner_item_key)
-            .unwrap_or_revert()
-        {
+        let page_uref = get_or_create_page_uref(page_table_entry);
+        let mut page = match get_page_record(page_uref, &token_owner_item_key) {
             Some(single_page) => single_page,
-            None => vec![false; PAGE_SIZE as usize],
+            None => vec![false; page_size as usize],
         };
         let _ = core::mem::replace(&mut page[page_address as usize], true);
-        storage::dictionary_put(page_uref, &token_owner_item_key, page);
+        put_page_record(page_uref, &token_owner_item_key, page, config.page_encoding);
         insert_hash_id_lookups(unmatched_hash_count - 1, token_identifier);
         unmatched_hash_count -= 1;
     }
@@ -660,13 +1054,14 @@ pub fn migrate_token_hashes(token_owner: Key) {
     storage::write(unmatched_hash_count_uref, unmatched_hash_count);
 }
 
-pub fn get_receipt_name(page_table_entry: u64) -> String {
+pub fn get_receipt_name(page_table_entry: u64, config: &Config) -> String {
+    let page_size = config.page_size;
     let receipt = utils::get_stored_value_with_user_errors::<String>(
         RECEIPT_NAME,
         NFTCoreError::MissingReceiptName,
         NFTCoreError::InvalidReceiptName,
     );
-    format!("{receipt}_m_{PAGE_SIZE}_p_{page_table_entry}")
+    format!("{receipt}_m_{page_size}_p_{page_table_entry}")
 }
 
 pub fn get_reporting_mode() -> OwnerReverseLookupMode {
@@ -679,16 +1074,64 @@ pub fn get_reporting_mode() -> OwnerReverseLookupMode {
     .unwrap_or_revert()
 }
 
+pub fn get_page_encoding() -> PageEncoding {
+    utils::get_stored_value_with_user_errors::<u8>(
+        PAGE_ENCODING,
+        NFTCoreError::MissingPageEncoding,
+        NFTCoreError::InvalidPageEncoding,
+    )
+    .try_into()
+    .unwrap_or_revert()
+}
+
+// The number of tokens tracked by each page, set at install.
+pub fn get_page_size() -> u64 {
+    utils::get_stored_value_with_user_errors::<u64>(
+        PAGE_SIZE,
+        NFTCoreError::MissingPageSize,
+        NFTCoreError::InvalidPageSize,
+    )
+}
+
+// Reads a page or page table record, whichever encoding it was written with. Bool list records
+// share the byte layout of Bytes and are decoded alongside bitmap records.
+pub fn get_page_record(page_uref: URef, item_key: &str) -> Option<Vec<bool>> {
+    storage::dictionary_get::<Bytes>(page_uref, item_key)
+        .unwrap_or_revert()
+        .map(|record| PageEncoding::decode(&record))
+}
+
+// Writes a page or page table record in the page encoding of the contract. Records written before
+// an opt-in migration to the bitmap encoding are converted as they are next written.
+pub fn put_page_record(
+    page_uref: URef,
+    item_key: &str,
+    flags: Vec<bool>,
+    page_encoding: PageEncoding,
+) {
+    match page_encoding {
+        PageEncoding::BoolList => storage::dictionary_put(page_uref, item_key, flags),
+        PageEncoding::Bitmap => storage::dictionary_put(
+            page_uref,
+            item_key,
+            Bytes::from(PageEncoding::encode(&flags)),
+        ),
+    }
+}
+
 pub fn add_page_entry_and_page_record(
     tokens_count: u64,
     item_key: &str,
     on_mint: bool,
+    config: &Config,
 ) -> (u64, URef) {
+    let page_size = config.page_size;
+
     // there is an explicit page_table;
     // this is the entry in that overall page table which maps to the underlying page
     // upon which this mint's address will exist
-    let page_table_entry = tokens_count / PAGE_SIZE;
-    let page_address = tokens_count % PAGE_SIZE;
+    let page_table_entry = tokens_count / page_size;
+    let page_address = tokens_count % page_size;
 
     // Update the page entry first
     let page_table_uref = utils::get_uref(
@@ -698,36 +1141,30 @@ pub fn add_page_entry_and_page_record(
     );
 
     // Update the individual page record.
-    let page_uref = utils::get_uref(
-        &format!("{PREFIX_PAGE_DICTIONARY}_{page_table_entry}"),
-        NFTCoreError::MissingPageUref,
-        NFTCoreError::InvalidPageUref,
-    );
+    let page_uref = get_or_create_page_uref(page_table_entry);
 
-    let mut page_table =
-        match storage::dictionary_get::<Vec<bool>>(page_table_uref, item_key).unwrap_or_revert() {
-            Some(page_table) => page_table,
-            None => runtime::revert(if on_mint {
-                NFTCoreError::UnregisteredOwnerInMint
-            } else {
-                NFTCoreError::UnregisteredOwnerInTransfer
-            }),
-        };
+    let mut page_table = match get_page_record(page_table_uref, item_key) {
+        Some(page_table) => page_table,
+        None => runtime::revert(if on_mint {
+            NFTCoreError::UnregisteredOwnerInMint
+        } else {
+            NFTCoreError::UnregisteredOwnerInTransfer
+        }),
+    };
 
+    widen_page_table(&mut page_table, page_table_entry);
     let mut page = if !page_table[page_table_entry as usize] {
         // We mark the page table entry to true to signal the allocation of a page.
         let _ = core::mem::replace(&mut page_table[page_table_entry as usize], true);
-        storage::dictionary_put(page_table_uref, item_key, page_table);
-        vec![false; PAGE_SIZE as usize]
+        put_page_record(page_table_uref, item_key, page_table, config.page_encoding);
+        vec![false; page_size as usize]
     } else {
-        storage::dictionary_get::<Vec<bool>>(page_uref, item_key)
-            .unwrap_or_revert()
-            .unwrap_or_revert_with(NFTCoreError::MissingPage)
+        get_page_record(page_uref, item_key).unwrap_or_revert_with(NFTCoreError::MissingPage)
     };
 
     let _ = core::mem::replace(&mut page[page_address as usize], true);
 
-    storage::dictionary_put(page_uref, item_key, page);
+    put_page_record(page_uref, item_key, page, config.page_encoding);
     (page_table_entry, page_uref)
 }
 
@@ -735,9 +1172,11 @@ pub fn update_page_entry_and_page_record(
     tokens_count: u64,
     old_item_key: &str,
     new_item_key: &str,
+    config: &Config,
 ) -> (u64, URef) {
-    let page_table_entry = tokens_count / PAGE_SIZE;
-    let page_address = tokens_count % PAGE_SIZE;
+    let page_size = config.page_size;
+    let page_table_entry = tokens_count / page_size;
+    let page_address = tokens_count % page_size;
 
     let page_uref = utils::get_uref(
         &format!("{PREFIX_PAGE_DICTIONARY}_{page_table_entry}"),
@@ -745,8 +1184,7 @@ pub fn update_page_entry_and_page_record(
         NFTCoreError::InvalidStorageUref,
     );
 
-    let mut source_page = storage::dictionary_get::<Vec<bool>>(page_uref, old_item_key)
-        .unwrap_or_revert()
+    let mut source_page = get_page_record(page_uref, old_item_key)
         .unwrap_or_revert_with(NFTCoreError::InvalidPageNumber);
 
     if !source_page[page_address as usize] {
@@ -755,7 +1193,7 @@ pub fn update_page_entry_and_page_record(
 
     let _ = core::mem::replace(&mut source_page[page_address as usize], false);
 
-    storage::dictionary_put(page_uref, old_item_key, source_page);
+    put_page_record(page_uref, old_item_key, source_page, config.page_encoding);
 
     let page_table_uref = utils::get_uref(
         PAGE_TABLE,
@@ -763,27 +1201,159 @@ pub fn update_page_entry_and_page_record(
         NFTCoreError::InvalidPageTableURef,
     );
 
-    let mut target_page_table = storage::dictionary_get::<Vec<bool>>(page_table_uref, new_item_key)
-        .unwrap_or_revert()
+    let mut target_page_table = get_page_record(page_table_uref, new_item_key)
         .unwrap_or_revert_with(NFTCoreError::UnregisteredOwnerInTransfer);
 
+    widen_page_table(&mut target_page_table, page_table_entry);
     let mut target_page = if !target_page_table[page_table_entry as usize] {
         // Create a new page here
         let _ = core::mem::replace(&mut target_page_table[page_table_entry as usize], true);
-        storage::dictionary_put(page_table_uref, new_item_key, target_page_table);
-        vec![false; PAGE_SIZE as usize]
+        put_page_record(
+            page_table_uref,
+            new_item_key,
+            target_page_table,
+            config.page_encoding,
+        );
+        vec![false; page_size as usize]
     } else {
-        storage::dictionary_get::<Vec<bool>>(page_uref, new_item_key)
-            .unwrap_or_revert()
-            .unwrap_or_revert()
+        get_page_record(page_uref, new_item_key).unwrap_or_revert()
     };
 
     let _ = core::mem::replace(&mut target_page[page_address as usize], true);
 
-    storage::dictionary_put(page_uref, new_item_key, target_page);
+    put_page_record(page_uref, new_item_key, target_page, config.page_encoding);
     (page_table_entry, page_uref)
 }
 
+// Groups token addresses by the page table entry of the page they live on.
+fn group_by_page(tokens_counts: &[u64], page_size: u64) -> BTreeMap<u64, Vec<u64>> {
+    let mut pages: BTreeMap<u64, Vec<u64>> = BTreeMap::new();
+    for tokens_count in tokens_counts {
+        pages
+            .entry(tokens_count / page_size)
+            .or_default()
+            .push(tokens_count % page_size);
+    }
+    pages
+}
+
+// Batch counterpart of add_page_entry_and_page_record, the page table and every page of the
+// owner are written at most once.
+pub fn add_page_entries_and_page_records(
+    tokens_counts: &[u64],
+    item_key: &str,
+    on_mint: bool,
+    config: &Config,
+) -> Vec<(u64, URef)> {
+    let page_size = config.page_size;
+    let page_table_uref = utils::get_uref(
+        PAGE_TABLE,
+        NFTCoreError::MissingPageTableURef,
+        NFTCoreError::InvalidPageTableURef,
+    );
+
+    let mut page_table = match get_page_record(page_table_uref, item_key) {
+        Some(page_table) => page_table,
+        None => runtime::revert(if on_mint {
+            NFTCoreError::UnregisteredOwnerInMint
+        } else {
+            NFTCoreError::UnregisteredOwnerInTransfer
+        }),
+    };
+    let mut page_table_updated = false;
+
+    let mut page_records = vec![];
+    for (page_table_entry, page_addresses) in group_by_page(tokens_counts, page_size) {
+        let page_uref = get_or_create_page_uref(page_table_entry);
+
+        widen_page_table(&mut page_table, page_table_entry);
+        let mut page = if !page_table[page_table_entry as usize] {
+            // We mark the page table entry to true to signal the allocation of a page.
+            let _ = core::mem::replace(&mut page_table[page_table_entry as usize], true);
+            page_table_updated = true;
+            vec![false; page_size as usize]
+        } else {
+            get_page_record(page_uref, item_key).unwrap_or_revert_with(NFTCoreError::MissingPage)
+        };
+
+        for page_address in page_addresses {
+            let _ = core::mem::replace(&mut page[page_address as usize], true);
+        }
+
+        put_page_record(page_uref, item_key, page, config.page_encoding);
+        page_records.push((page_table_entry, page_uref));
+    }
+
+    if page_table_updated {
+        put_page_record(page_table_uref, item_key, page_table, config.page_encoding);
+    }
+    page_records
+}
+
+// Batch counterpart of update_page_entry_and_page_record, the target page table and every
+// page of both owners are written at most once.
+pub fn update_page_entries_and_page_records(
+    tokens_counts: &[u64],
+    old_item_key: &str,
+    new_item_key: &str,
+    config: &Config,
+) -> Vec<(u64, URef)> {
+    let page_size = config.page_size;
+    let page_table_uref = utils::get_uref(
+        PAGE_TABLE,
+        NFTCoreError::MissingPageTableURef,
+        NFTCoreError::InvalidPageTableURef,
+    );
+
+    let mut target_page_table = get_page_record(page_table_uref, new_item_key)
+        .unwrap_or_revert_with(NFTCoreError::UnregisteredOwnerInTransfer);
+    let mut target_page_table_updated = false;
+
+    let mut page_records = vec![];
+    for (page_table_entry, page_addresses) in group_by_page(tokens_counts, page_size) {
+        let page_uref = utils::get_uref(
+            &format!("{PREFIX_PAGE_DICTIONARY}_{page_table_entry}"),
+            NFTCoreError::MissingStorageUref,
+            NFTCoreError::InvalidStorageUref,
+        );
+
+        let mut source_page = get_page_record(page_uref, old_item_key)
+            .unwrap_or_revert_with(NFTCoreError::InvalidPageNumber);
+
+        widen_page_table(&mut target_page_table, page_table_entry);
+        let mut target_page = if !target_page_table[page_table_entry as usize] {
+            // Create a new page here
+            let _ = core::mem::replace(&mut target_page_table[page_table_entry as usize], true);
+            target_page_table_updated = true;
+            vec![false; page_size as usize]
+        } else {
+            get_page_record(page_uref, new_item_key).unwrap_or_revert()
+        };
+
+        for page_address in page_addresses {
+            if !source_page[page_address as usize] {
+                runtime::revert(NFTCoreError::InvalidTokenIdentifier)
+            }
+            let _ = core::mem::replace(&mut source_page[page_address as usize], false);
+            let _ = core::mem::replace(&mut target_page[page_address as usize], true);
+        }
+
+        put_page_record(page_uref, old_item_key, source_page, config.page_encoding);
+        put_page_record(page_uref, new_item_key, target_page, config.page_encoding);
+        page_records.push((page_table_entry, page_uref));
+    }
+
+    if target_page_table_updated {
+        put_page_record(
+            page_table_uref,
+            new_item_key,
+            target_page_table,
+            config.page_encoding,
+        );
+    }
+    page_records
+}
+
 pub fn create_metadata_requirements(
     base: NFTMetadataKind,
     req: Vec<u8>,
@@ -816,8 +1386,12 @@ pub fn init_events() {
         .with::<ApprovalForAll>()
         .with::<Transfer>()
         .with::<MetadataUpdated>()
+        .with::<MetadataFrozen>()
+        .with::<CollectionMetadataUpdated>()
         .with::<VariablesSet>()
-        .with::<Migration>();
+        .with::<Migration>()
+        .with::<AdminTransferStarted>()
+        .with::<AdminTransferred>();
     casper_event_standard::init(schemas);
 }
 
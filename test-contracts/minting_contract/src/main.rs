@@ -45,6 +45,7 @@ const ARG_TOKEN_ID: &str = "token_id";
 const ARG_TOKEN_HASH: &str = "token_hash";
 const ARG_REVERSE_LOOKUP: &str = "reverse_lookup";
 const ARG_IS_HASH_IDENTIFIER_MODE: &str = "is_hash_identifier_mode";
+const ARG_NFT_METADATA_KIND: &str = "nft_metadata_kind";
 
 #[no_mangle]
 pub extern "C" fn mint() {
@@ -183,25 +184,25 @@ pub extern "C" fn metadata() {
         .map(ContractHash::new)
         .unwrap();
 
-    let metadata: String = if runtime::get_named_arg::<bool>(ARG_IS_HASH_IDENTIFIER_MODE) {
+    let mut metadata_args = if runtime::get_named_arg::<bool>(ARG_IS_HASH_IDENTIFIER_MODE) {
         let token_hash = runtime::get_named_arg::<String>(ARG_TOKEN_HASH);
-        runtime::call_contract::<String>(
-            nft_contract_hash,
-            ENTRY_POINT_METADATA,
-            runtime_args! {
-                ARG_TOKEN_HASH => token_hash
-            },
-        )
+        runtime_args! {
+            ARG_TOKEN_HASH => token_hash
+        }
     } else {
         let token_id = runtime::get_named_arg::<u64>(ARG_TOKEN_ID);
-        runtime::call_contract::<String>(
-            nft_contract_hash,
-            ENTRY_POINT_METADATA,
-            runtime_args! {
-                ARG_TOKEN_ID => token_id
-            },
-        )
+        runtime_args! {
+            ARG_TOKEN_ID => token_id
+        }
     };
+    if get_named_arg_size(ARG_NFT_METADATA_KIND).is_some() {
+        let metadata_kind = runtime::get_named_arg::<u8>(ARG_NFT_METADATA_KIND);
+        metadata_args
+            .insert(ARG_NFT_METADATA_KIND, metadata_kind)
+            .unwrap_or_revert();
+    }
+    let metadata =
+        runtime::call_contract::<String>(nft_contract_hash, ENTRY_POINT_METADATA, metadata_args);
     runtime::put_key("metadata", storage::new_uref(metadata).into());
 }
 
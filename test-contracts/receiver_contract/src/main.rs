@@ -0,0 +1,114 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec,
+};
+
+use casper_contract::contract_api::{
+    runtime::{self, ret},
+    storage,
+};
+use casper_types::{
+    bytesrepr::Bytes, contracts::NamedKeys, CLType, CLValue, ContractHash, ContractVersion,
+    EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter,
+};
+
+const CONTRACT_NAME: &str = "receiver_contract_hash";
+const CONTRACT_VERSION: &str = "receiver_contract_version";
+const HASH_KEY_NAME: &str = "receiver_contract_package_hash";
+const ACCESS_KEY_NAME: &str = "receiver_contract_access_uref";
+const ARG_ACKNOWLEDGMENT: &str = "acknowledgment";
+const ARG_OPERATOR: &str = "operator";
+const ARG_FROM: &str = "from";
+const ARG_DATA: &str = "data";
+const LAST_OPERATOR: &str = "last_operator";
+const LAST_FROM: &str = "last_from";
+const LAST_DATA: &str = "last_data";
+const RECEIVER_CONTRACT_ACKNOWLEDGMENT: &str = "on_cep78_received";
+
+fn install_receiver_contract() -> (ContractHash, ContractVersion) {
+    let on_cep78_received_entry_point = EntryPoint::new(
+        "on_cep78_received",
+        vec![
+            Parameter::new(ARG_OPERATOR, CLType::Key),
+            Parameter::new(ARG_FROM, CLType::Option(Box::new(CLType::Key))),
+            Parameter::new(ARG_DATA, CLType::List(Box::new(CLType::U8))),
+        ],
+        CLType::String,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let set_acknowledgment = EntryPoint::new(
+        "set_acknowledgment",
+        vec![Parameter::new(ARG_ACKNOWLEDGMENT, CLType::String)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(on_cep78_received_entry_point);
+    entry_points.add_entry_point(set_acknowledgment);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        ARG_ACKNOWLEDGMENT.to_string(),
+        storage::new_uref(RECEIVER_CONTRACT_ACKNOWLEDGMENT.to_string()).into(),
+    );
+
+    storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(HASH_KEY_NAME.to_string()),
+        Some(ACCESS_KEY_NAME.to_string()),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn set_acknowledgment() {
+    let acknowledgment: String = runtime::get_named_arg(ARG_ACKNOWLEDGMENT);
+    let uref = runtime::get_key(ARG_ACKNOWLEDGMENT)
+        .unwrap()
+        .into_uref()
+        .unwrap();
+
+    storage::write(uref, acknowledgment);
+}
+
+// Returns the stored acknowledgment and records the operator, source and data of the receipt.
+#[no_mangle]
+pub extern "C" fn on_cep78_received() {
+    let operator: Key = runtime::get_named_arg(ARG_OPERATOR);
+    let from: Option<Key> = runtime::get_named_arg(ARG_FROM);
+    let data: Bytes = runtime::get_named_arg(ARG_DATA);
+
+    runtime::put_key(LAST_OPERATOR, storage::new_uref(operator).into());
+    runtime::put_key(LAST_FROM, storage::new_uref(from).into());
+    runtime::put_key(LAST_DATA, storage::new_uref(data).into());
+
+    let uref = runtime::get_key(ARG_ACKNOWLEDGMENT)
+        .unwrap()
+        .into_uref()
+        .unwrap();
+
+    let acknowledgment = storage::read::<String>(uref).unwrap().unwrap();
+
+    ret(CLValue::from_t(acknowledgment).unwrap());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let (contract_hash, contract_version) = install_receiver_contract();
+
+    runtime::put_key(CONTRACT_NAME, contract_hash.into());
+    runtime::put_key(CONTRACT_VERSION, storage::new_uref(contract_version).into());
+}
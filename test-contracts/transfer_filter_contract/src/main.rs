@@ -6,15 +6,15 @@ compile_error!("target arch should be wasm32: compile with '--target wasm32-unkn
 
 extern crate alloc;
 
-use alloc::{string::ToString, vec};
+use alloc::{boxed::Box, string::ToString, vec};
 
 use casper_contract::contract_api::{
     runtime::{self, ret},
     storage,
 };
 use casper_types::{
-    contracts::NamedKeys, CLType, CLValue, ContractHash, ContractVersion, EntryPoint,
-    EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter,
+    bytesrepr::Bytes, contracts::NamedKeys, CLType, CLValue, ContractHash, ContractVersion,
+    EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter,
 };
 
 const CONTRACT_NAME: &str = "transfer_filter_contract_hash";
@@ -22,6 +22,17 @@ const CONTRACT_VERSION: &str = "transfer_filter_contract_version";
 const HASH_KEY_NAME: &str = "transfer_filter_contract_package_hash";
 const ACCESS_KEY_NAME: &str = "transfer_filter_contract_access_uref";
 const ARG_FILTER_CONTRACT_RETURN_VALUE: &str = "return_value";
+const ARG_TARGET_KEY: &str = "target_key";
+const ARG_CALLER: &str = "caller";
+const ARG_DATA: &str = "data";
+const LAST_TARGET_KEY: &str = "last_target_key";
+const LAST_CALLER: &str = "last_caller";
+const LAST_DATA: &str = "last_data";
+const ARG_TOKEN_OWNER: &str = "token_owner";
+const ARG_ISSUER: &str = "issuer";
+const ARG_TOKEN_META_DATA: &str = "token_meta_data";
+const LAST_TOKEN_OWNER: &str = "last_token_owner";
+const LAST_ISSUER: &str = "last_issuer";
 
 fn install_filter_contract() -> (ContractHash, ContractVersion) {
     let can_transfer_entry_point = EntryPoint::new(
@@ -35,6 +46,44 @@ fn install_filter_contract() -> (ContractHash, ContractVersion) {
         EntryPointType::Contract,
     );
 
+    let can_transfer_v2_entry_point = EntryPoint::new(
+        "can_transfer_v2",
+        vec![
+            Parameter::new("source_key", CLType::Key),
+            Parameter::new(ARG_TARGET_KEY, CLType::Key),
+            Parameter::new(ARG_CALLER, CLType::Key),
+            Parameter::new(ARG_DATA, CLType::List(Box::new(CLType::U8))),
+        ],
+        CLType::U8,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let can_mint_entry_point = EntryPoint::new(
+        "can_mint",
+        vec![
+            Parameter::new(ARG_TOKEN_OWNER, CLType::Key),
+            Parameter::new(ARG_ISSUER, CLType::Key),
+            Parameter::new(ARG_TOKEN_META_DATA, CLType::String),
+        ],
+        CLType::U8,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let can_burn_entry_point = EntryPoint::new(
+        "can_burn",
+        vec![
+            Parameter::new(ARG_TOKEN_OWNER, CLType::Key),
+            Parameter::new(ARG_ISSUER, CLType::Key),
+            Parameter::new(ARG_CALLER, CLType::Key),
+            Parameter::new(ARG_TOKEN_META_DATA, CLType::String),
+        ],
+        CLType::U8,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     let set_return_value = EntryPoint::new(
         "set_return_value",
         vec![Parameter::new(ARG_FILTER_CONTRACT_RETURN_VALUE, CLType::U8)],
@@ -45,6 +94,9 @@ fn install_filter_contract() -> (ContractHash, ContractVersion) {
 
     let mut entry_points = EntryPoints::new();
     entry_points.add_entry_point(can_transfer_entry_point);
+    entry_points.add_entry_point(can_transfer_v2_entry_point);
+    entry_points.add_entry_point(can_mint_entry_point);
+    entry_points.add_entry_point(can_burn_entry_point);
     entry_points.add_entry_point(set_return_value);
 
     let mut named_keys = NamedKeys::new();
@@ -86,6 +138,68 @@ pub extern "C" fn can_transfer() {
     ret(CLValue::from_t(return_value).unwrap());
 }
 
+// Returns the stored value as a deny reason and records the context it was called with.
+#[no_mangle]
+pub extern "C" fn can_transfer_v2() {
+    let target_key: Key = runtime::get_named_arg(ARG_TARGET_KEY);
+    let caller: Key = runtime::get_named_arg(ARG_CALLER);
+    let data: Bytes = runtime::get_named_arg(ARG_DATA);
+
+    runtime::put_key(LAST_TARGET_KEY, storage::new_uref(target_key).into());
+    runtime::put_key(LAST_CALLER, storage::new_uref(caller).into());
+    runtime::put_key(LAST_DATA, storage::new_uref(data).into());
+
+    let uref = runtime::get_key(ARG_FILTER_CONTRACT_RETURN_VALUE)
+        .unwrap()
+        .into_uref()
+        .unwrap();
+
+    let return_value = storage::read::<u8>(uref).unwrap().unwrap();
+
+    ret(CLValue::from_t(return_value).unwrap());
+}
+
+// Returns the stored value as a deny reason and records the owner and issuer of the minted token.
+#[no_mangle]
+pub extern "C" fn can_mint() {
+    let token_owner: Key = runtime::get_named_arg(ARG_TOKEN_OWNER);
+    let issuer: Key = runtime::get_named_arg(ARG_ISSUER);
+
+    runtime::put_key(LAST_TOKEN_OWNER, storage::new_uref(token_owner).into());
+    runtime::put_key(LAST_ISSUER, storage::new_uref(issuer).into());
+
+    let uref = runtime::get_key(ARG_FILTER_CONTRACT_RETURN_VALUE)
+        .unwrap()
+        .into_uref()
+        .unwrap();
+
+    let return_value = storage::read::<u8>(uref).unwrap().unwrap();
+
+    ret(CLValue::from_t(return_value).unwrap());
+}
+
+// Returns the stored value as a deny reason and records the owner and issuer of the burnt token
+// and the caller.
+#[no_mangle]
+pub extern "C" fn can_burn() {
+    let token_owner: Key = runtime::get_named_arg(ARG_TOKEN_OWNER);
+    let issuer: Key = runtime::get_named_arg(ARG_ISSUER);
+    let caller: Key = runtime::get_named_arg(ARG_CALLER);
+
+    runtime::put_key(LAST_TOKEN_OWNER, storage::new_uref(token_owner).into());
+    runtime::put_key(LAST_ISSUER, storage::new_uref(issuer).into());
+    runtime::put_key(LAST_CALLER, storage::new_uref(caller).into());
+
+    let uref = runtime::get_key(ARG_FILTER_CONTRACT_RETURN_VALUE)
+        .unwrap()
+        .into_uref()
+        .unwrap();
+
+    let return_value = storage::read::<u8>(uref).unwrap().unwrap();
+
+    ret(CLValue::from_t(return_value).unwrap());
+}
+
 #[no_mangle]
 pub extern "C" fn call() {
     let (contract_hash, contract_version) = install_filter_contract();
@@ -1,16 +1,19 @@
 use crate::utility::{
     constants::{
-        ACCOUNT_USER_1, ARG_NFT_CONTRACT_HASH, ARG_REVERSE_LOOKUP, CONTRACT_NAME,
-        MINTING_CONTRACT_WASM, MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION,
-        TEST_PRETTY_721_META_DATA,
+        ACCOUNT_USER_1, ARG_FILTER_CONTRACT_RETURN_VALUE, ARG_NFT_CONTRACT_HASH,
+        ARG_REVERSE_LOOKUP, CONTRACT_1_5_0_WASM, CONTRACT_NAME, MINTING_CONTRACT_WASM,
+        MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, NFT_TEST_SYMBOL,
+        TEST_PRETTY_721_META_DATA, TRANSFER_FILTER_CONTRACT_WASM,
     },
     installer_request_builder::{
         BurnMode, InstallerRequestBuilder, MetadataMutability, MintingMode, NFTHolderMode,
-        NFTIdentifierMode, OwnerReverseLookupMode, OwnershipMode, WhitelistMode,
+        NFTIdentifierMode, NFTMetadataKind, NamedKeyConventionMode, OwnerReverseLookupMode,
+        OwnershipMode, WhitelistMode,
     },
     support::{
         self, get_dictionary_value_from_key, get_minting_contract_hash,
         get_minting_contract_package_hash, get_nft_contract_hash,
+        get_transfer_filter_contract_hash,
     },
 };
 use casper_engine_test_support::{
@@ -20,9 +23,13 @@ use casper_engine_test_support::{
 use casper_types::{runtime_args, Key, RuntimeArgs};
 use contract::{
     constants::{
-        ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_OPERATOR, ARG_TOKEN_HASH, ARG_TOKEN_ID,
-        ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, BURNT_TOKENS, BURN_MODE, ENTRY_POINT_BURN,
-        ENTRY_POINT_MINT, ENTRY_POINT_SET_APPROVALL_FOR_ALL, TOKEN_COUNT,
+        ARG_ACCESS_KEY_NAME_1_0_0, ARG_APPROVE_ALL, ARG_BURN_FILTER_CONTRACT, ARG_COLLECTION_NAME,
+        ARG_HASH_KEY_NAME_1_0_0, ARG_NAMED_KEY_CONVENTION, ARG_OPERATOR, ARG_TOKEN_HASH,
+        ARG_TOKEN_ID, ARG_TOKEN_IDS, ARG_TOKEN_META_DATA, ARG_TOKEN_META_DATAS, ARG_TOKEN_OWNER,
+        ARG_TOKEN_OWNERS, BURNT_TOKENS, BURN_MODE, ENTRY_POINT_BURN, ENTRY_POINT_BURN_BATCH,
+        ENTRY_POINT_MINT, ENTRY_POINT_MINT_BATCH, ENTRY_POINT_SET_APPROVALL_FOR_ALL,
+        ENTRY_POINT_SET_VARIABLES, FILTER_CONTRACT_DENY_REASON_OFFSET, PREFIX_ACCESS_KEY_NAME,
+        PREFIX_HASH_KEY_NAME, TOKEN_COUNT,
     },
     events::events_ces::Burn,
     modalities::TokenIdentifier,
@@ -891,3 +898,409 @@ fn should_burn_token_in_hash_identifier_mode() {
 
     builder.exec(burn_request).expect_success().commit();
 }
+
+#[test]
+fn should_burn_batch() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request_builder =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(100u64)
+            .with_ownership_mode(OwnershipMode::Transferable)
+            .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+            .build();
+
+    builder
+        .exec(install_request_builder)
+        .expect_success()
+        .commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let mint_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT_BATCH,
+        runtime_args! {
+            ARG_TOKEN_META_DATAS => vec![TEST_PRETTY_721_META_DATA.to_string(); 3],
+            ARG_TOKEN_OWNERS => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR); 3],
+        },
+    )
+    .build();
+    builder.exec(mint_batch_request).expect_success().commit();
+
+    let burn_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN_BATCH,
+        runtime_args! {
+            ARG_TOKEN_IDS => vec![0u64, 2u64],
+        },
+    )
+    .build();
+    builder.exec(burn_batch_request).expect_success().commit();
+
+    for token_id in [0u64, 2u64] {
+        get_dictionary_value_from_key::<()>(
+            &builder,
+            &nft_contract_key,
+            BURNT_TOKENS,
+            &token_id.to_string(),
+        );
+    }
+
+    let actual_balance = get_dictionary_value_from_key::<u64>(
+        &builder,
+        &nft_contract_key,
+        TOKEN_COUNT,
+        &DEFAULT_ACCOUNT_ADDR.clone().to_string(),
+    );
+    assert_eq!(actual_balance, 1u64);
+}
+
+#[test]
+fn should_not_burn_batch_with_duplicate_token() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request_builder =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(100u64)
+            .with_ownership_mode(OwnershipMode::Transferable)
+            .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+            .build();
+
+    builder
+        .exec(install_request_builder)
+        .expect_success()
+        .commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    let mint_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT_BATCH,
+        runtime_args! {
+            ARG_TOKEN_META_DATAS => vec![TEST_PRETTY_721_META_DATA.to_string(); 2],
+            ARG_TOKEN_OWNERS => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR); 2],
+        },
+    )
+    .build();
+    builder.exec(mint_batch_request).expect_success().commit();
+
+    let burn_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN_BATCH,
+        runtime_args! {
+            ARG_TOKEN_IDS => vec![1u64, 1u64],
+        },
+    )
+    .build();
+    builder.exec(burn_batch_request).expect_failure();
+
+    let actual_error = builder.get_error().expect("must have error");
+    support::assert_expected_error(
+        actual_error,
+        42u16,
+        "should disallow burning the same token twice in a batch",
+    );
+}
+
+#[test]
+fn should_consult_burn_filter_contract_until_removed_by_installer() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let filter_contract_install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        TRANSFER_FILTER_CONTRACT_WASM,
+        runtime_args! {},
+    )
+    .build();
+
+    builder
+        .exec(filter_contract_install_request)
+        .expect_success()
+        .commit();
+
+    let filter_contract_hash = get_transfer_filter_contract_hash(&builder);
+
+    let deny_reason = 4u8;
+    let set_return_value_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        filter_contract_hash,
+        "set_return_value",
+        runtime_args! {
+            ARG_FILTER_CONTRACT_RETURN_VALUE => deny_reason
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_return_value_request)
+        .expect_success()
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_burn_filter_contract(Key::from(filter_contract_hash))
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    for _ in 0..2 {
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_MINT,
+            runtime_args! {
+                ARG_TOKEN_OWNER => token_owner_key,
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            },
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+    }
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    support::assert_expected_error(
+        error,
+        FILTER_CONTRACT_DENY_REASON_OFFSET + u16::from(deny_reason),
+        "the deny reason of the burn filter must be surfaced in the revert",
+    );
+
+    let set_return_value_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        filter_contract_hash,
+        "set_return_value",
+        runtime_args! {
+            ARG_FILTER_CONTRACT_RETURN_VALUE => 0u8
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_return_value_request)
+        .expect_success()
+        .commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_success().commit();
+
+    let filter_contract_key = Key::from(filter_contract_hash);
+    let last_token_owner: Key = support::query_stored_value(
+        &builder,
+        filter_contract_key,
+        vec!["last_token_owner".to_string()],
+    );
+    let last_caller: Key = support::query_stored_value(
+        &builder,
+        filter_contract_key,
+        vec!["last_caller".to_string()],
+    );
+    let last_issuer: Key = support::query_stored_value(
+        &builder,
+        filter_contract_key,
+        vec!["last_issuer".to_string()],
+    );
+    assert_eq!(last_token_owner, token_owner_key);
+    assert_eq!(last_caller, token_owner_key);
+    assert_eq!(last_issuer, token_owner_key);
+
+    let set_return_value_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        filter_contract_hash,
+        "set_return_value",
+        runtime_args! {
+            ARG_FILTER_CONTRACT_RETURN_VALUE => deny_reason
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_return_value_request)
+        .expect_success()
+        .commit();
+
+    // Only the installer may remove the burn filter contract, which is then no longer consulted.
+    let account_user_1 = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let remove_burn_filter_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! {
+            ARG_BURN_FILTER_CONTRACT => None::<Key>,
+        },
+    )
+    .build();
+
+    builder.exec(remove_burn_filter_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    support::assert_expected_error(error, 1u16, "InvalidAccount(1) must be raised");
+
+    let remove_burn_filter_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! {
+            ARG_BURN_FILTER_CONTRACT => None::<Key>,
+        },
+    )
+    .build();
+
+    builder
+        .exec(remove_burn_filter_request)
+        .expect_success()
+        .commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_success().commit();
+}
+
+#[test]
+fn should_not_submit_token_without_issuer_to_burn_filter_contract() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let filter_contract_install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        TRANSFER_FILTER_CONTRACT_WASM,
+        runtime_args! {},
+    )
+    .build();
+
+    builder
+        .exec(filter_contract_install_request)
+        .expect_success()
+        .commit();
+
+    let filter_contract_hash = get_transfer_filter_contract_hash(&builder);
+
+    // Tokens minted by the 1.5.0 version have no recorded issuer.
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, CONTRACT_1_5_0_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key_1_5_0: Key = get_nft_contract_hash(&builder).into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key_1_5_0,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => "",
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        NFT_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key_1_5_0,
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+            ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
+            ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+            ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+        },
+    )
+    .build();
+
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    let set_burn_filter_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! {
+            ARG_BURN_FILTER_CONTRACT => Some(Key::from(filter_contract_hash)),
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_burn_filter_request)
+        .expect_success()
+        .commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    support::assert_expected_error(
+        error,
+        231u16,
+        "tokens without a recorded issuer must not be submitted to the burn filter contract",
+    );
+}
@@ -0,0 +1,311 @@
+use std::collections::BTreeMap;
+
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs};
+use contract::constants::{
+    ARG_ACCESS_KEY_NAME_1_0_0, ARG_BURNT_TOKENS_COUNT, ARG_COLLECTION_NAME,
+    ARG_HASH_KEY_NAME_1_0_0, ARG_HOLDER_COUNT, ARG_NAMED_KEY_CONVENTION, ARG_SOURCE_KEY,
+    ARG_TARGET_KEY, ARG_TOKEN_ID, ARG_TOKEN_IDS, ARG_TOKEN_META_DATA, ARG_TOKEN_META_DATAS,
+    ARG_TOKEN_OWNER, ARG_TOKEN_OWNERS, BURNT_TOKENS_COUNT, CIRCULATING_SUPPLY, ENTRY_POINT_BURN,
+    ENTRY_POINT_BURN_BATCH, ENTRY_POINT_COLLECTION_STATS, ENTRY_POINT_MINT_BATCH,
+    ENTRY_POINT_TRANSFER, HOLDER_COUNT, NUMBER_OF_MINTED_TOKENS, PREFIX_ACCESS_KEY_NAME,
+    PREFIX_HASH_KEY_NAME,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_USER_1, ARG_NFT_CONTRACT_HASH, COLLECTION_STATS_SESSION_WASM, CONTRACT_1_5_0_WASM,
+        MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, NFT_TEST_SYMBOL,
+        TEST_PRETTY_721_META_DATA,
+    },
+    installer_request_builder::{
+        InstallerRequestBuilder, MetadataMutability, NFTIdentifierMode, NFTMetadataKind,
+        NamedKeyConventionMode, OwnerReverseLookupMode, OwnershipMode,
+    },
+    support::{
+        assert_expected_error, call_session_code_with_ret, create_funded_dummy_account,
+        get_nft_contract_hash, query_stored_value,
+    },
+};
+
+fn assert_collection_stats(
+    builder: &InMemoryWasmTestBuilder,
+    nft_contract_key: Key,
+    burnt_tokens_count: u64,
+    circulating_supply: u64,
+    holder_count: u64,
+) {
+    for (name, expected_value) in [
+        (BURNT_TOKENS_COUNT, burnt_tokens_count),
+        (CIRCULATING_SUPPLY, circulating_supply),
+        (HOLDER_COUNT, holder_count),
+    ] {
+        let actual_value: u64 =
+            query_stored_value(builder, nft_contract_key, vec![name.to_string()]);
+        assert_eq!(actual_value, expected_value, "unexpected {name}");
+    }
+}
+
+#[test]
+fn should_track_collection_stats() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let token_receiver_key = Key::Account(create_funded_dummy_account(
+        &mut builder,
+        Some(ACCOUNT_USER_1),
+    ));
+
+    assert_collection_stats(&builder, nft_contract_key, 0u64, 0u64, 0u64);
+
+    let mint_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT_BATCH,
+        runtime_args! {
+            ARG_TOKEN_META_DATAS => vec![TEST_PRETTY_721_META_DATA.to_string(); 3],
+            ARG_TOKEN_OWNERS => vec![token_owner_key; 3],
+        },
+    )
+    .build();
+    builder.exec(mint_batch_request).expect_success().commit();
+
+    assert_collection_stats(&builder, nft_contract_key, 0u64, 3u64, 1u64);
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => token_receiver_key,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_collection_stats(&builder, nft_contract_key, 0u64, 3u64, 2u64);
+
+    // Burning the last tokens of the installer leaves the receiver as the only holder.
+    let burn_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN_BATCH,
+        runtime_args! {
+            ARG_TOKEN_IDS => vec![1u64, 2u64],
+        },
+    )
+    .build();
+    builder.exec(burn_batch_request).expect_success().commit();
+
+    assert_collection_stats(&builder, nft_contract_key, 2u64, 1u64, 1u64);
+
+    let collection_stats = call_session_code_with_ret::<BTreeMap<String, u64>>(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_key,
+        runtime_args! {},
+        COLLECTION_STATS_SESSION_WASM,
+        "collection_stats",
+    );
+
+    assert_eq!(
+        collection_stats,
+        BTreeMap::from([
+            (NUMBER_OF_MINTED_TOKENS.to_string(), 3u64),
+            (BURNT_TOKENS_COUNT.to_string(), 2u64),
+            (CIRCULATING_SUPPLY.to_string(), 1u64),
+            (HOLDER_COUNT.to_string(), 1u64),
+        ])
+    );
+}
+
+// Installs a collection of the 1.5.0 version with three minted tokens, the first one being burnt.
+fn install_1_5_0_with_burnt_token(builder: &mut InMemoryWasmTestBuilder) -> Key {
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, CONTRACT_1_5_0_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash_1_5_0 = get_nft_contract_hash(builder);
+    let nft_contract_key_1_5_0: Key = nft_contract_hash_1_5_0.into();
+
+    for _ in 0..3 {
+        let mint_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            MINT_SESSION_WASM,
+            runtime_args! {
+                ARG_NFT_CONTRACT_HASH => nft_contract_key_1_5_0,
+                ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_TOKEN_META_DATA => "",
+                ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+            },
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+    }
+
+    burn_token(builder, nft_contract_hash_1_5_0, 0u64);
+
+    nft_contract_key_1_5_0
+}
+
+fn burn_token(builder: &mut InMemoryWasmTestBuilder, nft_contract_hash: ContractHash, id: u64) {
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => id,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_success().commit();
+}
+
+// Builds the upgrade of a 1.5.0 collection, seeding its burnt tokens and holder counts if given.
+fn upgrade_request_1_5_0(
+    nft_contract_key_1_5_0: Key,
+    collection_stats_seed: Option<(u64, u64)>,
+) -> ExecuteRequest {
+    let mut upgrade_args = runtime_args! {
+        ARG_NFT_CONTRACT_HASH => nft_contract_key_1_5_0,
+        ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+        ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
+        ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+        ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+    };
+    if let Some((burnt_tokens_count, holder_count)) = collection_stats_seed {
+        upgrade_args
+            .insert(ARG_BURNT_TOKENS_COUNT, burnt_tokens_count)
+            .expect("must insert burnt tokens count");
+        upgrade_args
+            .insert(ARG_HOLDER_COUNT, holder_count)
+            .expect("must insert holder count");
+    }
+
+    ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM, upgrade_args).build()
+}
+
+#[test]
+fn should_leave_collection_stats_untracked_on_upgrade_without_seed() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let nft_contract_key_1_5_0 = install_1_5_0_with_burnt_token(&mut builder);
+
+    let upgrade_request = upgrade_request_1_5_0(nft_contract_key_1_5_0, None);
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    let collection_stats_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_COLLECTION_STATS,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(collection_stats_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        229u16,
+        "collection stats must not be returned before they are seeded",
+    );
+
+    // Untracked counters are left as they are rather than going out of range.
+    burn_token(&mut builder, nft_contract_hash, 1u64);
+}
+
+#[test]
+fn should_seed_collection_stats_on_upgrade() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let nft_contract_key_1_5_0 = install_1_5_0_with_burnt_token(&mut builder);
+
+    let upgrade_request = upgrade_request_1_5_0(nft_contract_key_1_5_0, Some((1u64, 1u64)));
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    assert_collection_stats(&builder, nft_contract_key, 1u64, 2u64, 1u64);
+
+    burn_token(&mut builder, nft_contract_hash, 1u64);
+
+    assert_collection_stats(&builder, nft_contract_key, 2u64, 1u64, 1u64);
+
+    let collection_stats = call_session_code_with_ret::<BTreeMap<String, u64>>(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_key,
+        runtime_args! {},
+        COLLECTION_STATS_SESSION_WASM,
+        "collection_stats",
+    );
+
+    assert_eq!(
+        collection_stats,
+        BTreeMap::from([
+            (NUMBER_OF_MINTED_TOKENS.to_string(), 3u64),
+            (BURNT_TOKENS_COUNT.to_string(), 2u64),
+            (CIRCULATING_SUPPLY.to_string(), 1u64),
+            (HOLDER_COUNT.to_string(), 1u64),
+        ])
+    );
+}
+
+#[test]
+fn should_not_seed_more_burnt_tokens_than_minted_on_upgrade() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let nft_contract_key_1_5_0 = install_1_5_0_with_burnt_token(&mut builder);
+
+    let upgrade_request = upgrade_request_1_5_0(nft_contract_key_1_5_0, Some((4u64, 1u64)));
+
+    builder.exec(upgrade_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        214u16,
+        "burnt tokens count cannot exceed the minted tokens",
+    );
+}
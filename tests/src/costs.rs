@@ -227,9 +227,10 @@ fn should_cost_less_when_installing_without_reverse_lookup(reporting: OwnerRever
 
     let reverse_lookup_hash: Key = support::get_nft_contract_hash(&builder).into();
 
+    // Page dictionaries are only created as tokens are minted.
     let page_dictionary_lookup = builder.query(None, reverse_lookup_hash, &["page_0".to_string()]);
 
-    assert!(page_dictionary_lookup.is_ok());
+    assert!(page_dictionary_lookup.is_err());
 
     let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
         .with_collection_name(NFT_TEST_COLLECTION.to_string())
@@ -0,0 +1,307 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_types::{bytesrepr::FromBytes, runtime_args, CLTyped, ContractHash, Key, RuntimeArgs};
+use contract::constants::{
+    ARG_INDEX, ARG_LIMIT, ARG_OFFSET, ARG_SOURCE_KEY, ARG_TARGET_KEY, ARG_TOKEN_ID,
+    ARG_TOKEN_OWNER, ENTRY_POINT_BURN, ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_TOKENS_OF_OWNER,
+    ENTRY_POINT_TOKEN_OF_OWNER_BY_INDEX, ENTRY_POINT_TRANSFER,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_USER_1, ARG_IS_HASH_IDENTIFIER_MODE, NFT_CONTRACT_WASM,
+        TOKENS_OF_OWNER_SESSION_WASM, TOKEN_OF_OWNER_BY_INDEX_SESSION_WASM,
+    },
+    installer_request_builder::{
+        InstallerRequestBuilder, MetadataMutability, NFTIdentifierMode, OwnerReverseLookupMode,
+        OwnershipMode,
+    },
+    support::{
+        assert_expected_error, call_session_code_with_ret, create_funded_dummy_account,
+        get_nft_contract_hash, mint_token,
+    },
+};
+
+fn register_owner(
+    builder: &mut InMemoryWasmTestBuilder,
+    nft_contract_hash: ContractHash,
+    token_owner: Key,
+) {
+    let register_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REGISTER_OWNER,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner,
+        },
+    )
+    .build();
+
+    builder.exec(register_request).expect_success().commit();
+}
+
+fn get_tokens_of_owner<T: CLTyped + FromBytes>(
+    builder: &mut InMemoryWasmTestBuilder,
+    nft_contract_hash: ContractHash,
+    token_owner: Key,
+    offset: u64,
+    limit: u64,
+    is_hash_identifier_mode: bool,
+) -> Vec<T> {
+    call_session_code_with_ret::<Vec<T>>(
+        builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash.into(),
+        runtime_args! {
+            ARG_IS_HASH_IDENTIFIER_MODE => is_hash_identifier_mode,
+            ARG_TOKEN_OWNER => token_owner,
+            ARG_OFFSET => offset,
+            ARG_LIMIT => limit,
+        },
+        TOKENS_OF_OWNER_SESSION_WASM,
+        "tokens_of_owner",
+    )
+}
+
+#[test]
+fn should_enumerate_tokens_of_owner() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let token_receiver_key = Key::Account(create_funded_dummy_account(
+        &mut builder,
+        Some(ACCOUNT_USER_1),
+    ));
+
+    register_owner(&mut builder, nft_contract_hash, token_owner_key);
+    register_owner(&mut builder, nft_contract_hash, token_receiver_key);
+
+    for _ in 0..4 {
+        mint_token(&mut builder, *DEFAULT_ACCOUNT_ADDR, nft_contract_hash, None)
+            .expect_success()
+            .commit();
+    }
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => token_receiver_key,
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => 2u64,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_success().commit();
+
+    // Transferred and burnt tokens are no longer listed for their previous owner.
+    let tokens_of_owner: Vec<u64> = get_tokens_of_owner(
+        &mut builder,
+        nft_contract_hash,
+        token_owner_key,
+        0u64,
+        10u64,
+        false,
+    );
+    assert_eq!(tokens_of_owner, vec![0u64, 3u64]);
+
+    let tokens_of_owner: Vec<u64> = get_tokens_of_owner(
+        &mut builder,
+        nft_contract_hash,
+        token_owner_key,
+        1u64,
+        10u64,
+        false,
+    );
+    assert_eq!(tokens_of_owner, vec![3u64]);
+
+    let tokens_of_owner: Vec<u64> = get_tokens_of_owner(
+        &mut builder,
+        nft_contract_hash,
+        token_owner_key,
+        0u64,
+        1u64,
+        false,
+    );
+    assert_eq!(tokens_of_owner, vec![0u64]);
+
+    let tokens_of_receiver: Vec<u64> = get_tokens_of_owner(
+        &mut builder,
+        nft_contract_hash,
+        token_receiver_key,
+        0u64,
+        10u64,
+        false,
+    );
+    assert_eq!(tokens_of_receiver, vec![1u64]);
+
+    let token_id = call_session_code_with_ret::<u64>(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash.into(),
+        runtime_args! {
+            ARG_IS_HASH_IDENTIFIER_MODE => false,
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_INDEX => 1u64,
+        },
+        TOKEN_OF_OWNER_BY_INDEX_SESSION_WASM,
+        "token_of_owner_by_index",
+    );
+    assert_eq!(token_id, 3u64);
+
+    let token_of_owner_by_index_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TOKEN_OF_OWNER_BY_INDEX,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_INDEX => 2u64,
+        },
+    )
+    .build();
+
+    builder
+        .exec(token_of_owner_by_index_request)
+        .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        212u16,
+        "should not return a token beyond the tokens of the owner",
+    );
+}
+
+#[test]
+fn should_enumerate_token_hashes_of_owner() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_identifier_mode(NFTIdentifierMode::Hash)
+        .with_metadata_mutability(MetadataMutability::Immutable)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    register_owner(&mut builder, nft_contract_hash, token_owner_key);
+
+    let token_hashes = ["first_token", "second_token"];
+    for token_hash in token_hashes {
+        mint_token(
+            &mut builder,
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            Some(token_hash),
+        )
+        .expect_success()
+        .commit();
+    }
+
+    let tokens_of_owner: Vec<String> = get_tokens_of_owner(
+        &mut builder,
+        nft_contract_hash,
+        token_owner_key,
+        0u64,
+        10u64,
+        true,
+    );
+    assert_eq!(tokens_of_owner, token_hashes.map(str::to_string).to_vec());
+
+    let token_hash = call_session_code_with_ret::<String>(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash.into(),
+        runtime_args! {
+            ARG_IS_HASH_IDENTIFIER_MODE => true,
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_INDEX => 1u64,
+        },
+        TOKEN_OF_OWNER_BY_INDEX_SESSION_WASM,
+        "token_of_owner_by_index",
+    );
+    assert_eq!(token_hash, token_hashes[1]);
+}
+
+fn should_not_enumerate_tokens_with_reporting_mode(reporting_mode: OwnerReverseLookupMode) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(reporting_mode)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    mint_token(&mut builder, *DEFAULT_ACCOUNT_ADDR, nft_contract_hash, None)
+        .expect_success()
+        .commit();
+
+    let tokens_of_owner_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TOKENS_OF_OWNER,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_OFFSET => 0u64,
+            ARG_LIMIT => 10u64,
+        },
+    )
+    .build();
+
+    builder.exec(tokens_of_owner_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        205u16,
+        "should not enumerate tokens without the complete owner reverse lookup",
+    );
+}
+
+#[test]
+fn should_not_enumerate_tokens_without_complete_owner_reverse_lookup() {
+    should_not_enumerate_tokens_with_reporting_mode(OwnerReverseLookupMode::NoLookUp);
+    should_not_enumerate_tokens_with_reporting_mode(OwnerReverseLookupMode::TransfersOnly);
+}
@@ -9,20 +9,22 @@ use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs};
 
 use contract::{
     constants::{
-        ACCESS_KEY_NAME_1_0_0, APPROVED, ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_EVENTS_MODE,
-        ARG_NAMED_KEY_CONVENTION, ARG_OPERATOR, ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY,
-        ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, BURNER, BURNT_TOKENS,
+        ACCESS_KEY_NAME_1_0_0, ADMIN, APPROVED_SPENDERS, ARG_ADMIN, ARG_APPROVE_ALL,
+        ARG_COLLECTION_NAME, ARG_EVENTS_MODE, ARG_NAMED_KEY_CONVENTION, ARG_OPERATOR,
+        ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID,
+        ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, BURNER, BURNT_TOKENS, ENTRY_POINT_ACCEPT_ADMIN,
         ENTRY_POINT_APPROVE, ENTRY_POINT_BURN, ENTRY_POINT_REGISTER_OWNER,
-        ENTRY_POINT_SET_APPROVALL_FOR_ALL, ENTRY_POINT_SET_TOKEN_METADATA, EVENTS, EVENT_TYPE,
-        METADATA_CEP78, METADATA_CUSTOM_VALIDATED, METADATA_NFT721, METADATA_RAW, OPERATOR, OWNER,
-        PREFIX_CEP78, PREFIX_HASH_KEY_NAME, RECIPIENT, TOKEN_COUNT, TOKEN_ID,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL, ENTRY_POINT_SET_TOKEN_METADATA,
+        ENTRY_POINT_TRANSFER_ADMIN, EVENTS, EVENT_TYPE, METADATA_CEP78, METADATA_CUSTOM_VALIDATED,
+        METADATA_NFT721, METADATA_RAW, NEW_ADMIN, OPERATOR, OWNER, PENDING_ADMIN, PREFIX_CEP78,
+        PREFIX_HASH_KEY_NAME, PREVIOUS_ADMIN, RECIPIENT, TOKEN_COUNT, TOKEN_ID,
     },
     modalities::{EventsMode, NamedKeyConventionMode},
 };
 
 use crate::utility::{
     constants::{
-        ARG_IS_HASH_IDENTIFIER_MODE, ARG_KEY_NAME, ARG_NFT_CONTRACT_HASH,
+        ACCOUNT_USER_1, ARG_IS_HASH_IDENTIFIER_MODE, ARG_KEY_NAME, ARG_NFT_CONTRACT_HASH,
         ARG_NFT_CONTRACT_PACKAGE_HASH, CONTRACT_1_0_0_WASM, CONTRACT_NAME,
         IS_APPROVED_FOR_ALL_WASM, MINT_1_0_0_WASM, MINT_SESSION_WASM, NFT_CONTRACT_WASM,
         NFT_TEST_COLLECTION, NFT_TEST_SYMBOL, TEST_PRETTY_721_META_DATA,
@@ -543,14 +545,14 @@ fn should_cep47_dictionary_style_approve_event_in_hash_identifier_mode() {
 
     builder.exec(approve_request).expect_success().commit();
 
-    let maybe_approved_spender = get_dictionary_value_from_key::<Option<Key>>(
+    let approved_spenders = get_dictionary_value_from_key::<Vec<Key>>(
         &builder,
         &nft_contract_key,
-        APPROVED,
+        APPROVED_SPENDERS,
         &token_hash,
     );
 
-    assert_eq!(maybe_approved_spender, Some(spender));
+    assert_eq!(approved_spenders, vec![spender]);
 
     let event = get_dictionary_value_from_key::<BTreeMap<String, String>>(
         &builder,
@@ -810,6 +812,100 @@ fn should_cep47_dictionary_style_revoked_for_all_event() {
     assert_eq!(event, expected_event);
 }
 
+#[test]
+fn should_cep47_dictionary_style_admin_transfer_events() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let new_admin_account = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let install_request_builder =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(2u64)
+            .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+            .with_events_mode(EventsMode::CEP47);
+    builder
+        .exec(install_request_builder.build())
+        .expect_success()
+        .commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let transfer_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER_ADMIN,
+        runtime_args! { ARG_ADMIN => Key::Account(new_admin_account) },
+    )
+    .build();
+    builder
+        .exec(transfer_admin_request)
+        .expect_success()
+        .commit();
+
+    let accept_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        new_admin_account,
+        nft_contract_hash,
+        ENTRY_POINT_ACCEPT_ADMIN,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(accept_admin_request).expect_success().commit();
+
+    let collection_name: String = query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![ARG_COLLECTION_NAME.to_string()],
+    );
+
+    let package = query_stored_value::<String>(
+        &builder,
+        nft_contract_key,
+        vec![format!("{PREFIX_CEP78}_{collection_name}")],
+    );
+
+    let event = get_dictionary_value_from_key::<BTreeMap<String, String>>(
+        &builder,
+        &nft_contract_key,
+        EVENTS,
+        "0",
+    );
+    let mut expected_event: BTreeMap<String, String> = BTreeMap::new();
+    expected_event.insert(EVENT_TYPE.to_string(), "AdminTransferStarted".to_string());
+    expected_event.insert(PREFIX_HASH_KEY_NAME.to_string(), package.clone());
+    expected_event.insert(
+        ADMIN.to_string(),
+        Key::Account(*DEFAULT_ACCOUNT_ADDR).to_string(),
+    );
+    expected_event.insert(
+        PENDING_ADMIN.to_string(),
+        Key::Account(new_admin_account).to_string(),
+    );
+    assert_eq!(event, expected_event);
+
+    let event = get_dictionary_value_from_key::<BTreeMap<String, String>>(
+        &builder,
+        &nft_contract_key,
+        EVENTS,
+        "1",
+    );
+    let mut expected_event: BTreeMap<String, String> = BTreeMap::new();
+    expected_event.insert(EVENT_TYPE.to_string(), "AdminTransferred".to_string());
+    expected_event.insert(PREFIX_HASH_KEY_NAME.to_string(), package);
+    expected_event.insert(
+        PREVIOUS_ADMIN.to_string(),
+        Key::Account(*DEFAULT_ACCOUNT_ADDR).to_string(),
+    );
+    expected_event.insert(
+        NEW_ADMIN.to_string(),
+        Key::Account(new_admin_account).to_string(),
+    );
+    assert_eq!(event, expected_event);
+}
+
 #[test]
 fn should_record_migration_event_in_cep47() {
     const OWNED_TOKENS: &str = "owned_tokens";
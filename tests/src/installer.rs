@@ -3,24 +3,29 @@ use casper_engine_test_support::{
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
 use casper_event_standard::Schemas;
-use casper_types::{runtime_args, CLValue, ContractHash, Key, RuntimeArgs};
+use casper_types::{
+    bytesrepr::{self, Bytes},
+    runtime_args, CLValue, ContractHash, Key, RuntimeArgs,
+};
 use contract::{
     constants::{
         ACL_WHITELIST, ARG_ALLOW_MINTING, ARG_COLLECTION_NAME, ARG_COLLECTION_SYMBOL,
-        ARG_HOLDER_MODE, ARG_MINTING_MODE, ARG_TOTAL_TOKEN_SUPPLY, ARG_WHITELIST_MODE,
-        ENTRY_POINT_INIT, NUMBER_OF_MINTED_TOKENS,
+        ARG_HOLDER_MODE, ARG_MINTING_MODE, ARG_TOTAL_TOKEN_SUPPLY, ARG_WHITELIST_MODE, CONFIG,
+        ENTRY_POINT_INIT, MAX_PAGE_LIMIT, MAX_PAGE_SIZE, MIN_PAGE_SIZE, NUMBER_OF_MINTED_TOKENS,
     },
     events::events_ces::{
-        Approval, ApprovalForAll, ApprovalRevoked, Burn, MetadataUpdated, Migration, Mint,
-        Transfer, VariablesSet,
+        AdminTransferStarted, AdminTransferred, Approval, ApprovalForAll, ApprovalRevoked, Burn,
+        CollectionMetadataUpdated, MetadataFrozen, MetadataUpdated, Migration, Mint, Transfer,
+        VariablesSet,
     },
+    modalities::{self, Config},
 };
 
 use crate::utility::{
     constants::{CONTRACT_NAME, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, NFT_TEST_SYMBOL},
     installer_request_builder::{
-        InstallerRequestBuilder, MintingMode, NFTHolderMode, NFTIdentifierMode, NFTMetadataKind,
-        OwnerReverseLookupMode, OwnershipMode, WhitelistMode,
+        EventsMode, InstallerRequestBuilder, MintingMode, NFTHolderMode, NFTIdentifierMode,
+        NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode, WhitelistMode,
     },
     support::{self, get_dictionary_value_from_key, get_nft_contract_hash},
 };
@@ -117,8 +122,12 @@ fn should_install_contract() {
         .with::<ApprovalForAll>()
         .with::<Transfer>()
         .with::<MetadataUpdated>()
+        .with::<MetadataFrozen>()
+        .with::<CollectionMetadataUpdated>()
         .with::<VariablesSet>()
-        .with::<Migration>();
+        .with::<Migration>()
+        .with::<AdminTransferStarted>()
+        .with::<AdminTransferred>();
     let actual_schemas: Schemas = support::query_stored_value(
         &builder,
         nft_contract_key,
@@ -342,7 +351,7 @@ fn should_disallow_installation_with_zero_issuance() {
 }
 
 #[test]
-fn should_disallow_installation_with_supply_exceeding_hard_cap() {
+fn should_allow_installation_with_supply_exceeding_former_hard_cap() {
     let mut builder = InMemoryWasmTestBuilder::default();
     builder
         .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
@@ -355,6 +364,35 @@ fn should_disallow_installation_with_supply_exceeding_hard_cap() {
         .with_ownership_mode(OwnershipMode::Minter)
         .with_identifier_mode(NFTIdentifierMode::Ordinal)
         .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = get_nft_contract_hash(&builder).into();
+    let total_token_supply: u64 = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![ARG_TOTAL_TOKEN_SUPPLY.to_string()],
+    );
+
+    assert_eq!(total_token_supply, 1_000_001u64);
+}
+
+#[test]
+fn should_disallow_installation_with_supply_exceeding_max_page_limit() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(MAX_PAGE_LIMIT * MIN_PAGE_SIZE + 1)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .with_page_size(MIN_PAGE_SIZE)
         .build();
 
     builder.exec(install_request).expect_failure().commit();
@@ -364,10 +402,40 @@ fn should_disallow_installation_with_supply_exceeding_hard_cap() {
     support::assert_expected_error(
         error,
         133u16,
-        "cannot install when issuance is more than 1_000_000",
+        "cannot install when the supply spans more pages than the page limit cap",
     );
 }
 
+#[test]
+fn should_disallow_installation_with_page_size_out_of_bounds() {
+    for page_size in [MIN_PAGE_SIZE - 1, MAX_PAGE_SIZE + 1] {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let install_request =
+            InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+                .with_collection_name(NFT_TEST_COLLECTION.to_string())
+                .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+                .with_total_token_supply(100u64)
+                .with_ownership_mode(OwnershipMode::Transferable)
+                .with_reporting_mode(OwnerReverseLookupMode::Complete)
+                .with_page_size(page_size)
+                .build();
+
+        builder.exec(install_request).expect_failure().commit();
+
+        let error = builder.get_error().expect("must have error");
+
+        support::assert_expected_error(
+            error,
+            222u16,
+            "cannot install with a page size out of bounds",
+        );
+    }
+}
+
 #[test]
 fn should_prevent_installation_with_ownership_and_minting_modality_conflict() {
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -469,3 +537,37 @@ fn should_allow_installation_with_ownership_transferable_and_owner_reverse_looku
 
     builder.exec(install_request).expect_success().commit();
 }
+
+#[test]
+fn should_pack_modalities_into_config_at_installation() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1_000u64)
+        .with_minting_mode(MintingMode::Public)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_identifier_mode(NFTIdentifierMode::Hash)
+        .with_reporting_mode(OwnerReverseLookupMode::TransfersOnly)
+        .with_events_mode(EventsMode::CES)
+        .with_page_size(MIN_PAGE_SIZE)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = get_nft_contract_hash(&builder).into();
+    let config_bytes: Bytes =
+        support::query_stored_value(&builder, nft_contract_key, vec![CONFIG.to_string()]);
+    let config: Config = bytesrepr::deserialize_from_slice(config_bytes).unwrap();
+
+    assert!(config.ownership_mode == OwnershipMode::Transferable);
+    assert!(config.minting_mode == MintingMode::Public);
+    assert!(config.identifier_mode == modalities::NFTIdentifierMode::Hash);
+    assert!(config.reporting_mode == modalities::OwnerReverseLookupMode::TransfersOnly);
+    assert!(config.events_mode == EventsMode::CES);
+    assert_eq!(config.page_size, MIN_PAGE_SIZE);
+}
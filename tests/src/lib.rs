@@ -34,6 +34,26 @@ mod events;
 // around acl whitelist.
 #[cfg(test)]
 mod acl;
+// A collection of tests that are focused
+// around token royalties.
+#[cfg(test)]
+mod royalty;
+// A collection of tests that are focused
+// around roles.
+#[cfg(test)]
+mod roles;
+// A collection of tests that are focused
+// around token URIs.
+#[cfg(test)]
+mod token_uri;
+// A collection of tests that are focused
+// around enumerating the tokens of an owner.
+#[cfg(test)]
+mod enumeration;
+// A collection of tests that are focused
+// around collection statistics.
+#[cfg(test)]
+mod collection_stats;
 
 // A collection of helper methods and constants.
 #[cfg(test)]
@@ -4,28 +4,33 @@ use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
-use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs};
+use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs};
 use contract::{
     constants::{
-        ACL_WHITELIST, ARG_COLLECTION_NAME, ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_META_DATA,
-        ARG_TOKEN_OWNER, ENTRY_POINT_METADATA, ENTRY_POINT_MINT, ENTRY_POINT_SET_TOKEN_METADATA,
-        METADATA_CEP78, METADATA_CUSTOM_VALIDATED, METADATA_NFT721, METADATA_RAW, TOKEN_OWNERS,
+        ACL_WHITELIST, ARG_COLLECTION_METADATA, ARG_COLLECTION_NAME, ARG_NFT_METADATA_KIND,
+        ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, COLLECTION_METADATA,
+        ENTRY_POINT_COLLECTION_METADATA, ENTRY_POINT_FREEZE_METADATA, ENTRY_POINT_METADATA,
+        ENTRY_POINT_MINT, ENTRY_POINT_SET_COLLECTION_METADATA, ENTRY_POINT_SET_TOKEN_METADATA,
+        FROZEN_METADATA, METADATA_CEP78, METADATA_COMPACT, METADATA_CUSTOM_VALIDATED,
+        METADATA_NFT721, METADATA_RAW, TOKEN_OWNERS,
     },
-    events::events_ces::MetadataUpdated,
+    events::events_ces::{CollectionMetadataUpdated, MetadataFrozen, MetadataUpdated},
     modalities::TokenIdentifier,
 };
 
 use crate::utility::{
     constants::{
-        ARG_IS_HASH_IDENTIFIER_MODE, ARG_NFT_CONTRACT_HASH, ARG_REVERSE_LOOKUP,
-        MALFORMED_META_DATA, MINTING_CONTRACT_WASM, MINT_SESSION_WASM, NFT_CONTRACT_WASM,
-        NFT_TEST_COLLECTION, TEST_PRETTY_721_META_DATA, TEST_PRETTY_CEP78_METADATA,
-        TEST_PRETTY_UPDATED_721_META_DATA, TEST_PRETTY_UPDATED_CEP78_METADATA, TOKEN_HASH,
+        ACCOUNT_USER_1, ACCOUNT_USER_2, ACCOUNT_USER_3, ARG_IS_HASH_IDENTIFIER_MODE,
+        ARG_NFT_CONTRACT_HASH, ARG_REVERSE_LOOKUP, MALFORMED_META_DATA, MINTING_CONTRACT_WASM,
+        MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, TEST_PRETTY_721_META_DATA,
+        TEST_PRETTY_CEP78_METADATA, TEST_PRETTY_UPDATED_721_META_DATA,
+        TEST_PRETTY_UPDATED_CEP78_METADATA, TOKEN_HASH,
     },
     installer_request_builder::{
-        InstallerRequestBuilder, MetadataMutability, MintingMode, NFTHolderMode, NFTIdentifierMode,
-        NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode, WhitelistMode,
-        TEST_CUSTOM_METADATA, TEST_CUSTOM_METADATA_SCHEMA, TEST_CUSTOM_UPDATED_METADATA,
+        InstallerRequestBuilder, MetadataMutability, MetadataStorageMode, MintingMode,
+        NFTHolderMode, NFTIdentifierMode, NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode,
+        WhitelistMode, TEST_CUSTOM_METADATA, TEST_CUSTOM_METADATA_SCHEMA,
+        TEST_CUSTOM_UPDATED_METADATA,
     },
     support,
     support::{assert_expected_error, get_minting_contract_hash, get_nft_contract_hash},
@@ -983,3 +988,997 @@ fn should_not_require_json_schema_when_kind_is_not_custom_validated() {
     should_not_require_json_schema_when_kind_is(NFTMetadataKind::CEP78);
     should_not_require_json_schema_when_kind_is(NFTMetadataKind::NFT721);
 }
+
+#[test]
+fn should_prevent_install_with_hash_identifier_in_any_mutable_mode() {
+    for metadata_mutability in [
+        MetadataMutability::IssuerOnly,
+        MetadataMutability::OwnerOrIssuer,
+        MetadataMutability::Installer,
+    ] {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let install_request =
+            InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+                .with_total_token_supply(10u64)
+                .with_nft_metadata_kind(NFTMetadataKind::NFT721)
+                .with_identifier_mode(NFTIdentifierMode::Hash)
+                .with_metadata_mutability(metadata_mutability)
+                .build();
+
+        builder.exec(install_request).expect_failure();
+
+        let error = builder.get_error().expect("must fail at installation");
+
+        assert_expected_error(error, 102, "Should raise InvalidMetadataMutability(102)")
+    }
+}
+
+// Installs in the given mutability mode with public minting, then mints token 0 from the issuer
+// to the owner.
+fn setup_metadata_editing(
+    builder: &mut InMemoryWasmTestBuilder,
+    metadata_mutability: MetadataMutability,
+    issuer: AccountHash,
+    owner: AccountHash,
+) -> ContractHash {
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_minting_mode(MintingMode::Public)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_nft_metadata_kind(NFTMetadataKind::NFT721)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_metadata_mutability(metadata_mutability)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(builder);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        issuer,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(owner),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA,
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    nft_contract_hash
+}
+
+fn update_token_metadata(
+    builder: &mut InMemoryWasmTestBuilder,
+    caller: AccountHash,
+    nft_contract_hash: ContractHash,
+) -> bool {
+    let update_token_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_UPDATED_721_META_DATA
+        },
+    )
+    .build();
+
+    builder
+        .exec(update_token_metadata_request)
+        .commit()
+        .is_error()
+}
+
+#[test]
+fn should_only_allow_issuer_to_update_metadata_in_issuer_only_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let issuer = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let owner = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+
+    let nft_contract_hash =
+        setup_metadata_editing(&mut builder, MetadataMutability::IssuerOnly, issuer, owner);
+
+    assert!(update_token_metadata(
+        &mut builder,
+        owner,
+        nft_contract_hash
+    ));
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        189,
+        "owner should not update metadata in issuer only mode",
+    );
+
+    assert!(!update_token_metadata(
+        &mut builder,
+        issuer,
+        nft_contract_hash
+    ));
+
+    let updated_metadata = support::get_dictionary_value_from_key::<String>(
+        &builder,
+        &nft_contract_hash.into(),
+        METADATA_NFT721,
+        &0u64.to_string(),
+    );
+    assert_eq!(TEST_PRETTY_UPDATED_721_META_DATA, updated_metadata);
+}
+
+#[test]
+fn should_allow_owner_or_issuer_to_update_metadata_in_owner_or_issuer_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let issuer = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let owner = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+    let other = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_3));
+
+    let nft_contract_hash = setup_metadata_editing(
+        &mut builder,
+        MetadataMutability::OwnerOrIssuer,
+        issuer,
+        owner,
+    );
+
+    assert!(!update_token_metadata(
+        &mut builder,
+        owner,
+        nft_contract_hash
+    ));
+    assert!(!update_token_metadata(
+        &mut builder,
+        issuer,
+        nft_contract_hash
+    ));
+    assert!(update_token_metadata(
+        &mut builder,
+        other,
+        nft_contract_hash
+    ));
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        189,
+        "only owner or issuer should update metadata in owner or issuer mode",
+    );
+}
+
+#[test]
+fn should_only_allow_installer_to_update_metadata_in_installer_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let issuer = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    // The issuer also owns the token, neither role allows the update.
+    let nft_contract_hash =
+        setup_metadata_editing(&mut builder, MetadataMutability::Installer, issuer, issuer);
+
+    assert!(update_token_metadata(
+        &mut builder,
+        issuer,
+        nft_contract_hash
+    ));
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        189,
+        "only installer should update metadata in installer mode",
+    );
+
+    assert!(!update_token_metadata(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash
+    ));
+}
+
+fn freeze_token_metadata(
+    builder: &mut InMemoryWasmTestBuilder,
+    caller: AccountHash,
+    nft_contract_hash: ContractHash,
+) -> bool {
+    let freeze_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        nft_contract_hash,
+        ENTRY_POINT_FREEZE_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+
+    builder.exec(freeze_metadata_request).commit().is_error()
+}
+
+#[test]
+fn should_allow_issuer_to_freeze_metadata() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let issuer = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let owner = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+    let other = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_3));
+
+    let nft_contract_hash =
+        setup_metadata_editing(&mut builder, MetadataMutability::Mutable, issuer, owner);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    assert!(freeze_token_metadata(
+        &mut builder,
+        other,
+        nft_contract_hash
+    ));
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 6, "only owner or issuer should freeze metadata");
+
+    assert!(!freeze_token_metadata(
+        &mut builder,
+        issuer,
+        nft_contract_hash
+    ));
+
+    // Frozen tokens are recorded with a unit value, the lookup panics otherwise.
+    support::get_dictionary_value_from_key::<()>(
+        &builder,
+        &nft_contract_key,
+        FROZEN_METADATA,
+        &0u64.to_string(),
+    );
+
+    // Expect MetadataFrozen event.
+    let expected_event = MetadataFrozen::new(TokenIdentifier::Index(0));
+    let actual_event: MetadataFrozen = support::get_event(&builder, &nft_contract_key, 1).unwrap();
+    assert_eq!(
+        actual_event, expected_event,
+        "Expected MetadataFrozen event."
+    );
+
+    assert!(update_token_metadata(
+        &mut builder,
+        owner,
+        nft_contract_hash
+    ));
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 190, "should not update frozen metadata");
+
+    assert!(freeze_token_metadata(
+        &mut builder,
+        owner,
+        nft_contract_hash
+    ));
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 190, "should not freeze metadata twice");
+}
+
+#[test]
+fn should_allow_owner_to_freeze_metadata() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let issuer = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let owner = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+
+    let nft_contract_hash = setup_metadata_editing(
+        &mut builder,
+        MetadataMutability::OwnerOrIssuer,
+        issuer,
+        owner,
+    );
+
+    assert!(!freeze_token_metadata(
+        &mut builder,
+        owner,
+        nft_contract_hash
+    ));
+
+    // The issuer can no longer update the metadata either.
+    assert!(update_token_metadata(
+        &mut builder,
+        issuer,
+        nft_contract_hash
+    ));
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 190, "should not update frozen metadata");
+}
+
+const TEST_TYPED_METADATA_SCHEMA: &str = r#"{
+    "properties": {
+        "name": {
+            "name": "name",
+            "description": "The capitalized name of the hero.",
+            "required": true,
+            "type": "string",
+            "max_length": 10,
+            "pattern": "^[A-Z][a-z]+$"
+        },
+        "level": {
+            "name": "level",
+            "description": "The level of the hero.",
+            "required": true,
+            "type": "number",
+            "min": 1,
+            "max": 100
+        },
+        "class": {
+            "name": "class",
+            "description": "The class of the hero.",
+            "required": true,
+            "type": "enum",
+            "enum": ["warrior", "mage"]
+        },
+        "alive": {
+            "name": "alive",
+            "description": "Whether the hero is alive.",
+            "required": false,
+            "type": "bool"
+        },
+        "guild": {
+            "name": "guild",
+            "description": "The guild tag of the hero.",
+            "required": false,
+            "pattern": "\\d{2}-[a-z_]+"
+        }
+    }
+}"#;
+
+fn mint_custom_validated_metadata(
+    builder: &mut InMemoryWasmTestBuilder,
+    nft_contract_hash: ContractHash,
+    token_metadata: &str,
+) -> bool {
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => token_metadata.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).commit().is_error()
+}
+
+#[test]
+fn should_validate_typed_custom_metadata() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_nft_metadata_kind(NFTMetadataKind::CustomValidated)
+        .with_json_schema(TEST_TYPED_METADATA_SCHEMA.to_string())
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    for valid_metadata in [
+        r#"{"name": "Baldur", "level": 12, "class": "warrior"}"#,
+        r#"{"name": "Freya", "level": 100, "class": "mage", "alive": false}"#,
+        r#"{"name": "Thor", "level": 1, "class": "mage", "guild": "guild 07-asgard"}"#,
+    ] {
+        assert!(
+            !mint_custom_validated_metadata(&mut builder, nft_contract_hash, valid_metadata),
+            "should mint {valid_metadata}"
+        );
+    }
+
+    for invalid_metadata in [
+        r#"{"name": "Baldur", "level": "banana", "class": "warrior"}"#,
+        r#"{"name": "Baldur", "level": "12", "class": "warrior"}"#,
+        r#"{"name": "Baldur", "level": 0, "class": "warrior"}"#,
+        r#"{"name": "Baldur", "level": 101, "class": "warrior"}"#,
+        r#"{"name": "Baldur", "level": 12, "class": "bard"}"#,
+        r#"{"name": "Baldur", "level": 12, "class": "warrior", "alive": "false"}"#,
+        r#"{"name": "baldur", "level": 12, "class": "warrior"}"#,
+        r#"{"name": "Baldurbaldur", "level": 12, "class": "warrior"}"#,
+        r#"{"name": "Baldur", "level": 12, "class": "warrior", "guild": "7-asgard"}"#,
+        r#"{"name": 7, "level": 12, "class": "warrior"}"#,
+        r#"{"name": "Baldur", "class": "warrior"}"#,
+    ] {
+        assert!(
+            mint_custom_validated_metadata(&mut builder, nft_contract_hash, invalid_metadata),
+            "should not mint {invalid_metadata}"
+        );
+
+        let error = builder.get_error().expect("must have error");
+        assert_expected_error(error, 95, "Should raise InvalidCustomMetadata(95)");
+    }
+
+    // Updates are validated against the same schema.
+    let update_token_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TOKEN_META_DATA => r#"{"name": "Baldur", "level": "banana", "class": "warrior"}"#
+        },
+    )
+    .build();
+
+    builder.exec(update_token_metadata_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 95, "Should raise InvalidCustomMetadata(95)");
+}
+
+#[test]
+fn should_bound_pattern_matching_of_custom_metadata() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let json_schema = r#"{"properties": {"tag": {"name": "tag", "description": "", "required": true, "type": "string", "pattern": "a*a*a*a*a*a*a*a*b"}}}"#;
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_nft_metadata_kind(NFTMetadataKind::CustomValidated)
+        .with_json_schema(json_schema.to_string())
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    assert!(
+        !mint_custom_validated_metadata(&mut builder, nft_contract_hash, r#"{"tag": "aaab"}"#),
+        "should mint a value matching the pattern"
+    );
+
+    // Matching this value would backtrack through every split of the characters between the
+    // quantified atoms, the matcher gives up instead of running out of gas.
+    let token_metadata = format!(r#"{{"tag": "{}"}}"#, "a".repeat(64));
+    assert!(
+        mint_custom_validated_metadata(&mut builder, nft_contract_hash, &token_metadata),
+        "should not mint a value exceeding the pattern matching steps"
+    );
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 95, "Should raise InvalidCustomMetadata(95)");
+}
+
+#[test]
+fn should_require_consistent_typed_json_schema() {
+    for invalid_property in [
+        r#""type": "enum""#,
+        r#""type": "number", "min": 10, "max": 1"#,
+        r#""type": "string", "enum": ["a"]"#,
+        r#""pattern": "[a-z"#,
+        r#""pattern": "(a|b)""#,
+        r#""type": "text""#,
+        r#""type": "object""#,
+        r#""type": "string", "items": {"name": "", "description": "", "required": true}"#,
+        r#""type": "array", "items": {"name": "", "description": "", "required": true, "type": "enum"}"#,
+    ] {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let json_schema = format!(
+            r#"{{"properties": {{"level": {{"name": "level", "description": "", "required": true, {invalid_property}}}}}}}"#
+        );
+
+        let install_request =
+            InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+                .with_total_token_supply(10u64)
+                .with_nft_metadata_kind(NFTMetadataKind::CustomValidated)
+                .with_json_schema(json_schema)
+                .with_metadata_mutability(MetadataMutability::Mutable)
+                .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+                .build();
+
+        builder.exec(install_request).expect_failure();
+
+        let error = builder.get_error().expect("must have error");
+        assert_expected_error(
+            error,
+            68,
+            &format!("Should raise InvalidJsonSchema(68) for {invalid_property}"),
+        );
+    }
+}
+
+const TEST_NESTED_METADATA_SCHEMA: &str = r#"{
+    "properties": {
+        "name": {
+            "name": "name",
+            "description": "The name of the NFT.",
+            "required": true,
+            "type": "string"
+        },
+        "attributes": {
+            "name": "attributes",
+            "description": "The traits of the NFT.",
+            "required": true,
+            "type": "array",
+            "max_length": 3,
+            "items": {
+                "name": "attribute",
+                "description": "A trait of the NFT.",
+                "required": true,
+                "type": "object",
+                "properties": {
+                    "trait_type": {
+                        "name": "trait_type",
+                        "description": "The name of the trait.",
+                        "required": true,
+                        "type": "string"
+                    },
+                    "value": {
+                        "name": "value",
+                        "description": "The value of the trait.",
+                        "required": true
+                    }
+                }
+            }
+        }
+    }
+}"#;
+
+#[test]
+fn should_keep_types_of_nested_custom_metadata() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_nft_metadata_kind(NFTMetadataKind::CustomValidated)
+        .with_json_schema(TEST_NESTED_METADATA_SCHEMA.to_string())
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    let token_metadata = r#"{
+        "name": "Baldur",
+        "attributes": [
+            {"trait_type": "level", "value": 12},
+            {"trait_type": "alive", "value": true},
+            {"trait_type": "weapons", "value": ["spear", null, {"runes": -3}]}
+        ],
+        "lore": {"pantheon": "Nordic", "age": 18446744073709551615}
+    }"#;
+
+    assert!(!mint_custom_validated_metadata(
+        &mut builder,
+        nft_contract_hash,
+        token_metadata
+    ));
+
+    let stored_metadata = support::get_dictionary_value_from_key::<String>(
+        &builder,
+        &nft_contract_hash.into(),
+        METADATA_CUSTOM_VALIDATED,
+        &0u64.to_string(),
+    );
+
+    let expected_metadata: serde_json::Value = serde_json::from_str(token_metadata).unwrap();
+    let stored_metadata: serde_json::Value = serde_json::from_str(&stored_metadata).unwrap();
+    assert_eq!(stored_metadata, expected_metadata);
+
+    for invalid_metadata in [
+        r#"{"name": "Baldur", "attributes": {"trait_type": "level", "value": 12}}"#,
+        r#"{"name": "Baldur", "attributes": [{"value": 12}]}"#,
+        r#"{"name": "Baldur", "attributes": [{"trait_type": 1, "value": 12}]}"#,
+        r#"{"name": "Baldur", "attributes": [1, 2, 3, 4]}"#,
+    ] {
+        assert!(
+            mint_custom_validated_metadata(&mut builder, nft_contract_hash, invalid_metadata),
+            "should not mint {invalid_metadata}"
+        );
+
+        let error = builder.get_error().expect("must have error");
+        assert_expected_error(error, 95, "Should raise InvalidCustomMetadata(95)");
+    }
+
+    // Floating point numbers cannot be handled by the contract.
+    assert!(mint_custom_validated_metadata(
+        &mut builder,
+        nft_contract_hash,
+        r#"{"name": "Baldur", "attributes": [{"trait_type": "level", "value": 1.5}]}"#
+    ));
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 90, "Should raise FailedToParseCustomMetadata(90)");
+}
+
+const TEST_COLLECTION_METADATA: &str = r#"{"description":"A collection of deities","image":"ipfs://collection/image.png","external_link":"https://deities.example"}"#;
+
+fn set_collection_metadata(
+    builder: &mut InMemoryWasmTestBuilder,
+    caller: AccountHash,
+    nft_contract_hash: ContractHash,
+    collection_metadata: &str,
+) -> bool {
+    let set_collection_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        nft_contract_hash,
+        ENTRY_POINT_SET_COLLECTION_METADATA,
+        runtime_args! {
+            ARG_COLLECTION_METADATA => collection_metadata.to_string(),
+        },
+    )
+    .build();
+    builder.exec(set_collection_metadata_request);
+
+    let is_error = builder.is_error();
+    if !is_error {
+        builder.commit();
+    }
+    is_error
+}
+
+#[test]
+fn should_install_and_update_collection_metadata() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let account_user_1 = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_collection_metadata(TEST_COLLECTION_METADATA)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let collection_metadata: String = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![COLLECTION_METADATA.to_string()],
+    );
+    assert_eq!(collection_metadata, TEST_COLLECTION_METADATA);
+
+    let collection_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_COLLECTION_METADATA,
+        runtime_args! {},
+    )
+    .build();
+    builder
+        .exec(collection_metadata_request)
+        .expect_success()
+        .commit();
+
+    let updated_collection_metadata =
+        r#"{"description":"Deities of old","banner":{"width":1400,"height":350}}"#;
+
+    assert!(set_collection_metadata(
+        &mut builder,
+        account_user_1,
+        nft_contract_hash,
+        updated_collection_metadata
+    ));
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        1,
+        "only the installer should update collection metadata",
+    );
+
+    assert!(set_collection_metadata(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        "not a json document"
+    ));
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 194, "collection metadata should be a JSON object");
+
+    assert!(!set_collection_metadata(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        updated_collection_metadata
+    ));
+
+    let collection_metadata: String = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![COLLECTION_METADATA.to_string()],
+    );
+    assert_eq!(collection_metadata, updated_collection_metadata);
+
+    // Expect CollectionMetadataUpdated event.
+    let expected_event = CollectionMetadataUpdated::new(updated_collection_metadata.to_string());
+    let actual_event: CollectionMetadataUpdated =
+        support::get_event(&builder, &nft_contract_key, 0).unwrap();
+    assert_eq!(
+        actual_event, expected_event,
+        "Expected CollectionMetadataUpdated event."
+    );
+}
+
+#[test]
+fn should_require_valid_collection_metadata() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_collection_metadata(r#"["not", "an", "object"]"#)
+        .build();
+
+    builder.exec(install_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        194,
+        "collection metadata should be validated at install",
+    );
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    let collection_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_COLLECTION_METADATA,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(collection_metadata_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 193, "collection metadata should be missing");
+}
+
+#[test]
+fn should_store_metadata_once_in_compact_storage_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_minting_mode(MintingMode::Public)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_nft_metadata_kind(NFTMetadataKind::NFT721)
+        .with_optional_metadata(vec![NFTMetadataKind::CEP78 as u8])
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_metadata_storage_mode(MetadataStorageMode::Compact)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA,
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let named_keys = builder
+        .get_contract(nft_contract_hash)
+        .expect("must have nft contract")
+        .named_keys()
+        .clone();
+    assert!(named_keys.contains_key(METADATA_COMPACT));
+    assert!(!named_keys.contains_key(METADATA_NFT721));
+    assert!(!named_keys.contains_key(METADATA_RAW));
+
+    // The metadata validated against the NFT721 kind but not against CEP78, which has no checksum,
+    // and is stored as compact JSON.
+    let (token_metadata, validated_kinds): (String, u8) = support::get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        METADATA_COMPACT,
+        &0u64.to_string(),
+    );
+    assert_eq!(
+        token_metadata,
+        r#"{"name":"John Doe","symbol":"abc","token_uri":"https://www.barfoo.com"}"#
+    );
+    assert_eq!(validated_kinds, 1 << NFTMetadataKind::NFT721 as u8);
+
+    for (metadata_kind, is_validated) in [
+        (NFTMetadataKind::NFT721, true),
+        (NFTMetadataKind::CEP78, false),
+    ] {
+        let metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_METADATA,
+            runtime_args! {
+                ARG_TOKEN_ID => 0u64,
+                ARG_NFT_METADATA_KIND => metadata_kind as u8,
+            },
+        )
+        .build();
+
+        if is_validated {
+            builder.exec(metadata_request).expect_success().commit();
+        } else {
+            builder.exec(metadata_request).expect_failure();
+            let error = builder.get_error().expect("must have error");
+            assert_expected_error(
+                error,
+                47u16,
+                "metadata should only be read for the kinds it validated against",
+            );
+        }
+    }
+
+    let update_failed =
+        update_token_metadata(&mut builder, *DEFAULT_ACCOUNT_ADDR, nft_contract_hash);
+    assert!(!update_failed, "owner should update the token metadata");
+
+    let (updated_token_metadata, _): (String, u8) = support::get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        METADATA_COMPACT,
+        &0u64.to_string(),
+    );
+    assert_eq!(
+        updated_token_metadata,
+        r#"{"name":"John Doe","symbol":"abc","token_uri":"https://www.foobar.com"}"#
+    );
+}
+
+#[test]
+fn should_render_compact_metadata_views_of_validated_kinds() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let minting_contract_install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINTING_CONTRACT_WASM,
+        runtime_args! {},
+    )
+    .build();
+
+    builder
+        .exec(minting_contract_install_request)
+        .expect_success()
+        .commit();
+
+    let minting_contract_hash = get_minting_contract_hash(&builder);
+    let minting_contract_key: Key = minting_contract_hash.into();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_minting_mode(MintingMode::Public)
+        .with_nft_metadata_kind(NFTMetadataKind::NFT721)
+        .with_optional_metadata(vec![NFTMetadataKind::Raw as u8])
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_metadata_storage_mode(MetadataStorageMode::Compact)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    // Compact JSON with the keys out of the order the NFT721 view renders them in.
+    let submitted_metadata =
+        r#"{"token_uri":"https://www.barfoo.com","symbol":"abc","name":"John Doe"}"#;
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => submitted_metadata,
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    // Metadata validated against the Raw kind is stored as submitted.
+    let (token_metadata, _): (String, u8) = support::get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        METADATA_COMPACT,
+        &0u64.to_string(),
+    );
+    assert_eq!(token_metadata, submitted_metadata);
+
+    for (metadata_kind, expected_metadata) in [
+        (NFTMetadataKind::Raw, submitted_metadata),
+        (NFTMetadataKind::NFT721, TEST_PRETTY_721_META_DATA),
+    ] {
+        let get_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            minting_contract_hash,
+            ENTRY_POINT_METADATA,
+            runtime_args! {
+                ARG_IS_HASH_IDENTIFIER_MODE => false,
+                ARG_TOKEN_ID => 0u64,
+                ARG_NFT_CONTRACT_HASH => nft_contract_key,
+                ARG_NFT_METADATA_KIND => metadata_kind as u8,
+            },
+        )
+        .build();
+
+        builder.exec(get_metadata_request).expect_success().commit();
+
+        let actual_metadata: String = support::query_stored_value(
+            &builder,
+            minting_contract_key,
+            vec!["metadata".to_string()],
+        );
+        assert_eq!(actual_metadata, expected_metadata);
+    }
+}
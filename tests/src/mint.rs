@@ -1,11 +1,13 @@
 use contract::{
     constants::{
-        APPROVED, ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_MINTING_MODE, ARG_OPERATOR,
-        ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID,
-        ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ENTRY_POINT_APPROVE, ENTRY_POINT_MINT,
-        ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_SET_APPROVALL_FOR_ALL, METADATA_CEP78,
-        METADATA_CUSTOM_VALIDATED, METADATA_NFT721, METADATA_RAW, NUMBER_OF_MINTED_TOKENS,
-        PAGE_TABLE, RECEIPT_NAME, TOKEN_COUNT, TOKEN_ISSUERS, TOKEN_OWNERS,
+        APPROVED_SPENDERS, ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_DATA, ARG_MINTING_MODE,
+        ARG_OPERATOR, ARG_SAFE, ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_HASH,
+        ARG_TOKEN_HASHES, ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_META_DATAS, ARG_TOKEN_OWNER,
+        ARG_TOKEN_OWNERS, ENTRY_POINT_APPROVE, ENTRY_POINT_MINT, ENTRY_POINT_MINT_BATCH,
+        ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_SET_APPROVALL_FOR_ALL,
+        FILTER_CONTRACT_DENY_REASON_OFFSET, METADATA_CEP78, METADATA_CUSTOM_VALIDATED,
+        METADATA_NFT721, METADATA_RAW, NUMBER_OF_MINTED_TOKENS, PAGE_LIMIT, PAGE_TABLE,
+        PREFIX_PAGE_DICTIONARY, RECEIPT_NAME, TOKEN_COUNT, TOKEN_ISSUERS, TOKEN_OWNERS,
     },
     events::events_ces::{ApprovalForAll, Mint, RevokedForAll},
     modalities::TokenIdentifier,
@@ -17,25 +19,31 @@ use casper_engine_test_support::{
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
 use casper_execution_engine::storage::global_state::in_memory::InMemoryGlobalState;
-use casper_types::{account::AccountHash, runtime_args, CLValue, Key, RuntimeArgs};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{self, Bytes},
+    runtime_args, Key, RuntimeArgs,
+};
 
 use crate::utility::{
     constants::{
-        ACCOUNT_USER_1, ACCOUNT_USER_2, ARG_IS_HASH_IDENTIFIER_MODE, ARG_KEY_NAME,
-        ARG_NFT_CONTRACT_HASH, BALANCE_OF_SESSION_WASM, CONTRACT_NAME, GET_APPROVED_WASM,
-        IS_APPROVED_FOR_ALL_WASM, MALFORMED_META_DATA, MINT_SESSION_WASM, NFT_CONTRACT_WASM,
-        NFT_TEST_COLLECTION, OWNER_OF_SESSION_WASM, PAGE_SIZE, TEST_COMPACT_META_DATA,
-        TEST_PRETTY_721_META_DATA, TEST_PRETTY_CEP78_METADATA, TEST_PRETTY_UPDATED_CEP78_METADATA,
-        TRANSFER_SESSION_WASM,
+        ACCOUNT_USER_1, ACCOUNT_USER_2, ARG_FILTER_CONTRACT_RETURN_VALUE,
+        ARG_IS_HASH_IDENTIFIER_MODE, ARG_KEY_NAME, ARG_NFT_CONTRACT_HASH, BALANCE_OF_SESSION_WASM,
+        CONTRACT_NAME, GET_APPROVED_WASM, IS_APPROVED_FOR_ALL_WASM, MALFORMED_META_DATA,
+        MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, OWNER_OF_SESSION_WASM,
+        PAGE_SIZE, RECEIVER_CONTRACT_WASM, TEST_COMPACT_META_DATA, TEST_PRETTY_721_META_DATA,
+        TEST_PRETTY_CEP78_METADATA, TEST_PRETTY_UPDATED_CEP78_METADATA,
+        TRANSFER_FILTER_CONTRACT_WASM, TRANSFER_SESSION_WASM,
     },
     installer_request_builder::{
         InstallerRequestBuilder, MetadataMutability, MintingMode, NFTHolderMode, NFTIdentifierMode,
-        NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode, WhitelistMode,
+        NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode, PageEncoding, WhitelistMode,
         TEST_CUSTOM_METADATA, TEST_CUSTOM_METADATA_SCHEMA,
     },
     support::{
         self, assert_expected_error, call_session_code_with_ret, create_funded_dummy_account,
-        get_dictionary_value_from_key, get_nft_contract_hash, get_token_page_by_hash,
+        get_dictionary_value_from_key, get_nft_contract_hash, get_receiver_contract_hash,
+        get_token_page_by_hash, get_transfer_filter_contract_hash,
     },
 };
 
@@ -179,7 +187,7 @@ fn entry_points_with_ret_should_return_correct_value() {
     .build();
     builder.exec(approve_request).expect_success().commit();
 
-    let actual_approved_account: Option<Key> = call_session_code_with_ret(
+    let actual_approved_accounts: Vec<Key> = call_session_code_with_ret(
         &mut builder,
         account_hash,
         nft_contract_key,
@@ -193,9 +201,9 @@ fn entry_points_with_ret_should_return_correct_value() {
 
     let expected_approved_account = Key::Account(AccountHash::new(ACCOUNT_USER_1));
     assert_eq!(
-        actual_approved_account,
-        Some(expected_approved_account),
-        "actual and expected approved account should be equal"
+        actual_approved_accounts,
+        vec![expected_approved_account],
+        "actual and expected approved accounts should be equal"
     );
 }
 
@@ -288,8 +296,7 @@ fn mint_should_return_dictionary_key_to_callers_owned_tokens() {
         .query(None, account_receipt, &[])
         .expect("must have stored_value")
         .as_cl_value()
-        .map(|page_cl_value| CLValue::into_t::<Vec<bool>>(page_cl_value.clone()))
-        .unwrap()
+        .map(support::decode_page_record)
         .unwrap();
 
     let expected_page = {
@@ -301,6 +308,122 @@ fn mint_should_return_dictionary_key_to_callers_owned_tokens() {
     assert_eq!(actual_page, expected_page);
 }
 
+#[test]
+fn should_store_owner_pages_in_the_page_encoding_of_the_install() {
+    // New installs default to bitmap pages of one bit per token behind the bitmap header.
+    for (page_encoding, expected_record_length) in [
+        (None, 1 + PAGE_SIZE / 8),
+        (Some(PageEncoding::Bitmap), 1 + PAGE_SIZE / 8),
+        (Some(PageEncoding::BoolList), PAGE_SIZE),
+    ] {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let mut install_request_builder =
+            InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+                .with_collection_name(NFT_TEST_COLLECTION.to_string())
+                .with_total_token_supply(100u64)
+                .with_ownership_mode(OwnershipMode::Transferable);
+        if let Some(page_encoding) = page_encoding {
+            install_request_builder = install_request_builder.with_page_encoding(page_encoding);
+        }
+
+        builder
+            .exec(install_request_builder.build())
+            .expect_success()
+            .commit();
+
+        let nft_contract_key: Key = get_nft_contract_hash(&builder).into();
+        let mint_session_call = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            MINT_SESSION_WASM,
+            runtime_args! {
+                ARG_NFT_CONTRACT_HASH => nft_contract_key,
+                ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+                ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+            },
+        )
+        .build();
+
+        builder.exec(mint_session_call).expect_success().commit();
+
+        let page_record = support::get_dictionary_cl_value_from_key(
+            &builder,
+            &nft_contract_key,
+            &format!("{PREFIX_PAGE_DICTIONARY}_0"),
+            &DEFAULT_ACCOUNT_ADDR.to_string(),
+        );
+        let record: Bytes = bytesrepr::deserialize_from_slice(page_record.inner_bytes()).unwrap();
+        assert_eq!(record.len() as u64, expected_record_length);
+
+        let page = support::decode_page_record(&page_record);
+        assert_eq!(page.len() as u64, PAGE_SIZE);
+        assert!(page[0]);
+        assert!(!page[1..].contains(&true));
+    }
+}
+
+#[test]
+fn should_address_owner_pages_with_the_page_size_of_the_install() {
+    let page_size = 10u64;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_total_token_supply(30u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_page_size(page_size)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = get_nft_contract_hash(&builder).into();
+
+    let page_limit: u64 =
+        support::query_stored_value(&builder, nft_contract_key, vec![PAGE_LIMIT.to_string()]);
+    assert_eq!(page_limit, 3u64);
+
+    for _ in 0..12 {
+        let mint_session_call = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            MINT_SESSION_WASM,
+            runtime_args! {
+                ARG_NFT_CONTRACT_HASH => nft_contract_key,
+                ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+                ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+            },
+        )
+        .build();
+
+        builder.exec(mint_session_call).expect_success().commit();
+    }
+
+    // Tokens 10 and 11 are the first two tokens of the second page.
+    let second_page = support::get_page_record_from_key(
+        &builder,
+        &nft_contract_key,
+        &format!("{PREFIX_PAGE_DICTIONARY}_1"),
+        &DEFAULT_ACCOUNT_ADDR.to_string(),
+    );
+    assert!(second_page[0] && second_page[1]);
+    assert!(!second_page[2..].contains(&true));
+
+    let nft_receipt: String =
+        support::query_stored_value(&builder, nft_contract_key, vec![RECEIPT_NAME.to_string()]);
+    let account = builder.get_expected_account(*DEFAULT_ACCOUNT_ADDR);
+    assert!(account
+        .named_keys()
+        .contains_key(&format!("{nft_receipt}_m_{page_size}_p_1")));
+}
+
 #[test]
 fn mint_should_increment_number_of_minted_tokens_by_one_and_add_public_key_to_token_owners() {
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -1367,14 +1490,14 @@ fn should_approve_in_hash_identifier_mode() {
 
     builder.exec(approve_request).expect_success().commit();
 
-    let maybe_approved_account = support::get_dictionary_value_from_key::<Option<Key>>(
+    let approved_accounts = support::get_dictionary_value_from_key::<Vec<Key>>(
         &builder,
         &nft_contract_key,
-        APPROVED,
+        APPROVED_SPENDERS,
         &token_hash,
     );
 
-    assert_eq!(maybe_approved_account, Some(spender))
+    assert_eq!(approved_accounts, vec![spender])
 }
 
 #[test]
@@ -1471,14 +1594,16 @@ fn should_maintain_page_table_despite_invoking_register_owner() {
 
     builder.exec(mint_session_call).expect_success().commit();
 
-    let actual_page_table = support::get_dictionary_value_from_key::<Vec<bool>>(
+    let actual_page_table = support::get_page_record_from_key(
         &builder,
         &nft_contract_key,
         PAGE_TABLE,
         &DEFAULT_ACCOUNT_ADDR.to_string(),
     );
 
-    assert_eq!(actual_page_table.len(), 1);
+    // Only the first page is allocated, the bitmap being padded to a whole byte.
+    assert!(actual_page_table[0]);
+    assert!(!actual_page_table[1..].contains(&true));
 
     // The mint WASM will register the owner, now we re-invoke the same entry point
     // and ensure that the page table doesn't mutate.
@@ -1494,7 +1619,7 @@ fn should_maintain_page_table_despite_invoking_register_owner() {
 
     builder.exec(register_call).expect_success().commit();
 
-    let table_post_register = support::get_dictionary_value_from_key::<Vec<bool>>(
+    let table_post_register = support::get_page_record_from_key(
         &builder,
         &nft_contract_key,
         PAGE_TABLE,
@@ -1965,3 +2090,434 @@ fn should_approve_all_with_flat_gas_cost() {
         second_set_approve_for_all_gas_cost
     )
 }
+
+#[test]
+fn should_mint_batch() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let account_user_1 = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    for owner in [*DEFAULT_ACCOUNT_ADDR, account_user_1] {
+        let register_call = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_REGISTER_OWNER,
+            runtime_args! {
+                ARG_TOKEN_OWNER => Key::Account(owner)
+            },
+        )
+        .build();
+        builder.exec(register_call).expect_success().commit();
+    }
+
+    let token_owners = vec![
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Key::Account(account_user_1),
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+    ];
+
+    let mint_batch_call = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT_BATCH,
+        runtime_args! {
+            ARG_TOKEN_META_DATAS => vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            ARG_TOKEN_OWNERS => token_owners.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_batch_call).expect_success().commit();
+
+    let number_of_minted_tokens = support::query_stored_value::<u64>(
+        &builder,
+        nft_contract_key,
+        vec![NUMBER_OF_MINTED_TOKENS.to_string()],
+    );
+    assert_eq!(number_of_minted_tokens, 3u64);
+
+    for (token_id, expected_owner) in token_owners.into_iter().enumerate() {
+        let actual_owner = support::get_dictionary_value_from_key::<Key>(
+            &builder,
+            &nft_contract_key,
+            TOKEN_OWNERS,
+            &token_id.to_string(),
+        );
+        assert_eq!(actual_owner, expected_owner);
+    }
+
+    let minter_balance = support::get_dictionary_value_from_key::<u64>(
+        &builder,
+        &nft_contract_key,
+        TOKEN_COUNT,
+        &DEFAULT_ACCOUNT_ADDR.to_string(),
+    );
+    assert_eq!(minter_balance, 2u64);
+
+    let user_balance = support::get_dictionary_value_from_key::<u64>(
+        &builder,
+        &nft_contract_key,
+        TOKEN_COUNT,
+        &account_user_1.to_string(),
+    );
+    assert_eq!(user_balance, 1u64);
+
+    let token_page = support::get_token_page_by_id(
+        &builder,
+        &nft_contract_key,
+        &Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        2u64,
+    );
+    assert!(token_page[0] && !token_page[1] && token_page[2]);
+}
+
+#[test]
+fn should_mint_batch_with_hash_identifier_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_identifier_mode(NFTIdentifierMode::Hash)
+        .with_metadata_mutability(MetadataMutability::Immutable)
+        .with_ownership_mode(OwnershipMode::Minter)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let custom_token_hash = "custom".to_string();
+
+    let mint_batch_call = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT_BATCH,
+        runtime_args! {
+            ARG_TOKEN_META_DATAS => vec!["a".to_string(), "b".to_string()],
+            ARG_TOKEN_HASHES => vec![custom_token_hash.clone(), String::new()],
+        },
+    )
+    .build();
+
+    builder.exec(mint_batch_call).expect_success().commit();
+
+    let derived_token_hash = base16::encode_lower(&support::create_blake2b_hash("b"));
+
+    for token_hash in [custom_token_hash, derived_token_hash] {
+        let actual_owner = support::get_dictionary_value_from_key::<Key>(
+            &builder,
+            &nft_contract_key,
+            TOKEN_OWNERS,
+            &token_hash,
+        );
+        assert_eq!(actual_owner, Key::Account(*DEFAULT_ACCOUNT_ADDR));
+    }
+}
+
+#[test]
+fn should_not_mint_batch_beyond_total_token_supply() {
+    let mut builder = setup_nft_contract(Some(2u64), true);
+
+    let mint_batch_call = ExecuteRequestBuilder::contract_call_by_name(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_NAME,
+        ENTRY_POINT_MINT_BATCH,
+        runtime_args! {
+            ARG_TOKEN_META_DATAS => vec![TEST_PRETTY_721_META_DATA.to_string(); 3],
+        },
+    )
+    .build();
+    builder.exec(mint_batch_call).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        32u16,
+        "should not mint a batch exceeding the total token supply",
+    );
+}
+
+#[test]
+fn should_not_mint_batch_with_mismatched_token_owners() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let mint_batch_call = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        get_nft_contract_hash(&builder),
+        ENTRY_POINT_MINT_BATCH,
+        runtime_args! {
+            ARG_TOKEN_META_DATAS => vec!["a".to_string(), "b".to_string()],
+            ARG_TOKEN_OWNERS => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)],
+        },
+    )
+    .build();
+    builder.exec(mint_batch_call).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        172u16,
+        "should not mint a batch when token owners do not match the metadata",
+    );
+}
+
+#[test]
+fn should_consult_mint_filter_contract_before_minting() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let filter_contract_install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        TRANSFER_FILTER_CONTRACT_WASM,
+        runtime_args! {},
+    )
+    .build();
+
+    builder
+        .exec(filter_contract_install_request)
+        .expect_success()
+        .commit();
+
+    let filter_contract_hash = get_transfer_filter_contract_hash(&builder);
+
+    let deny_reason = 3u8;
+    let set_return_value_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        filter_contract_hash,
+        "set_return_value",
+        runtime_args! {
+            ARG_FILTER_CONTRACT_RETURN_VALUE => deny_reason
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_return_value_request)
+        .expect_success()
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_mint_filter_contract(Key::from(filter_contract_hash))
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let account_user_1 = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let token_owner_key = Key::Account(account_user_1);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        FILTER_CONTRACT_DENY_REASON_OFFSET + u16::from(deny_reason),
+        "the deny reason of the mint filter must be surfaced in the revert",
+    );
+
+    let number_of_minted_tokens = support::query_stored_value::<u64>(
+        &builder,
+        nft_contract_key,
+        vec![NUMBER_OF_MINTED_TOKENS.to_string()],
+    );
+    assert_eq!(number_of_minted_tokens, 0u64);
+
+    let set_return_value_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        filter_contract_hash,
+        "set_return_value",
+        runtime_args! {
+            ARG_FILTER_CONTRACT_RETURN_VALUE => 0u8
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_return_value_request)
+        .expect_success()
+        .commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let filter_contract_key = Key::from(filter_contract_hash);
+    let last_token_owner: Key = support::query_stored_value(
+        &builder,
+        filter_contract_key,
+        vec!["last_token_owner".to_string()],
+    );
+    let last_issuer: Key = support::query_stored_value(
+        &builder,
+        filter_contract_key,
+        vec!["last_issuer".to_string()],
+    );
+
+    assert_eq!(last_token_owner, token_owner_key);
+    assert_eq!(last_issuer, Key::Account(*DEFAULT_ACCOUNT_ADDR));
+}
+
+#[test]
+fn should_require_receiver_contract_acknowledgment_on_safe_mint() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let receiver_contract_install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        RECEIVER_CONTRACT_WASM,
+        runtime_args! {},
+    )
+    .build();
+
+    builder
+        .exec(receiver_contract_install_request)
+        .expect_success()
+        .commit();
+
+    let receiver_contract_hash = get_receiver_contract_hash(&builder);
+    let receiver_contract_key = Key::from(receiver_contract_hash);
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    // A contract without an on_cep78_received entrypoint cannot safely receive a token.
+    let filter_contract_install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        TRANSFER_FILTER_CONTRACT_WASM,
+        runtime_args! {},
+    )
+    .build();
+
+    builder
+        .exec(filter_contract_install_request)
+        .expect_success()
+        .commit();
+
+    let filter_contract_key = Key::from(get_transfer_filter_contract_hash(&builder));
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => filter_contract_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_SAFE => true,
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_failure();
+
+    let data = Bytes::from(vec![7u8, 8, 9]);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => receiver_contract_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_SAFE => true,
+            ARG_DATA => data.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let actual_token_owner: Key =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, TOKEN_OWNERS, "0");
+    assert_eq!(actual_token_owner, receiver_contract_key);
+
+    let last_operator: Key = support::query_stored_value(
+        &builder,
+        receiver_contract_key,
+        vec!["last_operator".to_string()],
+    );
+    let last_from: Option<Key> = support::query_stored_value(
+        &builder,
+        receiver_contract_key,
+        vec!["last_from".to_string()],
+    );
+    let last_data: Bytes = support::query_stored_value(
+        &builder,
+        receiver_contract_key,
+        vec!["last_data".to_string()],
+    );
+
+    assert_eq!(last_operator, Key::Account(*DEFAULT_ACCOUNT_ADDR));
+    assert_eq!(last_from, None);
+    assert_eq!(last_data, data);
+}
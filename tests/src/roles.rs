@@ -0,0 +1,513 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs};
+use contract::{
+    constants::{
+        ACL_WHITELIST, ALLOW_MINTING, ARG_ALLOW_MINTING, ARG_OPERATOR_BURN_MODE, ARG_ROLE,
+        ARG_ROLE_HOLDER, ARG_TOKEN_ID, ARG_TOKEN_META_DATA, BURNT_TOKENS, ENTRY_POINT_BURN,
+        ENTRY_POINT_GRANT_ROLE, ENTRY_POINT_HAS_ROLE, ENTRY_POINT_REVOKE_ROLE,
+        ENTRY_POINT_SET_TOKEN_METADATA, ENTRY_POINT_SET_VARIABLES, METADATA_EDITORS, PAUSERS,
+    },
+    error::NFTCoreError,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_USER_1, ACCOUNT_USER_2, NFT_CONTRACT_WASM, TEST_PRETTY_UPDATED_721_META_DATA,
+    },
+    installer_request_builder::{
+        InstallerRequestBuilder, MetadataMutability, OwnerReverseLookupMode, Role, WhitelistMode,
+    },
+    support::{
+        self, assert_expected_error, create_funded_dummy_account, get_dictionary_value_from_key,
+        get_nft_contract_hash, mint_token,
+    },
+};
+
+fn update_role(
+    builder: &mut InMemoryWasmTestBuilder,
+    caller: AccountHash,
+    nft_contract_hash: ContractHash,
+    entry_point: &str,
+    role: Role,
+    role_holder: Key,
+) -> bool {
+    let update_role_request = ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        nft_contract_hash,
+        entry_point,
+        runtime_args! {
+            ARG_ROLE => role as u8,
+            ARG_ROLE_HOLDER => role_holder,
+        },
+    )
+    .build();
+
+    builder.exec(update_role_request).commit().is_error()
+}
+
+#[test]
+fn should_grant_and_revoke_roles() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let account_user_1 = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let role_holder = Key::Account(account_user_1);
+    let role_holder_item_key = account_user_1.to_string();
+
+    assert!(!update_role(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_GRANT_ROLE,
+        Role::MetadataEditor,
+        role_holder,
+    ));
+
+    let is_metadata_editor = get_dictionary_value_from_key::<bool>(
+        &builder,
+        &nft_contract_key,
+        METADATA_EDITORS,
+        &role_holder_item_key,
+    );
+    assert!(is_metadata_editor);
+
+    // The minter role is held through the ACL whitelist.
+    assert!(!update_role(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_GRANT_ROLE,
+        Role::Minter,
+        role_holder,
+    ));
+
+    let is_minter = get_dictionary_value_from_key::<bool>(
+        &builder,
+        &nft_contract_key,
+        ACL_WHITELIST,
+        &role_holder_item_key,
+    );
+    assert!(is_minter);
+
+    let has_role_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_HAS_ROLE,
+        runtime_args! {
+            ARG_ROLE => Role::Pauser as u8,
+            ARG_ROLE_HOLDER => role_holder,
+        },
+    )
+    .build();
+    builder.exec(has_role_request).expect_success().commit();
+
+    assert!(!update_role(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REVOKE_ROLE,
+        Role::MetadataEditor,
+        role_holder,
+    ));
+
+    let is_metadata_editor = get_dictionary_value_from_key::<bool>(
+        &builder,
+        &nft_contract_key,
+        METADATA_EDITORS,
+        &role_holder_item_key,
+    );
+    assert!(!is_metadata_editor);
+}
+
+#[test]
+fn should_only_allow_installer_to_grant_roles() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let account_user_1 = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    assert!(update_role(
+        &mut builder,
+        account_user_1,
+        nft_contract_hash,
+        ENTRY_POINT_GRANT_ROLE,
+        Role::Minter,
+        Key::Account(account_user_1),
+    ));
+
+    let error = builder.get_error().expect("should have an error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidAccount as u16,
+        "only the installer can grant roles",
+    );
+
+    let invalid_role_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_GRANT_ROLE,
+        runtime_args! {
+            ARG_ROLE => 4u8,
+            ARG_ROLE_HOLDER => Key::Account(account_user_1),
+        },
+    )
+    .build();
+    builder.exec(invalid_role_request).expect_failure().commit();
+
+    let error = builder.get_error().expect("should have an error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidRole as u16,
+        "should not grant an unknown role",
+    );
+}
+
+#[test]
+fn should_not_grant_minter_role_with_locked_whitelist() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let account_user_1 = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_whitelist_mode(WhitelistMode::Locked)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    assert!(update_role(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_GRANT_ROLE,
+        Role::Minter,
+        Key::Account(account_user_1),
+    ));
+
+    let error = builder.get_error().expect("should have an error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidWhitelistMode as u16,
+        "should not grant minter role with a locked whitelist",
+    );
+
+    // Other roles are not tied to the whitelist mode.
+    assert!(!update_role(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_GRANT_ROLE,
+        Role::Burner,
+        Key::Account(account_user_1),
+    ));
+}
+
+#[test]
+fn should_allow_minter_to_mint_in_installer_minting_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let account_user_1 = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    assert!(
+        mint_token(&mut builder, account_user_1, nft_contract_hash, None)
+            .commit()
+            .is_error()
+    );
+
+    let error = builder.get_error().expect("should have an error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidMinter as u16,
+        "should not mint without the minter role",
+    );
+
+    assert!(!update_role(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_GRANT_ROLE,
+        Role::Minter,
+        Key::Account(account_user_1),
+    ));
+
+    assert!(
+        !mint_token(&mut builder, account_user_1, nft_contract_hash, None)
+            .commit()
+            .is_error()
+    );
+
+    assert!(!update_role(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REVOKE_ROLE,
+        Role::Minter,
+        Key::Account(account_user_1),
+    ));
+
+    assert!(
+        mint_token(&mut builder, account_user_1, nft_contract_hash, None)
+            .commit()
+            .is_error()
+    );
+}
+
+#[test]
+fn should_allow_metadata_editor_to_update_metadata() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let account_user_1 = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    assert!(
+        !mint_token(&mut builder, *DEFAULT_ACCOUNT_ADDR, nft_contract_hash, None)
+            .commit()
+            .is_error()
+    );
+
+    let set_token_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_UPDATED_721_META_DATA,
+        },
+    )
+    .build();
+    builder
+        .exec(set_token_metadata_request)
+        .expect_failure()
+        .commit();
+
+    let error = builder.get_error().expect("should have an error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidTokenOwner as u16,
+        "should not update metadata without the metadata editor role",
+    );
+
+    assert!(!update_role(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_GRANT_ROLE,
+        Role::MetadataEditor,
+        Key::Account(account_user_1),
+    ));
+
+    let set_token_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_UPDATED_721_META_DATA,
+        },
+    )
+    .build();
+    builder
+        .exec(set_token_metadata_request)
+        .expect_success()
+        .commit();
+}
+
+#[test]
+fn should_allow_burner_to_burn_any_token() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let account_user_1 = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    assert!(
+        !mint_token(&mut builder, *DEFAULT_ACCOUNT_ADDR, nft_contract_hash, None)
+            .commit()
+            .is_error()
+    );
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! { ARG_TOKEN_ID => 0u64 },
+    )
+    .build();
+    builder.exec(burn_request).expect_failure().commit();
+
+    let error = builder.get_error().expect("should have an error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidTokenOwner as u16,
+        "should not burn without the burner role",
+    );
+
+    assert!(!update_role(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_GRANT_ROLE,
+        Role::Burner,
+        Key::Account(account_user_1),
+    ));
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! { ARG_TOKEN_ID => 0u64 },
+    )
+    .build();
+    builder.exec(burn_request).expect_success().commit();
+
+    // Burnt tokens are recorded with a unit value, the lookup panics otherwise.
+    get_dictionary_value_from_key::<()>(&builder, &nft_contract_key, BURNT_TOKENS, "0");
+}
+
+#[test]
+fn should_only_allow_pauser_to_toggle_minting() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let account_user_1 = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let account_user_2 = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    assert!(!update_role(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_GRANT_ROLE,
+        Role::Pauser,
+        Key::Account(account_user_1),
+    ));
+
+    let is_pauser = get_dictionary_value_from_key::<bool>(
+        &builder,
+        &nft_contract_key,
+        PAUSERS,
+        &account_user_1.to_string(),
+    );
+    assert!(is_pauser);
+
+    for (caller, runtime_args) in [
+        (account_user_2, runtime_args! { ARG_ALLOW_MINTING => false }),
+        (
+            account_user_1,
+            runtime_args! { ARG_ALLOW_MINTING => false, ARG_OPERATOR_BURN_MODE => true },
+        ),
+    ] {
+        let set_variables_request = ExecuteRequestBuilder::contract_call_by_hash(
+            caller,
+            nft_contract_hash,
+            ENTRY_POINT_SET_VARIABLES,
+            runtime_args,
+        )
+        .build();
+        builder
+            .exec(set_variables_request)
+            .expect_failure()
+            .commit();
+
+        let error = builder.get_error().expect("should have an error");
+        assert_expected_error(
+            error,
+            NFTCoreError::InvalidAccount as u16,
+            "only a pauser can set variables and only allow_minting",
+        );
+    }
+
+    let set_variables_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! { ARG_ALLOW_MINTING => false },
+    )
+    .build();
+    builder
+        .exec(set_variables_request)
+        .expect_success()
+        .commit();
+
+    let allow_minting: bool =
+        support::query_stored_value(&builder, nft_contract_key, vec![ALLOW_MINTING.to_string()]);
+    assert!(!allow_minting);
+}
@@ -0,0 +1,387 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{runtime_args, Key, RuntimeArgs, U512};
+use contract::constants::{
+    ARG_ACCESS_KEY_NAME_1_0_0, ARG_COLLECTION_NAME, ARG_HASH_KEY_NAME_1_0_0,
+    ARG_NAMED_KEY_CONVENTION, ARG_ROYALTY_BASIS_POINTS, ARG_ROYALTY_RECEIVER, ARG_SALE_PRICE,
+    ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ENTRY_POINT_MINT, ENTRY_POINT_ROYALTY_INFO,
+    PREFIX_ACCESS_KEY_NAME, PREFIX_HASH_KEY_NAME, TOKEN_ROYALTIES,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_USER_1, ACCOUNT_USER_2, ARG_IS_HASH_IDENTIFIER_MODE, ARG_NFT_CONTRACT_HASH,
+        CONTRACT_1_5_0_WASM, MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION,
+        NFT_TEST_SYMBOL, ROYALTY_INFO_SESSION_WASM, TEST_PRETTY_721_META_DATA,
+    },
+    installer_request_builder::{
+        InstallerRequestBuilder, MetadataMutability, NFTIdentifierMode, NFTMetadataKind,
+        NamedKeyConventionMode, OwnerReverseLookupMode, OwnershipMode,
+    },
+    support::{
+        self, assert_expected_error, call_session_code_with_ret, create_funded_dummy_account,
+        get_nft_contract_hash, mint_token,
+    },
+};
+
+fn get_royalty_info(
+    builder: &mut InMemoryWasmTestBuilder,
+    nft_contract_key: Key,
+    token_id: u64,
+    sale_price: U512,
+) -> (Key, U512) {
+    call_session_code_with_ret::<(Key, U512)>(
+        builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_key,
+        runtime_args! {
+            ARG_IS_HASH_IDENTIFIER_MODE => false,
+            ARG_TOKEN_ID => token_id,
+            ARG_SALE_PRICE => sale_price,
+        },
+        ROYALTY_INFO_SESSION_WASM,
+        "royalty_info",
+    )
+}
+
+#[test]
+fn should_default_royalty_to_installer_without_royalty_args() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    mint_token(&mut builder, *DEFAULT_ACCOUNT_ADDR, nft_contract_hash, None)
+        .expect_success()
+        .commit();
+
+    let (royalty_receiver, royalty_amount) =
+        get_royalty_info(&mut builder, nft_contract_key, 0u64, U512::from(1_000u64));
+
+    assert_eq!(royalty_receiver, Key::Account(*DEFAULT_ACCOUNT_ADDR));
+    assert_eq!(royalty_amount, U512::zero());
+}
+
+#[test]
+fn should_return_collection_royalty_info() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let account_user_1 = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_royalty_receiver(Key::Account(account_user_1))
+        .with_royalty_basis_points(250u64)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    mint_token(&mut builder, *DEFAULT_ACCOUNT_ADDR, nft_contract_hash, None)
+        .expect_success()
+        .commit();
+
+    let (royalty_receiver, royalty_amount) =
+        get_royalty_info(&mut builder, nft_contract_key, 0u64, U512::from(1_000u64));
+
+    assert_eq!(royalty_receiver, Key::Account(account_user_1));
+    assert_eq!(royalty_amount, U512::from(25u64));
+
+    // Amounts are rounded down and do not overflow on the largest prices.
+    let (_, royalty_amount) = get_royalty_info(&mut builder, nft_contract_key, 0u64, U512::MAX);
+    assert_eq!(royalty_amount, U512::MAX / 40);
+}
+
+#[test]
+fn should_return_royalty_info_set_at_mint() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let account_user_1 = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let account_user_2 = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_royalty_receiver(Key::Account(account_user_1))
+        .with_royalty_basis_points(250u64)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    // The receiver is overridden while the basis points fall back on the collection default.
+    let mint_call = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_ROYALTY_RECEIVER => Key::Account(account_user_2),
+        },
+    )
+    .build();
+
+    builder.exec(mint_call).expect_success().commit();
+
+    let mint_call = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_ROYALTY_BASIS_POINTS => 1_000u64,
+        },
+    )
+    .build();
+
+    builder.exec(mint_call).expect_success().commit();
+
+    let token_royalty = support::get_dictionary_value_from_key::<(Key, u64)>(
+        &builder,
+        &nft_contract_key,
+        TOKEN_ROYALTIES,
+        "0",
+    );
+    assert_eq!(token_royalty, (Key::Account(account_user_2), 250u64));
+
+    let (royalty_receiver, royalty_amount) =
+        get_royalty_info(&mut builder, nft_contract_key, 0u64, U512::from(1_000u64));
+    assert_eq!(royalty_receiver, Key::Account(account_user_2));
+    assert_eq!(royalty_amount, U512::from(25u64));
+
+    let (royalty_receiver, royalty_amount) =
+        get_royalty_info(&mut builder, nft_contract_key, 1u64, U512::from(1_000u64));
+    assert_eq!(royalty_receiver, Key::Account(account_user_1));
+    assert_eq!(royalty_amount, U512::from(100u64));
+}
+
+#[test]
+fn should_not_install_with_royalty_basis_points_above_maximum() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_royalty_basis_points(10_001u64)
+        .build();
+
+    builder.exec(install_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        176u16,
+        "royalty basis points cannot exceed the whole sale price",
+    );
+}
+
+#[test]
+fn should_not_mint_with_royalty_basis_points_above_maximum() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    let mint_call = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_ROYALTY_BASIS_POINTS => 10_001u64,
+        },
+    )
+    .build();
+
+    builder.exec(mint_call).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        176u16,
+        "royalty basis points cannot exceed the whole sale price",
+    );
+}
+
+#[test]
+fn should_not_return_royalty_info_for_non_existing_token() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    let royalty_info_call = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_ROYALTY_INFO,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SALE_PRICE => U512::from(1_000u64),
+        },
+    )
+    .build();
+
+    builder.exec(royalty_info_call).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        155u16,
+        "should not return royalty info for a token that was never minted",
+    );
+}
+
+fn install_1_5_0_with_minted_token(builder: &mut InMemoryWasmTestBuilder) -> Key {
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, CONTRACT_1_5_0_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key_1_5_0: Key = get_nft_contract_hash(builder).into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key_1_5_0,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => "",
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    nft_contract_key_1_5_0
+}
+
+fn upgrade_request_with_royalty(
+    nft_contract_key_1_5_0: Key,
+    royalty_receiver: Key,
+    royalty_basis_points: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        NFT_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key_1_5_0,
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+            ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
+            ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+            ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+            ARG_ROYALTY_RECEIVER => royalty_receiver,
+            ARG_ROYALTY_BASIS_POINTS => royalty_basis_points,
+        },
+    )
+    .build()
+}
+
+#[test]
+fn should_set_collection_royalty_on_upgrade() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let (_, account_user_1) = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let nft_contract_key_1_5_0 = install_1_5_0_with_minted_token(&mut builder);
+
+    let upgrade_request =
+        upgrade_request_with_royalty(nft_contract_key_1_5_0, Key::Account(account_user_1), 500u64);
+
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let nft_contract_key: Key = get_nft_contract_hash(&builder).into();
+
+    let (royalty_receiver, royalty_amount) =
+        get_royalty_info(&mut builder, nft_contract_key, 0u64, U512::from(1_000u64));
+
+    assert_eq!(royalty_receiver, Key::Account(account_user_1));
+    assert_eq!(royalty_amount, U512::from(50u64));
+}
+
+#[test]
+fn should_not_upgrade_with_royalty_basis_points_above_maximum() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let nft_contract_key_1_5_0 = install_1_5_0_with_minted_token(&mut builder);
+
+    let upgrade_request = upgrade_request_with_royalty(
+        nft_contract_key_1_5_0,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        10_001u64,
+    );
+
+    builder.exec(upgrade_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        176u16,
+        "royalty basis points cannot exceed the whole sale price",
+    );
+}
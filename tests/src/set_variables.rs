@@ -5,17 +5,20 @@ use casper_engine_test_support::{
 use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs};
 use contract::{
     constants::{
-        ACL_PACKAGE_MODE, ALLOW_MINTING, ARG_ACL_PACKAGE_MODE, ARG_ALLOW_MINTING,
-        ARG_OPERATOR_BURN_MODE, ARG_PACKAGE_OPERATOR_MODE, ENTRY_POINT_SET_VARIABLES,
-        OPERATOR_BURN_MODE, PACKAGE_OPERATOR_MODE,
+        ACL_PACKAGE_MODE, ALLOW_MINTING, ARG_ACL_PACKAGE_MODE, ARG_ADMIN, ARG_ALLOW_MINTING,
+        ARG_OPERATOR_BURN_MODE, ARG_PACKAGE_OPERATOR_MODE, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER,
+        ENTRY_POINT_ACCEPT_ADMIN, ENTRY_POINT_MINT, ENTRY_POINT_SET_VARIABLES,
+        ENTRY_POINT_TRANSFER_ADMIN, INSTALLER, OPERATOR_BURN_MODE, PACKAGE_OPERATOR_MODE,
+        PENDING_ADMIN,
     },
     error::NFTCoreError,
-    events::events_ces::VariablesSet,
+    events::events_ces::{AdminTransferStarted, AdminTransferred, VariablesSet},
 };
 
 use crate::utility::{
     constants::{
-        ACCOUNT_USER_1, CONTRACT_NAME, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, NFT_TEST_SYMBOL,
+        ACCOUNT_USER_1, ACCOUNT_USER_2, CONTRACT_NAME, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION,
+        NFT_TEST_SYMBOL, TEST_PRETTY_721_META_DATA,
     },
     installer_request_builder::{InstallerRequestBuilder, OwnerReverseLookupMode},
     support::{self, assert_expected_error, get_nft_contract_hash},
@@ -284,3 +287,241 @@ fn installer_should_be_able_to_toggle_operator_burn_mode() {
     let actual_event: VariablesSet = support::get_event(&builder, &nft_contract_key, 0).unwrap();
     assert_eq!(actual_event, expected_event, "Expected VariablesSet event.");
 }
+
+#[test]
+fn should_transfer_admin_in_two_steps() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let new_admin_account =
+        support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let other_user_account =
+        support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(1u64)
+        .with_allowing_minting(false)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    // Only the current admin can start the transfer.
+    let other_user_transfer_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        other_user_account,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER_ADMIN,
+        runtime_args! { ARG_ADMIN => Key::Account(other_user_account) },
+    )
+    .build();
+    builder
+        .exec(other_user_transfer_admin_request)
+        .expect_failure()
+        .commit();
+
+    let error = builder.get_error().expect("should have an error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidAccount as u16,
+        "Invalid Account to transfer admin",
+    );
+
+    let transfer_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER_ADMIN,
+        runtime_args! { ARG_ADMIN => Key::Account(new_admin_account) },
+    )
+    .build();
+    builder
+        .exec(transfer_admin_request)
+        .expect_success()
+        .commit();
+
+    let pending_admin: Option<Key> =
+        support::query_stored_value(&builder, nft_contract_key, vec![PENDING_ADMIN.to_string()]);
+    assert_eq!(pending_admin, Some(Key::Account(new_admin_account)));
+
+    // Only the pending admin can accept the transfer.
+    let other_user_accept_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        other_user_account,
+        nft_contract_hash,
+        ENTRY_POINT_ACCEPT_ADMIN,
+        runtime_args! {},
+    )
+    .build();
+    builder
+        .exec(other_user_accept_admin_request)
+        .expect_failure()
+        .commit();
+
+    let error = builder.get_error().expect("should have an error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidAccount as u16,
+        "Invalid Account to accept admin",
+    );
+
+    let accept_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        new_admin_account,
+        nft_contract_hash,
+        ENTRY_POINT_ACCEPT_ADMIN,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(accept_admin_request).expect_success().commit();
+
+    let admin = *builder
+        .get_contract(nft_contract_hash)
+        .expect("should have nft contract")
+        .named_keys()
+        .get(INSTALLER)
+        .expect("should have installer named key");
+    assert_eq!(admin, Key::Account(new_admin_account));
+
+    let pending_admin: Option<Key> =
+        support::query_stored_value(&builder, nft_contract_key, vec![PENDING_ADMIN.to_string()]);
+    assert_eq!(pending_admin, None);
+
+    // Expect AdminTransferStarted and AdminTransferred events.
+    let expected_event = AdminTransferStarted::new(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Key::Account(new_admin_account),
+    );
+    let actual_event: AdminTransferStarted =
+        support::get_event(&builder, &nft_contract_key, 0).unwrap();
+    assert_eq!(
+        actual_event, expected_event,
+        "Expected AdminTransferStarted event."
+    );
+
+    let expected_event = AdminTransferred::new(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Key::Account(new_admin_account),
+    );
+    let actual_event: AdminTransferred =
+        support::get_event(&builder, &nft_contract_key, 1).unwrap();
+    assert_eq!(
+        actual_event, expected_event,
+        "Expected AdminTransferred event."
+    );
+
+    // The previous admin lost its rights to set variables.
+    let previous_admin_set_variables_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! { ARG_ALLOW_MINTING => true },
+    )
+    .build();
+    builder
+        .exec(previous_admin_set_variables_request)
+        .expect_failure()
+        .commit();
+
+    let error = builder.get_error().expect("should have an error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidAccount as u16,
+        "Invalid Account to set variables",
+    );
+
+    let new_admin_set_variables_request = ExecuteRequestBuilder::contract_call_by_hash(
+        new_admin_account,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! { ARG_ALLOW_MINTING => true },
+    )
+    .build();
+    builder
+        .exec(new_admin_set_variables_request)
+        .expect_success()
+        .commit();
+
+    let allow_minting: bool =
+        support::query_stored_value(&builder, nft_contract_key, vec![ALLOW_MINTING.to_string()]);
+    assert!(allow_minting);
+}
+
+#[test]
+fn should_only_allow_new_admin_to_mint_in_installer_minting_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let new_admin_account =
+        support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    let transfer_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER_ADMIN,
+        runtime_args! { ARG_ADMIN => Key::Account(new_admin_account) },
+    )
+    .build();
+    builder
+        .exec(transfer_admin_request)
+        .expect_success()
+        .commit();
+
+    let accept_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        new_admin_account,
+        nft_contract_hash,
+        ENTRY_POINT_ACCEPT_ADMIN,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(accept_admin_request).expect_success().commit();
+
+    let previous_admin_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA,
+        },
+    )
+    .build();
+    builder
+        .exec(previous_admin_mint_request)
+        .expect_failure()
+        .commit();
+
+    let error = builder.get_error().expect("should have an error");
+    assert_expected_error(
+        error,
+        NFTCoreError::InvalidMinter as u16,
+        "previous admin should not mint in installer minting mode",
+    );
+
+    let new_admin_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        new_admin_account,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(new_admin_account),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA,
+        },
+    )
+    .build();
+    builder
+        .exec(new_admin_mint_request)
+        .expect_success()
+        .commit();
+}
@@ -0,0 +1,201 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs};
+use contract::constants::{ARG_BASE_URI, ARG_TOKEN_ID, ENTRY_POINT_SET_BASE_URI};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_USER_1, ARG_IS_HASH_IDENTIFIER_MODE, ARG_KEY_NAME, ARG_NFT_CONTRACT_HASH,
+        NFT_CONTRACT_WASM, TOKEN_URI_SESSION_WASM,
+    },
+    installer_request_builder::{InstallerRequestBuilder, OwnerReverseLookupMode},
+    support::{
+        assert_expected_error, call_session_code_with_ret, create_funded_dummy_account,
+        get_nft_contract_hash, mint_token,
+    },
+};
+
+fn install_with_base_uri(
+    builder: &mut InMemoryWasmTestBuilder,
+    base_uri: Option<&str>,
+) -> ContractHash {
+    let mut install_request_builder =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(10u64)
+            .with_reporting_mode(OwnerReverseLookupMode::NoLookUp);
+    if let Some(base_uri) = base_uri {
+        install_request_builder = install_request_builder.with_base_uri(base_uri);
+    }
+
+    builder
+        .exec(install_request_builder.build())
+        .expect_success()
+        .commit();
+
+    get_nft_contract_hash(builder)
+}
+
+fn get_token_uri(
+    builder: &mut InMemoryWasmTestBuilder,
+    nft_contract_hash: ContractHash,
+    token_id: u64,
+) -> String {
+    call_session_code_with_ret::<String>(
+        builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash.into(),
+        runtime_args! {
+            ARG_IS_HASH_IDENTIFIER_MODE => false,
+            ARG_TOKEN_ID => token_id,
+        },
+        TOKEN_URI_SESSION_WASM,
+        "token_uri",
+    )
+}
+
+#[test]
+fn should_append_token_id_to_base_uri() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let nft_contract_hash = install_with_base_uri(&mut builder, Some("ipfs://collection/"));
+    mint_token(&mut builder, *DEFAULT_ACCOUNT_ADDR, nft_contract_hash, None)
+        .expect_success()
+        .commit();
+    mint_token(&mut builder, *DEFAULT_ACCOUNT_ADDR, nft_contract_hash, None)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        get_token_uri(&mut builder, nft_contract_hash, 0u64),
+        "ipfs://collection/0"
+    );
+    assert_eq!(
+        get_token_uri(&mut builder, nft_contract_hash, 1u64),
+        "ipfs://collection/1"
+    );
+}
+
+#[test]
+fn should_replace_id_placeholder_of_base_uri() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let nft_contract_hash =
+        install_with_base_uri(&mut builder, Some("https://nft.example/{id}/meta.json"));
+    mint_token(&mut builder, *DEFAULT_ACCOUNT_ADDR, nft_contract_hash, None)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        get_token_uri(&mut builder, nft_contract_hash, 0u64),
+        "https://nft.example/0/meta.json"
+    );
+}
+
+#[test]
+fn should_allow_installer_to_set_base_uri() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let account_user_1 = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let nft_contract_hash = install_with_base_uri(&mut builder, Some("ipfs://first/"));
+    mint_token(&mut builder, *DEFAULT_ACCOUNT_ADDR, nft_contract_hash, None)
+        .expect_success()
+        .commit();
+
+    let unauthorized_set_base_uri = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1,
+        nft_contract_hash,
+        ENTRY_POINT_SET_BASE_URI,
+        runtime_args! {
+            ARG_BASE_URI => "ipfs://other/".to_string(),
+        },
+    )
+    .build();
+    builder.exec(unauthorized_set_base_uri).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 1u16, "only the installer can set the base uri");
+
+    let set_base_uri = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_BASE_URI,
+        runtime_args! {
+            ARG_BASE_URI => "ipfs://second/".to_string(),
+        },
+    )
+    .build();
+    builder.exec(set_base_uri).expect_success().commit();
+
+    assert_eq!(
+        get_token_uri(&mut builder, nft_contract_hash, 0u64),
+        "ipfs://second/0"
+    );
+}
+
+#[test]
+fn should_not_return_token_uri_without_base_uri_or_token() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let nft_contract_hash = install_with_base_uri(&mut builder, None);
+    mint_token(&mut builder, *DEFAULT_ACCOUNT_ADDR, nft_contract_hash, None)
+        .expect_success()
+        .commit();
+
+    let token_uri_call = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        TOKEN_URI_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => Key::from(nft_contract_hash),
+            ARG_KEY_NAME => "token_uri".to_string(),
+            ARG_IS_HASH_IDENTIFIER_MODE => false,
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+    builder.exec(token_uri_call).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 191u16, "token uri requires a base uri");
+
+    let set_base_uri = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_BASE_URI,
+        runtime_args! {
+            ARG_BASE_URI => "ipfs://collection/".to_string(),
+        },
+    )
+    .build();
+    builder.exec(set_base_uri).expect_success().commit();
+
+    let missing_token_uri_call = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        TOKEN_URI_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => Key::from(nft_contract_hash),
+            ARG_KEY_NAME => "token_uri".to_string(),
+            ARG_IS_HASH_IDENTIFIER_MODE => false,
+            ARG_TOKEN_ID => 1u64,
+        },
+    )
+    .build();
+    builder.exec(missing_token_uri_call).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 155u16, "token uri requires an existing token");
+}
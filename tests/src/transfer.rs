@@ -4,26 +4,33 @@ use casper_engine_test_support::{
 };
 use casper_execution_engine::storage::global_state::in_memory::InMemoryGlobalState;
 use casper_types::{
-    account::AccountHash, runtime_args, Key, PublicKey, RuntimeArgs, SecretKey, U512,
+    account::AccountHash,
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, ContractHash, Key, PublicKey, RuntimeArgs, SecretKey, U512,
 };
 use contract::{
     constants::{
-        ACL_WHITELIST, APPROVED, ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_OPERATOR,
-        ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID,
-        ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ENTRY_POINT_APPROVE, ENTRY_POINT_MINT,
-        ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_REVOKE, ENTRY_POINT_SET_APPROVALL_FOR_ALL,
-        ENTRY_POINT_TRANSFER, PAGE_TABLE, TOKEN_COUNT, TOKEN_OWNERS,
+        ACL_WHITELIST, APPROVAL_EXPIRIES, APPROVED_SPENDERS, ARG_APPROVE_ALL, ARG_COLLECTION_NAME,
+        ARG_DATA, ARG_EXPIRES_AT, ARG_NON_TRANSFERABLE, ARG_OPERATOR, ARG_SOURCE_KEY, ARG_SPENDER,
+        ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_IDS, ARG_TOKEN_META_DATA,
+        ARG_TOKEN_META_DATAS, ARG_TOKEN_OWNER, ARG_TOKEN_OWNERS, ENTRY_POINT_APPROVE,
+        ENTRY_POINT_APPROVE_BATCH, ENTRY_POINT_BURN, ENTRY_POINT_MINT, ENTRY_POINT_MINT_BATCH,
+        ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_REVOKE, ENTRY_POINT_SAFE_TRANSFER,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL, ENTRY_POINT_TRANSFER, ENTRY_POINT_TRANSFER_BATCH,
+        FILTER_CONTRACT_DENY_REASON_OFFSET, NON_TRANSFERABLE_TOKENS, PAGE_TABLE,
+        RECEIVER_CONTRACT_ACKNOWLEDGMENT, TOKEN_COUNT, TOKEN_OWNERS,
     },
     events::events_ces::{Approval, ApprovalRevoked, Transfer},
-    modalities::{TokenIdentifier, TransferFilterContractResult},
+    modalities::{TokenIdentifier, TransferFilterContractResult, TransferFilterContractVersion},
 };
 
 use crate::utility::{
     constants::{
-        ACCOUNT_USER_1, ACCOUNT_USER_2, ACCOUNT_USER_3, ARG_FILTER_CONTRACT_RETURN_VALUE,
-        ARG_IS_HASH_IDENTIFIER_MODE, ARG_NFT_CONTRACT_HASH, ARG_REVERSE_LOOKUP,
-        MINTING_CONTRACT_WASM, MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION,
-        NFT_TEST_SYMBOL, TEST_PRETTY_721_META_DATA, TRANSFER_FILTER_CONTRACT_WASM,
+        ACCOUNT_USER_1, ACCOUNT_USER_2, ACCOUNT_USER_3, ARG_ACKNOWLEDGMENT,
+        ARG_FILTER_CONTRACT_RETURN_VALUE, ARG_IS_HASH_IDENTIFIER_MODE, ARG_KEY_NAME,
+        ARG_NFT_CONTRACT_HASH, ARG_REVERSE_LOOKUP, GET_APPROVED_WASM, MINTING_CONTRACT_WASM,
+        MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, NFT_TEST_SYMBOL,
+        RECEIVER_CONTRACT_WASM, TEST_PRETTY_721_META_DATA, TRANSFER_FILTER_CONTRACT_WASM,
         TRANSFER_SESSION_WASM,
     },
     installer_request_builder::{
@@ -33,7 +40,7 @@ use crate::utility::{
     support::{
         self, assert_expected_error, create_funded_dummy_account, get_dictionary_value_from_key,
         get_minting_contract_hash, get_minting_contract_package_hash, get_nft_contract_hash,
-        get_transfer_filter_contract_hash,
+        get_receiver_contract_hash, get_transfer_filter_contract_hash,
     },
 };
 
@@ -315,14 +322,14 @@ fn approve_token_for_transfer_should_add_entry_to_approved_dictionary(
     let nft_contract_hash = get_nft_contract_hash(&builder);
     let nft_contract_key: Key = nft_contract_hash.into();
 
-    let actual_approved_key: Option<Key> = support::get_dictionary_value_from_key(
+    let actual_approved_key: Vec<Key> = support::get_dictionary_value_from_key(
         &builder,
         &nft_contract_key,
-        APPROVED,
+        APPROVED_SPENDERS,
         &token_id.to_string(),
     );
 
-    assert_eq!(actual_approved_key, Some(spender_key));
+    assert_eq!(actual_approved_key, vec![spender_key]);
 
     // Expect Approval event.
     let expected_event = Approval::new(owner_key, spender_key, TokenIdentifier::Index(token_id));
@@ -419,14 +426,14 @@ fn revoke_token_for_transfer_should_remove_entry_to_approved_dictionary(
 
     let nft_contract_key: Key = nft_contract_hash.into();
 
-    let actual_approved_key: Option<Key> = support::get_dictionary_value_from_key(
+    let actual_approved_key: Vec<Key> = support::get_dictionary_value_from_key(
         &builder,
         &nft_contract_key,
-        APPROVED,
+        APPROVED_SPENDERS,
         &token_id.to_string(),
     );
 
-    assert_eq!(actual_approved_key, Some(spender_key));
+    assert_eq!(actual_approved_key, vec![spender_key]);
 
     let revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
         *DEFAULT_ACCOUNT_ADDR,
@@ -439,14 +446,14 @@ fn revoke_token_for_transfer_should_remove_entry_to_approved_dictionary(
     .build();
     builder.exec(revoke_request).expect_success().commit();
 
-    let actual_approved_key: Option<Key> = support::get_dictionary_value_from_key(
+    let actual_approved_key: Vec<Key> = support::get_dictionary_value_from_key(
         &builder,
         &nft_contract_key,
-        APPROVED,
+        APPROVED_SPENDERS,
         &token_id.to_string(),
     );
 
-    assert_eq!(actual_approved_key, None);
+    assert_eq!(actual_approved_key, Vec::<Key>::new());
 
     // Expect ApprovalRevoked event.
     let expected_event = ApprovalRevoked::new(owner_key, TokenIdentifier::Index(token_id));
@@ -605,10 +612,14 @@ fn should_be_able_to_transfer_token(
     let nft_contract_hash = get_nft_contract_hash(&builder);
     let nft_contract_key: Key = nft_contract_hash.into();
 
-    let actual_approved_account: Option<Key> =
-        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED, &token_id.to_string());
+    let actual_approved_account: Vec<Key> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVED_SPENDERS,
+        &token_id.to_string(),
+    );
 
-    let expected_approved_account = Some(spender_key);
+    let expected_approved_account = vec![spender_key];
     assert_eq!(
         actual_approved_account, expected_approved_account,
         "approved account should have been set in dictionary when approved"
@@ -646,12 +657,17 @@ fn should_be_able_to_transfer_token(
     .build();
     builder.exec(transfer_request).expect_success().commit();
 
-    let actual_approved_account_hash: Option<Key> =
-        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED, &token_id.to_string());
+    let actual_approved_account_hash: Vec<Key> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVED_SPENDERS,
+        &token_id.to_string(),
+    );
 
     assert_eq!(
-        actual_approved_account_hash, None,
-        "approved account should be set to none after a transfer"
+        actual_approved_account_hash,
+        Vec::<Key>::new(),
+        "approved accounts should be cleared after a transfer"
     );
 }
 
@@ -729,10 +745,14 @@ fn should_dissallow_same_approved_account_to_transfer_token_twice() {
     let nft_contract_hash = get_nft_contract_hash(&builder);
     let nft_contract_key: Key = nft_contract_hash.into();
 
-    let actual_approved_account: Option<Key> =
-        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED, &token_id.to_string());
+    let actual_approved_account: Vec<Key> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVED_SPENDERS,
+        &token_id.to_string(),
+    );
 
-    let expected_approved_account = Some(spender_key);
+    let expected_approved_account = vec![spender_key];
 
     assert_eq!(
         actual_approved_account, expected_approved_account,
@@ -876,10 +896,14 @@ fn should_disallow_to_transfer_token_using_revoked_hash(
     let nft_contract_hash = get_nft_contract_hash(&builder);
     let nft_contract_key: Key = nft_contract_hash.into();
 
-    let actual_approved_account: Option<Key> =
-        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED, &token_id.to_string());
+    let actual_approved_account: Vec<Key> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVED_SPENDERS,
+        &token_id.to_string(),
+    );
 
-    let expected_approved_account = Some(spender_key);
+    let expected_approved_account = vec![spender_key];
     assert_eq!(
         actual_approved_account, expected_approved_account,
         "approved account should have been set in dictionary when approved"
@@ -938,12 +962,17 @@ fn should_disallow_to_transfer_token_using_revoked_hash(
         "transfer from revoked account must raise InvalidTokenOwner",
     );
 
-    let actual_approved_account_hash: Option<Key> =
-        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED, &token_id.to_string());
+    let actual_approved_account_hash: Vec<Key> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVED_SPENDERS,
+        &token_id.to_string(),
+    );
 
     assert_eq!(
-        actual_approved_account_hash, None,
-        "approved account should be unset after revoke and a failed transfer"
+        actual_approved_account_hash,
+        Vec::<Key>::new(),
+        "approved accounts should be unset after revoke and a failed transfer"
     );
 }
 
@@ -1020,10 +1049,14 @@ fn should_be_able_to_approve_with_deprecated_operator_argument() {
     builder.exec(approve_request).expect_success().commit();
 
     let nft_contract_key: Key = get_nft_contract_hash(&builder).into();
-    let actual_approved_account: Option<Key> =
-        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED, &token_id.to_string());
+    let actual_approved_account: Vec<Key> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVED_SPENDERS,
+        &token_id.to_string(),
+    );
 
-    let expected_approved_account = Some(spender_key);
+    let expected_approved_account = vec![spender_key];
     assert_eq!(
         actual_approved_account, expected_approved_account,
         "approved account should have been set in dictionary when approved"
@@ -1482,7 +1515,7 @@ fn transfer_should_correctly_track_page_table_entries() {
 
     builder.exec(transfer_request).expect_success().commit();
 
-    let account_user_1_page_table = support::get_dictionary_value_from_key::<Vec<bool>>(
+    let account_user_1_page_table = support::get_page_record_from_key(
         &builder,
         &nft_contract_key,
         PAGE_TABLE,
@@ -2033,6 +2066,150 @@ fn check_transfers_with_transfer_filter_contract_modes() {
     builder.exec(transfer_request).expect_success().commit();
 }
 
+#[test]
+fn should_pass_transfer_context_to_v2_transfer_filter_contract() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let transfer_filter_contract_install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        TRANSFER_FILTER_CONTRACT_WASM,
+        runtime_args! {},
+    )
+    .build();
+
+    builder
+        .exec(transfer_filter_contract_install_request)
+        .expect_success()
+        .commit();
+
+    let transfer_filter_contract_hash = get_transfer_filter_contract_hash(&builder);
+
+    // A v2 filter answers a deny reason, 0 letting the transfer proceed.
+    let deny_reason = 7u8;
+    let transfer_filter_contract_set_return_value_request =
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            transfer_filter_contract_hash,
+            "set_return_value",
+            runtime_args! {
+                ARG_FILTER_CONTRACT_RETURN_VALUE => deny_reason
+            },
+        )
+        .build();
+
+    builder
+        .exec(transfer_filter_contract_set_return_value_request)
+        .expect_success()
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_transfer_filter_contract(Key::from(transfer_filter_contract_hash))
+        .with_transfer_filter_contract_version(TransferFilterContractVersion::V2)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let token_receiver = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let token_receiver_key = Key::Account(token_receiver);
+    let data = Bytes::from(vec![1u8, 2, 3]);
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => token_receiver_key,
+            ARG_DATA => data.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        FILTER_CONTRACT_DENY_REASON_OFFSET + u16::from(deny_reason),
+        "the deny reason of the filter must be surfaced in the revert",
+    );
+
+    let transfer_filter_contract_set_return_value_request =
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            transfer_filter_contract_hash,
+            "set_return_value",
+            runtime_args! {
+                ARG_FILTER_CONTRACT_RETURN_VALUE => 0u8
+            },
+        )
+        .build();
+
+    builder
+        .exec(transfer_filter_contract_set_return_value_request)
+        .expect_success()
+        .commit();
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => token_receiver_key,
+            ARG_DATA => data.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    let transfer_filter_contract_key = Key::from(transfer_filter_contract_hash);
+    let last_target_key: Key = support::query_stored_value(
+        &builder,
+        transfer_filter_contract_key,
+        vec!["last_target_key".to_string()],
+    );
+    let last_caller: Key = support::query_stored_value(
+        &builder,
+        transfer_filter_contract_key,
+        vec!["last_caller".to_string()],
+    );
+    let last_data: Bytes = support::query_stored_value(
+        &builder,
+        transfer_filter_contract_key,
+        vec!["last_data".to_string()],
+    );
+
+    assert_eq!(last_target_key, token_receiver_key);
+    assert_eq!(last_caller, token_owner_key);
+    assert_eq!(last_data, data);
+}
+
 #[test]
 fn should_disallow_transfer_from_contract_with_package_operator_mode_without_operator() {
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -2886,3 +3063,903 @@ fn should_allow_package_operator_to_revoke_with_package_operator_mode() {
 
     assert_eq!(actual_token_owner, *DEFAULT_ACCOUNT_ADDR);
 }
+
+fn should_transfer_batch(reporting: OwnerReverseLookupMode) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(reporting)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let source_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let target_key = Key::Account(AccountHash::new(ACCOUNT_USER_1));
+
+    for owner_key in [source_key, target_key] {
+        let register_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_REGISTER_OWNER,
+            runtime_args! {
+                ARG_TOKEN_OWNER => owner_key
+            },
+        )
+        .build();
+        builder.exec(register_request).expect_success().commit();
+    }
+
+    let mint_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT_BATCH,
+        runtime_args! {
+            ARG_TOKEN_META_DATAS => vec![String::new(); 3],
+            ARG_TOKEN_OWNERS => vec![source_key; 3],
+        },
+    )
+    .build();
+    builder.exec(mint_batch_request).expect_success().commit();
+
+    let transfer_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER_BATCH,
+        runtime_args! {
+            ARG_TOKEN_IDS => vec![0u64, 2u64],
+            ARG_SOURCE_KEY => source_key,
+            ARG_TARGET_KEY => target_key,
+        },
+    )
+    .build();
+    builder
+        .exec(transfer_batch_request)
+        .expect_success()
+        .commit();
+
+    for (token_id, expected_owner) in [(0u64, target_key), (1, source_key), (2, target_key)] {
+        let actual_owner = get_dictionary_value_from_key::<Key>(
+            &builder,
+            &nft_contract_key,
+            TOKEN_OWNERS,
+            &token_id.to_string(),
+        );
+        assert_eq!(actual_owner, expected_owner);
+    }
+
+    let source_balance = get_dictionary_value_from_key::<u64>(
+        &builder,
+        &nft_contract_key,
+        TOKEN_COUNT,
+        &DEFAULT_ACCOUNT_ADDR.to_string(),
+    );
+    assert_eq!(source_balance, 1u64);
+
+    let target_balance = get_dictionary_value_from_key::<u64>(
+        &builder,
+        &nft_contract_key,
+        TOKEN_COUNT,
+        &AccountHash::new(ACCOUNT_USER_1).to_string(),
+    );
+    assert_eq!(target_balance, 2u64);
+
+    let target_page = support::get_token_page_by_id(&builder, &nft_contract_key, &target_key, 0u64);
+    assert!(target_page[0] && !target_page[1] && target_page[2]);
+
+    let source_page = support::get_token_page_by_id(&builder, &nft_contract_key, &source_key, 0u64);
+    assert!(!source_page[0] && !source_page[2]);
+}
+
+#[test]
+fn should_transfer_batch_with_complete_reporting() {
+    should_transfer_batch(OwnerReverseLookupMode::Complete)
+}
+
+#[test]
+fn should_transfer_batch_with_transfer_only_reporting() {
+    should_transfer_batch(OwnerReverseLookupMode::TransfersOnly)
+}
+
+#[test]
+fn should_not_transfer_batch_with_token_of_another_owner() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let source_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let other_key = Key::Account(AccountHash::new(ACCOUNT_USER_1));
+
+    let mint_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT_BATCH,
+        runtime_args! {
+            ARG_TOKEN_META_DATAS => vec![String::new(); 2],
+            ARG_TOKEN_OWNERS => vec![source_key, other_key],
+        },
+    )
+    .build();
+    builder.exec(mint_batch_request).expect_success().commit();
+
+    let transfer_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER_BATCH,
+        runtime_args! {
+            ARG_TOKEN_IDS => vec![0u64, 1u64],
+            ARG_SOURCE_KEY => source_key,
+            ARG_TARGET_KEY => Key::Account(AccountHash::new(ACCOUNT_USER_2)),
+        },
+    )
+    .build();
+    builder.exec(transfer_batch_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        1u16,
+        "should not transfer a batch containing a token of another owner",
+    );
+}
+
+#[test]
+fn should_not_transfer_batch_to_source_owner() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let source_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let register_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REGISTER_OWNER,
+        runtime_args! {
+            ARG_TOKEN_OWNER => source_key
+        },
+    )
+    .build();
+    builder.exec(register_request).expect_success().commit();
+
+    let mint_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT_BATCH,
+        runtime_args! {
+            ARG_TOKEN_META_DATAS => vec![String::new(); 2],
+            ARG_TOKEN_OWNERS => vec![source_key; 2],
+        },
+    )
+    .build();
+    builder.exec(mint_batch_request).expect_success().commit();
+
+    let transfer_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER_BATCH,
+        runtime_args! {
+            ARG_TOKEN_IDS => vec![0u64, 1u64],
+            ARG_SOURCE_KEY => source_key,
+            ARG_TARGET_KEY => source_key,
+        },
+    )
+    .build();
+    builder.exec(transfer_batch_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        1u16,
+        "should not transfer a batch to its source owner",
+    );
+}
+
+#[test]
+fn should_approve_batch() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let spender_key = Key::Account(AccountHash::new(ACCOUNT_USER_1));
+
+    let mint_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT_BATCH,
+        runtime_args! {
+            ARG_TOKEN_META_DATAS => vec![String::new(); 3],
+            ARG_TOKEN_OWNERS => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR); 3],
+        },
+    )
+    .build();
+    builder.exec(mint_batch_request).expect_success().commit();
+
+    let approve_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_APPROVE_BATCH,
+        runtime_args! {
+            ARG_TOKEN_IDS => vec![0u64, 2u64],
+            ARG_SPENDER => spender_key,
+        },
+    )
+    .build();
+    builder
+        .exec(approve_batch_request)
+        .expect_success()
+        .commit();
+
+    for token_id in [0u64, 2u64] {
+        let actual_approved = get_dictionary_value_from_key::<Vec<Key>>(
+            &builder,
+            &nft_contract_key,
+            APPROVED_SPENDERS,
+            &token_id.to_string(),
+        );
+        assert_eq!(actual_approved, vec![spender_key]);
+    }
+}
+
+#[test]
+fn should_not_transfer_non_transferable_token() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let source_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let target_key = Key::Account(AccountHash::new(ACCOUNT_USER_1));
+
+    for non_transferable in [true, false] {
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_MINT,
+            runtime_args! {
+                ARG_TOKEN_OWNER => source_key,
+                ARG_TOKEN_META_DATA => "",
+                ARG_NON_TRANSFERABLE => non_transferable,
+            },
+        )
+        .build();
+        builder.exec(mint_request).expect_success().commit();
+    }
+
+    // The flag is stored against the token in its own dictionary.
+    support::get_dictionary_value_from_key::<()>(
+        &builder,
+        &nft_contract_key,
+        NON_TRANSFERABLE_TOKENS,
+        "0",
+    );
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SOURCE_KEY => source_key,
+            ARG_TARGET_KEY => target_key,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        180u16,
+        "should not transfer a token flagged as non transferable",
+    );
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+            ARG_SOURCE_KEY => source_key,
+            ARG_TARGET_KEY => target_key,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    let actual_owner = support::get_dictionary_value_from_key::<Key>(
+        &builder,
+        &nft_contract_key,
+        TOKEN_OWNERS,
+        "1",
+    );
+    assert_eq!(actual_owner, target_key);
+}
+
+#[test]
+fn should_not_transfer_batch_with_non_transferable_token() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let source_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let mint_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT_BATCH,
+        runtime_args! {
+            ARG_TOKEN_META_DATAS => vec![String::new(); 2],
+            ARG_TOKEN_OWNERS => vec![source_key; 2],
+            ARG_NON_TRANSFERABLE => true,
+        },
+    )
+    .build();
+    builder.exec(mint_batch_request).expect_success().commit();
+
+    let transfer_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER_BATCH,
+        runtime_args! {
+            ARG_TOKEN_IDS => vec![0u64, 1u64],
+            ARG_SOURCE_KEY => source_key,
+            ARG_TARGET_KEY => Key::Account(AccountHash::new(ACCOUNT_USER_1)),
+        },
+    )
+    .build();
+    builder.exec(transfer_batch_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        180u16,
+        "should not transfer a batch containing a non transferable token",
+    );
+}
+
+fn install_and_mint_two_transferable_tokens(builder: &mut InMemoryWasmTestBuilder) -> ContractHash {
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(builder);
+    let source_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let mint_batch_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT_BATCH,
+        runtime_args! {
+            ARG_TOKEN_META_DATAS => vec![String::new(); 2],
+            ARG_TOKEN_OWNERS => vec![source_key; 2],
+        },
+    )
+    .build();
+    builder.exec(mint_batch_request).expect_success().commit();
+
+    nft_contract_hash
+}
+
+fn transfer_token_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    caller: AccountHash,
+    nft_contract_hash: ContractHash,
+    token_id: u64,
+    block_time: u64,
+) -> bool {
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_SOURCE_KEY => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TARGET_KEY => Key::Account(AccountHash::new(ACCOUNT_USER_2)),
+        },
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(transfer_request);
+
+    let is_error = builder.is_error();
+    if !is_error {
+        builder.commit();
+    }
+    is_error
+}
+
+#[test]
+fn should_ignore_expired_approval() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let nft_contract_hash = install_and_mint_two_transferable_tokens(&mut builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let spender = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_APPROVE_BATCH,
+        runtime_args! {
+            ARG_TOKEN_IDS => vec![0u64, 1u64],
+            ARG_SPENDER => Key::Account(spender),
+            ARG_EXPIRES_AT => 1_000u64,
+        },
+    )
+    .with_block_time(100u64)
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    // Expiries are recorded per spender of each token.
+    let mut approval_expiry_bytes = Key::Account(spender).to_bytes().unwrap();
+    approval_expiry_bytes.append(&mut "0".to_string().to_bytes().unwrap());
+    let approval_expiry_item_key =
+        base16::encode_lower(&support::create_blake2b_hash(approval_expiry_bytes));
+    let actual_expires_at: Option<u64> = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        APPROVAL_EXPIRIES,
+        &approval_expiry_item_key,
+    );
+    assert_eq!(actual_expires_at, Some(1_000u64));
+
+    assert!(!transfer_token_at(
+        &mut builder,
+        spender,
+        nft_contract_hash,
+        0u64,
+        999u64
+    ));
+
+    assert!(transfer_token_at(
+        &mut builder,
+        spender,
+        nft_contract_hash,
+        1u64,
+        1_000u64
+    ));
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 6u16, "expired approval should not allow a transfer");
+
+    // Approving again without expiry makes the approval last.
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_APPROVE,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+            ARG_SPENDER => Key::Account(spender),
+        },
+    )
+    .with_block_time(1_000u64)
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    assert!(!transfer_token_at(
+        &mut builder,
+        spender,
+        nft_contract_hash,
+        1u64,
+        5_000u64
+    ));
+}
+
+#[test]
+fn should_ignore_expired_operator() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let nft_contract_hash = install_and_mint_two_transferable_tokens(&mut builder);
+    let operator = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let approve_all_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL,
+        runtime_args! {
+            ARG_APPROVE_ALL => true,
+            ARG_OPERATOR => Key::Account(operator),
+            ARG_EXPIRES_AT => 1_000u64,
+        },
+    )
+    .with_block_time(100u64)
+    .build();
+    builder.exec(approve_all_request).expect_success().commit();
+
+    assert!(!transfer_token_at(
+        &mut builder,
+        operator,
+        nft_contract_hash,
+        0u64,
+        999u64
+    ));
+
+    assert!(transfer_token_at(
+        &mut builder,
+        operator,
+        nft_contract_hash,
+        1u64,
+        1_000u64
+    ));
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 6u16, "expired operator should not allow a transfer");
+}
+
+#[test]
+fn should_disallow_approval_expiring_in_the_past() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let nft_contract_hash = install_and_mint_two_transferable_tokens(&mut builder);
+    let spender = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_APPROVE,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SPENDER => Key::Account(spender),
+            ARG_EXPIRES_AT => 1_000u64,
+        },
+    )
+    .with_block_time(1_000u64)
+    .build();
+    builder.exec(approve_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 195u16, "approval should not expire in the past");
+
+    let approve_all_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL,
+        runtime_args! {
+            ARG_APPROVE_ALL => true,
+            ARG_OPERATOR => Key::Account(spender),
+            ARG_EXPIRES_AT => 500u64,
+        },
+    )
+    .with_block_time(1_000u64)
+    .build();
+    builder.exec(approve_all_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 195u16, "operator should not expire in the past");
+}
+
+#[test]
+fn should_approve_several_spenders_per_token() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let nft_contract_hash = install_and_mint_two_transferable_tokens(&mut builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let first_spender = Key::Account(create_funded_dummy_account(
+        &mut builder,
+        Some(ACCOUNT_USER_1),
+    ));
+    let second_spender_account = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_3));
+    let second_spender = Key::Account(second_spender_account);
+
+    let approve = |builder: &mut InMemoryWasmTestBuilder, token_id: u64, spender: Key| {
+        let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_APPROVE,
+            runtime_args! {
+                ARG_TOKEN_ID => token_id,
+                ARG_SPENDER => spender,
+            },
+        )
+        .build();
+        builder.exec(approve_request).expect_success().commit();
+    };
+
+    approve(&mut builder, 0u64, first_spender);
+    approve(&mut builder, 0u64, second_spender);
+    approve(&mut builder, 0u64, second_spender);
+
+    let approved_spenders: Vec<Key> = support::call_session_code_with_ret(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_key,
+        runtime_args! {
+            ARG_IS_HASH_IDENTIFIER_MODE => false,
+            ARG_TOKEN_ID => 0u64,
+        },
+        GET_APPROVED_WASM,
+        ARG_KEY_NAME,
+    );
+    assert_eq!(approved_spenders, vec![first_spender, second_spender]);
+
+    // Revoking a given spender keeps the others approved.
+    let revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REVOKE,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SPENDER => first_spender,
+        },
+    )
+    .build();
+    builder.exec(revoke_request).expect_success().commit();
+
+    let approved_spenders: Vec<Key> =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED_SPENDERS, "0");
+    assert_eq!(approved_spenders, vec![second_spender]);
+
+    // Revoking without spender revokes every spender.
+    approve(&mut builder, 0u64, first_spender);
+    let revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REVOKE,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+    builder.exec(revoke_request).expect_success().commit();
+
+    let approved_spenders: Vec<Key> =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED_SPENDERS, "0");
+    assert!(approved_spenders.is_empty());
+
+    // Any approved spender may transfer, which clears every approval.
+    approve(&mut builder, 0u64, first_spender);
+    approve(&mut builder, 0u64, second_spender);
+    assert!(!transfer_token_at(
+        &mut builder,
+        second_spender_account,
+        nft_contract_hash,
+        0u64,
+        0u64
+    ));
+
+    let approved_spenders: Vec<Key> =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED_SPENDERS, "0");
+    assert!(approved_spenders.is_empty());
+
+    // Burning a token clears its approvals as well.
+    approve(&mut builder, 1u64, first_spender);
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+        },
+    )
+    .build();
+    builder.exec(burn_request).expect_success().commit();
+
+    let approved_spenders: Vec<Key> =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, APPROVED_SPENDERS, "1");
+    assert!(approved_spenders.is_empty());
+}
+
+#[test]
+fn should_require_receiver_contract_acknowledgment_on_safe_transfer() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let receiver_contract_install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        RECEIVER_CONTRACT_WASM,
+        runtime_args! {},
+    )
+    .build();
+
+    builder
+        .exec(receiver_contract_install_request)
+        .expect_success()
+        .commit();
+
+    let receiver_contract_hash = get_receiver_contract_hash(&builder);
+    let receiver_contract_key = Key::from(receiver_contract_hash);
+
+    let set_acknowledgment_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        receiver_contract_hash,
+        "set_acknowledgment",
+        runtime_args! {
+            ARG_ACKNOWLEDGMENT => "unknown".to_string()
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_acknowledgment_request)
+        .expect_success()
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let data = Bytes::from(vec![4u8, 5, 6]);
+
+    let safe_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SAFE_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => receiver_contract_key,
+            ARG_DATA => data.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(safe_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        204u16,
+        "should not safely transfer a token the receiver contract does not acknowledge",
+    );
+
+    let set_acknowledgment_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        receiver_contract_hash,
+        "set_acknowledgment",
+        runtime_args! {
+            ARG_ACKNOWLEDGMENT => RECEIVER_CONTRACT_ACKNOWLEDGMENT.to_string()
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_acknowledgment_request)
+        .expect_success()
+        .commit();
+
+    let safe_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SAFE_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => receiver_contract_key,
+            ARG_DATA => data.clone(),
+        },
+    )
+    .build();
+
+    builder
+        .exec(safe_transfer_request)
+        .expect_success()
+        .commit();
+
+    let actual_token_owner: Key =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, TOKEN_OWNERS, &0u64.to_string());
+    assert_eq!(actual_token_owner, receiver_contract_key);
+
+    let last_operator: Key = support::query_stored_value(
+        &builder,
+        receiver_contract_key,
+        vec!["last_operator".to_string()],
+    );
+    let last_from: Option<Key> = support::query_stored_value(
+        &builder,
+        receiver_contract_key,
+        vec!["last_from".to_string()],
+    );
+    let last_data: Bytes = support::query_stored_value(
+        &builder,
+        receiver_contract_key,
+        vec!["last_data".to_string()],
+    );
+
+    assert_eq!(last_operator, token_owner_key);
+    assert_eq!(last_from, Some(token_owner_key));
+    assert_eq!(last_data, data);
+}
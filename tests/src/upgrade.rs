@@ -3,33 +3,39 @@ use casper_engine_test_support::{
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
 
-use casper_types::{account::AccountHash, runtime_args, CLValue, ContractHash, Key, RuntimeArgs};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{self, Bytes},
+    runtime_args, CLValue, ContractHash, Key, RuntimeArgs,
+};
 use contract::{
     constants::{
         ACCESS_KEY_NAME_1_0_0, ACL_PACKAGE_MODE, ARG_ACCESS_KEY_NAME_1_0_0, ARG_ACL_PACKAGE_MODE,
-        ARG_COLLECTION_NAME, ARG_EVENTS_MODE, ARG_HASH_KEY_NAME_1_0_0, ARG_NAMED_KEY_CONVENTION,
-        ARG_OPERATOR_BURN_MODE, ARG_PACKAGE_OPERATOR_MODE, ARG_SOURCE_KEY, ARG_TARGET_KEY,
-        ARG_TOKEN_HASH, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ARG_TOTAL_TOKEN_SUPPLY,
-        ENTRY_POINT_MINT, ENTRY_POINT_REGISTER_OWNER, NUMBER_OF_MINTED_TOKENS, OPERATOR_BURN_MODE,
-        PACKAGE_OPERATOR_MODE, PAGE_LIMIT, PREFIX_ACCESS_KEY_NAME, PREFIX_HASH_KEY_NAME,
+        ARG_ADMIN, ARG_COLLECTION_NAME, ARG_EVENTS_MODE, ARG_HASH_KEY_NAME_1_0_0,
+        ARG_NAMED_KEY_CONVENTION, ARG_OPERATOR_BURN_MODE, ARG_PACKAGE_ACCESS_UREF,
+        ARG_PACKAGE_OPERATOR_MODE, ARG_PAGE_ENCODING, ARG_SOURCE_KEY, ARG_TARGET_KEY,
+        ARG_TOKEN_HASH, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ARG_TOTAL_TOKEN_SUPPLY, CONFIG,
+        ENTRY_POINT_MINT, ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_TRANSFER_ADMIN, MAX_PAGE_LIMIT,
+        NUMBER_OF_MINTED_TOKENS, OPERATOR_BURN_MODE, PACKAGE_OPERATOR_MODE, PAGE_ENCODING,
+        PAGE_LIMIT, PREFIX_ACCESS_KEY_NAME, PREFIX_HASH_KEY_NAME, PREFIX_PAGE_DICTIONARY,
         RECEIPT_NAME, UNMATCHED_HASH_COUNT,
     },
     events::events_ces::Migration,
-    modalities::EventsMode,
+    modalities::{Config, EventsMode},
 };
 
 use crate::utility::{
     constants::{
-        ACCOUNT_USER_1, ARG_IS_HASH_IDENTIFIER_MODE, ARG_NFT_CONTRACT_HASH,
-        ARG_NFT_CONTRACT_PACKAGE_HASH, CONTRACT_1_0_0_WASM, CONTRACT_1_1_0_WASM,
-        CONTRACT_1_2_0_WASM, CONTRACT_1_3_0_WASM, CONTRACT_1_4_0_WASM, CONTRACT_1_5_0_WASM,
-        MANGLE_NAMED_KEYS, MINT_1_0_0_WASM, MINT_SESSION_WASM, NFT_CONTRACT_WASM,
-        NFT_TEST_COLLECTION, NFT_TEST_SYMBOL, PAGE_SIZE, TRANSFER_SESSION_WASM,
+        ACCEPT_ADMIN_SESSION_WASM, ACCOUNT_USER_1, ARG_IS_HASH_IDENTIFIER_MODE,
+        ARG_NFT_CONTRACT_HASH, ARG_NFT_CONTRACT_PACKAGE_HASH, CONTRACT_1_0_0_WASM,
+        CONTRACT_1_1_0_WASM, CONTRACT_1_2_0_WASM, CONTRACT_1_3_0_WASM, CONTRACT_1_4_0_WASM,
+        CONTRACT_1_5_0_WASM, MANGLE_NAMED_KEYS, MINT_1_0_0_WASM, MINT_SESSION_WASM,
+        NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, NFT_TEST_SYMBOL, PAGE_SIZE, TRANSFER_SESSION_WASM,
         UPDATED_RECEIPTS_WASM,
     },
     installer_request_builder::{
         InstallerRequestBuilder, MetadataMutability, NFTIdentifierMode, NFTMetadataKind,
-        NamedKeyConventionMode, OwnerReverseLookupMode, OwnershipMode,
+        NamedKeyConventionMode, OwnerReverseLookupMode, OwnershipMode, PageEncoding,
     },
     support::{self},
 };
@@ -660,7 +666,7 @@ fn should_upgrade_with_custom_named_keys() {
 }
 
 #[test]
-fn should_not_upgrade_with_larger_total_token_supply() {
+fn should_upgrade_with_larger_total_token_supply() {
     let mut builder = InMemoryWasmTestBuilder::default();
     builder
         .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
@@ -690,14 +696,326 @@ fn should_not_upgrade_with_larger_total_token_supply() {
     )
     .build();
 
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let nft_contract_key: Key = support::get_nft_contract_hash(&builder).into();
+
+    let total_token_supply_post_upgrade: u64 = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![ARG_TOTAL_TOKEN_SUPPLY.to_string()],
+    );
+
+    assert_eq!(total_token_supply_post_upgrade, 1000u64);
+}
+
+#[test]
+fn should_not_lower_total_token_supply_on_upgrade_with_owner_pages() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1000u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        NFT_CONTRACT_WASM,
+        runtime_args! {
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+            ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
+            ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+            ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+            ARG_TOTAL_TOKEN_SUPPLY => 10u64,
+        },
+    )
+    .build();
+
     builder.exec(upgrade_request).expect_failure();
-    let error = builder.get_error().expect("must have error");
 
+    let error = builder.get_error().expect("must have error");
     support::assert_expected_error(
         error,
-        150u16,
-        "cannot upgrade when new total token supply is larger than pre-migration one",
+        232u16,
+        "collections keeping owner pages must not lower their total token supply",
+    );
+
+    let nft_contract_key: Key = support::get_nft_contract_hash(&builder).into();
+    let total_token_supply: u64 = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![ARG_TOTAL_TOKEN_SUPPLY.to_string()],
+    );
+    assert_eq!(total_token_supply, 1000u64);
+}
+
+#[test]
+fn should_not_upgrade_with_supply_exceeding_max_page_limit() {
+    for reporting_mode in [
+        OwnerReverseLookupMode::NoLookUp,
+        OwnerReverseLookupMode::Complete,
+    ] {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let install_request =
+            InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+                .with_collection_name(NFT_TEST_COLLECTION.to_string())
+                .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+                .with_total_token_supply(1000u64)
+                .with_ownership_mode(OwnershipMode::Transferable)
+                .with_reporting_mode(reporting_mode)
+                .with_identifier_mode(NFTIdentifierMode::Ordinal)
+                .with_nft_metadata_kind(NFTMetadataKind::Raw)
+                .build();
+
+        builder.exec(install_request).expect_success().commit();
+
+        let upgrade_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            NFT_CONTRACT_WASM,
+            runtime_args! {
+                ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+                ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
+                ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+                ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+                ARG_TOTAL_TOKEN_SUPPLY => MAX_PAGE_LIMIT * PAGE_SIZE + 1,
+            },
+        )
+        .build();
+
+        builder.exec(upgrade_request).expect_failure();
+
+        let error = builder.get_error().expect("must have error");
+        support::assert_expected_error(
+            error,
+            133u16,
+            "cannot upgrade to a supply spanning more pages than the page limit cap",
+        );
+
+        let nft_contract_key: Key = support::get_nft_contract_hash(&builder).into();
+        let total_token_supply: u64 = support::query_stored_value(
+            &builder,
+            nft_contract_key,
+            vec![ARG_TOTAL_TOKEN_SUPPLY.to_string()],
+        );
+        assert_eq!(total_token_supply, 1000u64);
+    }
+}
+
+#[test]
+fn should_raise_page_limit_when_raising_total_token_supply_on_upgrade() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1000u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+
+    let register_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REGISTER_OWNER,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        },
+    )
+    .build();
+
+    builder.exec(register_request).expect_success().commit();
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        NFT_CONTRACT_WASM,
+        runtime_args! {
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+            ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
+            ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+            ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+            ARG_TOTAL_TOKEN_SUPPLY => 2500u64,
+        },
+    )
+    .build();
+
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let total_token_supply_post_upgrade: u64 = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![ARG_TOTAL_TOKEN_SUPPLY.to_string()],
+    );
+    assert_eq!(total_token_supply_post_upgrade, 2500u64);
+
+    let page_limit: u64 =
+        support::query_stored_value(&builder, nft_contract_key, vec![PAGE_LIMIT.to_string()]);
+    assert_eq!(page_limit, 3u64);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => "",
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    // Only the page of the minted token is allocated.
+    let nft_contract_named_keys = builder
+        .query(None, nft_contract_key, &[])
+        .expect("must have nft contract")
+        .as_contract()
+        .expect("must convert contract")
+        .named_keys()
+        .clone();
+    for (page_number, is_allocated) in [(0u64, true), (1u64, false), (2u64, false)] {
+        assert_eq!(
+            nft_contract_named_keys
+                .contains_key(&format!("{PREFIX_PAGE_DICTIONARY}_{page_number}")),
+            is_allocated
+        );
+    }
+}
+
+#[test]
+fn should_convert_owner_pages_to_bitmaps_when_opting_in_on_upgrade() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .with_page_encoding(PageEncoding::BoolList)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+
+    let register_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REGISTER_OWNER,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        },
+    )
+    .build();
+
+    builder.exec(register_request).expect_success().commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => "",
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        NFT_CONTRACT_WASM,
+        runtime_args! {
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+            ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
+            ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+            ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+            ARG_PAGE_ENCODING => PageEncoding::Bitmap as u8,
+        },
+    )
+    .build();
+
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let page_encoding: u8 =
+        support::query_stored_value(&builder, nft_contract_key, vec![PAGE_ENCODING.to_string()]);
+    assert_eq!(page_encoding, PageEncoding::Bitmap as u8);
+
+    // Records written before the upgrade are converted as they are next written.
+    let page_dictionary_name = format!("{PREFIX_PAGE_DICTIONARY}_0");
+    let page_record_pre_conversion = support::get_dictionary_cl_value_from_key(
+        &builder,
+        &nft_contract_key,
+        &page_dictionary_name,
+        &DEFAULT_ACCOUNT_ADDR.to_string(),
+    );
+    assert_eq!(
+        page_record_pre_conversion.inner_bytes().len() as u64,
+        4 + PAGE_SIZE
+    );
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => "",
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let page_record = support::get_dictionary_cl_value_from_key(
+        &builder,
+        &nft_contract_key,
+        &page_dictionary_name,
+        &DEFAULT_ACCOUNT_ADDR.to_string(),
     );
+    assert_eq!(
+        page_record.inner_bytes().len() as u64,
+        4 + 1 + PAGE_SIZE / 8
+    );
+
+    let page = support::decode_page_record(&page_record);
+    assert!(page[0] && page[1]);
+    assert!(!page[2..].contains(&true));
 }
 
 fn should_safely_upgrade_from_old_version_to_new_version_with_reporting_mode(
@@ -756,20 +1074,23 @@ fn should_safely_upgrade_from_old_version_to_new_version_with_reporting_mode(
         builder.exec(mint_request).expect_success().commit();
     }
 
-    let upgrade_request = ExecuteRequestBuilder::standard(
-        *DEFAULT_ACCOUNT_ADDR,
-        new_version,
-        runtime_args! {
-            ARG_NFT_CONTRACT_HASH => nft_contract_key,
-            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
-            ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
-            ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
-            ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
-            ARG_TOTAL_TOKEN_SUPPLY => 10u64,
-            ARG_EVENTS_MODE => EventsMode::CES as u8 // Optin for CES on upgrade
-        },
-    )
-    .build();
+    let mut upgrade_args = runtime_args! {
+        ARG_NFT_CONTRACT_HASH => nft_contract_key,
+        ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+        ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
+        ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+        ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+        ARG_EVENTS_MODE => EventsMode::CES as u8 // Optin for CES on upgrade
+    };
+    // Collections keeping owner pages cannot lower their total token supply.
+    if reporting_mode == OwnerReverseLookupMode::NoLookUp {
+        upgrade_args
+            .insert(ARG_TOTAL_TOKEN_SUPPLY, 10u64)
+            .expect("must insert total token supply");
+    }
+
+    let upgrade_request =
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, new_version, upgrade_args).build();
 
     builder.exec(upgrade_request).expect_success().commit();
 
@@ -1109,7 +1430,6 @@ fn should_safely_upgrade_from_1_0_0_to_1_2_0_to_current_version() {
             ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
             ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
             ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
-            ARG_TOTAL_TOKEN_SUPPLY => 10u64,
             ARG_EVENTS_MODE => EventsMode::CES as u8
         },
     )
@@ -1221,7 +1541,6 @@ fn should_safely_upgrade_from_1_0_0_to_1_3_0_to_current_version() {
             ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
             ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
             ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
-            ARG_TOTAL_TOKEN_SUPPLY => 10u64,
             ARG_EVENTS_MODE => EventsMode::CES as u8
         },
     )
@@ -1333,7 +1652,6 @@ fn should_safely_upgrade_from_1_0_0_to_1_4_0_to_current_version() {
             ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
             ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
             ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
-            ARG_TOTAL_TOKEN_SUPPLY => 10u64,
             ARG_EVENTS_MODE => EventsMode::CES as u8
         },
     )
@@ -1445,7 +1763,6 @@ fn should_safely_upgrade_from_1_0_0_to_1_5_0_to_current_version() {
             ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
             ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
             ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
-            ARG_TOTAL_TOKEN_SUPPLY => 10u64,
             ARG_EVENTS_MODE => EventsMode::CES as u8
         },
     )
@@ -1540,7 +1857,6 @@ fn should_safely_upgrade_from_1_5_0_to_current_version_without_supplying_events_
             ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
             ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
             ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
-            ARG_TOTAL_TOKEN_SUPPLY => 10u64,
         },
     )
     .build();
@@ -1627,7 +1943,6 @@ fn should_safely_upgrade_from_1_5_0_and_disable_events_mode() {
             ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
             ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
             ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
-            ARG_TOTAL_TOKEN_SUPPLY => 10u64,
             ARG_EVENTS_MODE => EventsMode::NoEvents as u8,
         },
     )
@@ -1647,6 +1962,16 @@ fn should_safely_upgrade_from_1_5_0_and_disable_events_mode() {
 
     assert_eq!(number_of_tokens_at_upgrade, 3);
 
+    // The config record is packed on migrate from the updated named keys.
+    let config_bytes: Bytes =
+        support::query_stored_value(&builder, nft_contract_key, vec![CONFIG.to_string()]);
+    let config: Config = bytesrepr::deserialize_from_slice(config_bytes).unwrap();
+    assert!(config.events_mode == EventsMode::NoEvents);
+    assert_eq!(
+        config.metadata_mutability as u8,
+        MetadataMutability::Mutable as u8
+    );
+
     // Expect No Migration event after 3 Mint events.
     let seed_uref = *builder
         .query(None, nft_contract_key, &[])
@@ -1772,3 +2097,117 @@ fn should_safely_upgrade_from_1_0_0_to_current_version() {
     let actual_event: Migration = support::get_event(&builder, &nft_contract_key, 0).unwrap();
     assert_eq!(actual_event, expected_event, "Expected Migration event.");
 }
+
+#[test]
+fn should_hand_upgrades_over_to_new_admin() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let new_admin_account =
+        support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1000u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_package_hash = support::get_nft_contract_package_hash(&builder);
+    let access_key_name = format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}");
+    let hash_key_name = format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}");
+
+    let package_access_uref = builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .get(&access_key_name)
+        .expect("must have the package access key")
+        .into_uref()
+        .expect("must be a uref");
+
+    let transfer_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER_ADMIN,
+        runtime_args! {
+            ARG_ADMIN => Key::Account(new_admin_account),
+            ARG_PACKAGE_ACCESS_UREF => Some(package_access_uref),
+        },
+    )
+    .build();
+
+    builder
+        .exec(transfer_admin_request)
+        .expect_success()
+        .commit();
+
+    let accept_admin_request = ExecuteRequestBuilder::standard(
+        new_admin_account,
+        ACCEPT_ADMIN_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_PACKAGE_HASH => Key::from(nft_contract_package_hash),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(accept_admin_request).expect_success().commit();
+
+    let new_admin_named_keys = builder
+        .get_expected_account(new_admin_account)
+        .named_keys()
+        .clone();
+    assert_eq!(
+        new_admin_named_keys.get(&access_key_name),
+        Some(&Key::from(package_access_uref))
+    );
+
+    // The previous admin still holds the package access URef but can no longer upgrade.
+    let previous_admin_upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        NFT_CONTRACT_WASM,
+        runtime_args! {
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+            ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
+            ARG_ACCESS_KEY_NAME_1_0_0 => access_key_name.clone(),
+            ARG_HASH_KEY_NAME_1_0_0 => hash_key_name.clone(),
+        },
+    )
+    .build();
+
+    builder
+        .exec(previous_admin_upgrade_request)
+        .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    support::assert_expected_error(
+        error,
+        1u16,
+        "the previous admin must not upgrade the contract",
+    );
+
+    let new_admin_upgrade_request = ExecuteRequestBuilder::standard(
+        new_admin_account,
+        NFT_CONTRACT_WASM,
+        runtime_args! {
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+            ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
+            ARG_ACCESS_KEY_NAME_1_0_0 => access_key_name,
+            ARG_HASH_KEY_NAME_1_0_0 => hash_key_name,
+        },
+    )
+    .build();
+
+    builder
+        .exec(new_admin_upgrade_request)
+        .expect_success()
+        .commit();
+}
@@ -13,7 +13,14 @@ pub const MINT_SESSION_WASM: &str = "mint_call.wasm";
 pub const MINTING_CONTRACT_WASM: &str = "minting_contract.wasm";
 pub const TRANSFER_FILTER_CONTRACT_WASM: &str = "transfer_filter_contract.wasm";
 pub const NFT_CONTRACT_WASM: &str = "contract.wasm";
+pub const RECEIVER_CONTRACT_WASM: &str = "receiver_contract.wasm";
 pub const OWNER_OF_SESSION_WASM: &str = "owner_of_call.wasm";
+pub const ROYALTY_INFO_SESSION_WASM: &str = "royalty_info_call.wasm";
+pub const ACCEPT_ADMIN_SESSION_WASM: &str = "accept_admin_call.wasm";
+pub const COLLECTION_STATS_SESSION_WASM: &str = "collection_stats_call.wasm";
+pub const TOKENS_OF_OWNER_SESSION_WASM: &str = "tokens_of_owner_call.wasm";
+pub const TOKEN_OF_OWNER_BY_INDEX_SESSION_WASM: &str = "token_of_owner_by_index_call.wasm";
+pub const TOKEN_URI_SESSION_WASM: &str = "token_uri_call.wasm";
 pub const TRANSFER_SESSION_WASM: &str = "transfer_call.wasm";
 pub const UPDATED_RECEIPTS_WASM: &str = "updated_receipts.wasm";
 
@@ -23,12 +30,14 @@ pub const ARG_NFT_CONTRACT_HASH: &str = "nft_contract_hash";
 pub const ARG_NFT_CONTRACT_PACKAGE_HASH: &str = "nft_contract_package_hash";
 pub const ARG_REVERSE_LOOKUP: &str = "reverse_lookup";
 pub const ARG_FILTER_CONTRACT_RETURN_VALUE: &str = "return_value";
+pub const ARG_ACKNOWLEDGMENT: &str = "acknowledgment";
 
 pub const CONTRACT_NAME: &str = "cep78_contract_hash_nft-test";
 pub const MINTING_CONTRACT_NAME: &str = "minting_contract_hash";
 pub const MINTING_CONTRACT_PACKAGE_NAME: &str = "minting_contract_package_hash";
 pub const MINTING_CONTRACT_VERSION: &str = "minting_contract_version";
 pub const TRANSFER_FILTER_CONTRACT_NAME: &str = "transfer_filter_contract_hash";
+pub const RECEIVER_CONTRACT_NAME: &str = "receiver_contract_hash";
 pub const NFT_TEST_COLLECTION: &str = "nft-test";
 pub const NFT_TEST_SYMBOL: &str = "TEST";
 pub const TOKEN_HASH: &str = "token_hash";
@@ -5,12 +5,15 @@ use casper_types::{
 };
 use contract::constants::{
     ARG_ACL_PACKAGE_MODE, ARG_ACL_WHITELIST, ARG_ADDITIONAL_REQUIRED_METADATA, ARG_ALLOW_MINTING,
-    ARG_BURN_MODE, ARG_COLLECTION_NAME, ARG_COLLECTION_SYMBOL, ARG_CONTRACT_WHITELIST,
-    ARG_EVENTS_MODE, ARG_HOLDER_MODE, ARG_IDENTIFIER_MODE, ARG_JSON_SCHEMA,
-    ARG_METADATA_MUTABILITY, ARG_MINTING_MODE, ARG_NAMED_KEY_CONVENTION, ARG_NFT_KIND,
-    ARG_NFT_METADATA_KIND, ARG_OPERATOR_BURN_MODE, ARG_OPTIONAL_METADATA, ARG_OWNERSHIP_MODE,
-    ARG_OWNER_LOOKUP_MODE, ARG_PACKAGE_OPERATOR_MODE, ARG_TOTAL_TOKEN_SUPPLY,
-    ARG_TRANSFER_FILTER_CONTRACT, ARG_WHITELIST_MODE,
+    ARG_BASE_URI, ARG_BURN_FILTER_CONTRACT, ARG_BURN_MODE, ARG_COLLECTION_METADATA,
+    ARG_COLLECTION_NAME, ARG_COLLECTION_SYMBOL, ARG_CONTRACT_WHITELIST, ARG_EVENTS_MODE,
+    ARG_HOLDER_MODE, ARG_IDENTIFIER_MODE, ARG_JSON_SCHEMA, ARG_METADATA_MUTABILITY,
+    ARG_METADATA_STORAGE_MODE, ARG_MINTING_MODE, ARG_MINT_FILTER_CONTRACT,
+    ARG_NAMED_KEY_CONVENTION, ARG_NFT_KIND, ARG_NFT_METADATA_KIND, ARG_OPERATOR_BURN_MODE,
+    ARG_OPTIONAL_METADATA, ARG_OWNERSHIP_MODE, ARG_OWNER_LOOKUP_MODE, ARG_PACKAGE_OPERATOR_MODE,
+    ARG_PAGE_ENCODING, ARG_PAGE_SIZE, ARG_ROYALTY_BASIS_POINTS, ARG_ROYALTY_RECEIVER,
+    ARG_TOTAL_TOKEN_SUPPLY, ARG_TRANSFER_FILTER_CONTRACT, ARG_TRANSFER_FILTER_CONTRACT_VERSION,
+    ARG_WHITELIST_MODE,
 };
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
@@ -18,7 +21,8 @@ use std::collections::BTreeMap;
 
 // Modalities reexports.
 pub use contract::modalities::{
-    EventsMode, MintingMode, NFTHolderMode, NFTKind, OwnershipMode, WhitelistMode,
+    EventsMode, MetadataStorageMode, MintingMode, NFTHolderMode, NFTKind, OwnershipMode,
+    PageEncoding, Role, TransferFilterContractVersion, WhitelistMode,
 };
 
 use super::constants::{NFT_TEST_COLLECTION, NFT_TEST_SYMBOL};
@@ -97,6 +101,9 @@ pub enum NFTIdentifierMode {
 pub enum MetadataMutability {
     Immutable = 0,
     Mutable = 1,
+    IssuerOnly = 2,
+    OwnerOrIssuer = 3,
+    Installer = 4,
 }
 
 #[repr(u8)]
@@ -149,6 +156,16 @@ pub(crate) struct InstallerRequestBuilder {
     optional_metadata: CLValue,
     events_mode: CLValue,
     transfer_filter_contract: Option<CLValue>,
+    transfer_filter_contract_version: Option<CLValue>,
+    page_encoding: Option<CLValue>,
+    page_size: Option<CLValue>,
+    metadata_storage_mode: Option<CLValue>,
+    mint_filter_contract: Option<CLValue>,
+    burn_filter_contract: Option<CLValue>,
+    royalty_receiver: Option<CLValue>,
+    royalty_basis_points: Option<CLValue>,
+    base_uri: Option<CLValue>,
+    collection_metadata: Option<CLValue>,
 }
 
 impl InstallerRequestBuilder {
@@ -193,6 +210,16 @@ impl InstallerRequestBuilder {
             optional_metadata: CLValue::from_t(Bytes::new()).unwrap(),
             events_mode: CLValue::from_t(EventsMode::CES as u8).unwrap(),
             transfer_filter_contract: None,
+            transfer_filter_contract_version: None,
+            page_encoding: None,
+            page_size: None,
+            metadata_storage_mode: None,
+            mint_filter_contract: None,
+            burn_filter_contract: None,
+            royalty_receiver: None,
+            royalty_basis_points: None,
+            base_uri: None,
+            collection_metadata: None,
         }
     }
 
@@ -351,6 +378,63 @@ impl InstallerRequestBuilder {
         self
     }
 
+    pub(crate) fn with_transfer_filter_contract_version(
+        mut self,
+        transfer_filter_contract_version: TransferFilterContractVersion,
+    ) -> Self {
+        self.transfer_filter_contract_version =
+            Some(CLValue::from_t(transfer_filter_contract_version as u8).unwrap());
+        self
+    }
+
+    pub(crate) fn with_page_encoding(mut self, page_encoding: PageEncoding) -> Self {
+        self.page_encoding = Some(CLValue::from_t(page_encoding as u8).unwrap());
+        self
+    }
+
+    pub(crate) fn with_page_size(mut self, page_size: u64) -> Self {
+        self.page_size = Some(CLValue::from_t(page_size).unwrap());
+        self
+    }
+
+    pub(crate) fn with_metadata_storage_mode(
+        mut self,
+        metadata_storage_mode: MetadataStorageMode,
+    ) -> Self {
+        self.metadata_storage_mode = Some(CLValue::from_t(metadata_storage_mode as u8).unwrap());
+        self
+    }
+
+    pub(crate) fn with_mint_filter_contract(mut self, mint_filter_contract: Key) -> Self {
+        self.mint_filter_contract = Some(CLValue::from_t(mint_filter_contract).unwrap());
+        self
+    }
+
+    pub(crate) fn with_burn_filter_contract(mut self, burn_filter_contract: Key) -> Self {
+        self.burn_filter_contract = Some(CLValue::from_t(burn_filter_contract).unwrap());
+        self
+    }
+
+    pub(crate) fn with_royalty_receiver(mut self, royalty_receiver: Key) -> Self {
+        self.royalty_receiver = Some(CLValue::from_t(royalty_receiver).unwrap());
+        self
+    }
+
+    pub(crate) fn with_royalty_basis_points(mut self, royalty_basis_points: u64) -> Self {
+        self.royalty_basis_points = Some(CLValue::from_t(royalty_basis_points).unwrap());
+        self
+    }
+
+    pub(crate) fn with_base_uri(mut self, base_uri: &str) -> Self {
+        self.base_uri = Some(CLValue::from_t(base_uri.to_string()).unwrap());
+        self
+    }
+
+    pub(crate) fn with_collection_metadata(mut self, collection_metadata: &str) -> Self {
+        self.collection_metadata = Some(CLValue::from_t(collection_metadata.to_string()).unwrap());
+        self
+    }
+
     pub(crate) fn build(self) -> ExecuteRequest {
         let mut runtime_args = RuntimeArgs::new();
         runtime_args.insert_cl_value(ARG_COLLECTION_NAME, self.collection_name);
@@ -391,6 +475,39 @@ impl InstallerRequestBuilder {
         if let Some(transfer_filter_contract) = self.transfer_filter_contract {
             runtime_args.insert_cl_value(ARG_TRANSFER_FILTER_CONTRACT, transfer_filter_contract);
         }
+        if let Some(transfer_filter_contract_version) = self.transfer_filter_contract_version {
+            runtime_args.insert_cl_value(
+                ARG_TRANSFER_FILTER_CONTRACT_VERSION,
+                transfer_filter_contract_version,
+            );
+        }
+        if let Some(page_encoding) = self.page_encoding {
+            runtime_args.insert_cl_value(ARG_PAGE_ENCODING, page_encoding);
+        }
+        if let Some(page_size) = self.page_size {
+            runtime_args.insert_cl_value(ARG_PAGE_SIZE, page_size);
+        }
+        if let Some(metadata_storage_mode) = self.metadata_storage_mode {
+            runtime_args.insert_cl_value(ARG_METADATA_STORAGE_MODE, metadata_storage_mode);
+        }
+        if let Some(mint_filter_contract) = self.mint_filter_contract {
+            runtime_args.insert_cl_value(ARG_MINT_FILTER_CONTRACT, mint_filter_contract);
+        }
+        if let Some(burn_filter_contract) = self.burn_filter_contract {
+            runtime_args.insert_cl_value(ARG_BURN_FILTER_CONTRACT, burn_filter_contract);
+        }
+        if let Some(royalty_receiver) = self.royalty_receiver {
+            runtime_args.insert_cl_value(ARG_ROYALTY_RECEIVER, royalty_receiver);
+        }
+        if let Some(royalty_basis_points) = self.royalty_basis_points {
+            runtime_args.insert_cl_value(ARG_ROYALTY_BASIS_POINTS, royalty_basis_points);
+        }
+        if let Some(base_uri) = self.base_uri {
+            runtime_args.insert_cl_value(ARG_BASE_URI, base_uri);
+        }
+        if let Some(collection_metadata) = self.collection_metadata {
+            runtime_args.insert_cl_value(ARG_COLLECTION_METADATA, collection_metadata);
+        }
         ExecuteRequestBuilder::standard(self.account_hash, &self.session_file, runtime_args).build()
     }
 }
@@ -3,7 +3,7 @@ use super::{
 };
 use crate::utility::constants::{
     ARG_KEY_NAME, ARG_NFT_CONTRACT_HASH, CONTRACT_NAME, MINTING_CONTRACT_NAME, PAGE_SIZE,
-    TRANSFER_FILTER_CONTRACT_NAME,
+    RECEIVER_CONTRACT_NAME, TEST_PRETTY_721_META_DATA, TRANSFER_FILTER_CONTRACT_NAME,
 };
 use blake2::{
     digest::{Update, VariableOutput},
@@ -19,13 +19,19 @@ use casper_execution_engine::{
 };
 use casper_types::{
     account::AccountHash,
-    bytesrepr::{Bytes, FromBytes},
+    bytesrepr::{self, Bytes, FromBytes},
     runtime_args,
     system::{handle_payment::ARG_TARGET, mint::ARG_ID},
-    ApiError, CLTyped, CLValueError, ContractHash, ContractPackageHash, Key, PublicKey,
+    ApiError, CLTyped, CLValue, CLValueError, ContractHash, ContractPackageHash, Key, PublicKey,
     RuntimeArgs, SecretKey, URef, BLAKE2B_DIGEST_LENGTH,
 };
-use contract::constants::{HASH_KEY_NAME_1_0_0, INDEX_BY_HASH, PREFIX_PAGE_DICTIONARY};
+use contract::{
+    constants::{
+        ARG_TOKEN_HASH, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ENTRY_POINT_MINT,
+        HASH_KEY_NAME_1_0_0, INDEX_BY_HASH, PREFIX_PAGE_DICTIONARY,
+    },
+    modalities::PageEncoding,
+};
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 use sha256::digest;
@@ -101,12 +107,37 @@ pub(crate) fn get_transfer_filter_contract_hash(
     ContractHash::new(transfer_filter_contract_hash)
 }
 
+pub(crate) fn get_receiver_contract_hash(
+    builder: &WasmTestBuilder<InMemoryGlobalState>,
+) -> ContractHash {
+    let receiver_contract_hash = builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .get(RECEIVER_CONTRACT_NAME)
+        .expect("must have receiver contract hash entry in named keys")
+        .into_hash()
+        .expect("must get hash_addr");
+
+    ContractHash::new(receiver_contract_hash)
+}
+
 pub(crate) fn get_dictionary_value_from_key<T: CLTyped + FromBytes>(
     builder: &WasmTestBuilder<InMemoryGlobalState>,
     nft_contract_key: &Key,
     dictionary_name: &str,
     dictionary_key: &str,
 ) -> T {
+    get_dictionary_cl_value_from_key(builder, nft_contract_key, dictionary_name, dictionary_key)
+        .into_t()
+        .unwrap()
+}
+
+pub(crate) fn get_dictionary_cl_value_from_key(
+    builder: &WasmTestBuilder<InMemoryGlobalState>,
+    nft_contract_key: &Key,
+    dictionary_name: &str,
+    dictionary_key: &str,
+) -> CLValue {
     let seed_uref = *builder
         .query(None, *nft_contract_key, &[])
         .expect("must have nft contract")
@@ -124,8 +155,29 @@ pub(crate) fn get_dictionary_value_from_key<T: CLTyped + FromBytes>(
         .as_cl_value()
         .expect("T should be CLValue")
         .to_owned()
-        .into_t()
-        .unwrap()
+}
+
+// Reads a page or page table record of the owner, whichever page encoding it was written with.
+pub(crate) fn get_page_record_from_key(
+    builder: &WasmTestBuilder<InMemoryGlobalState>,
+    nft_contract_key: &Key,
+    dictionary_name: &str,
+    dictionary_key: &str,
+) -> Vec<bool> {
+    decode_page_record(&get_dictionary_cl_value_from_key(
+        builder,
+        nft_contract_key,
+        dictionary_name,
+        dictionary_key,
+    ))
+}
+
+// Bool list and bitmap page records share the byte layout of Bytes, the page encoding tells them
+// apart.
+pub(crate) fn decode_page_record(page_record: &CLValue) -> Vec<bool> {
+    let record: Bytes = bytesrepr::deserialize_from_slice(page_record.inner_bytes())
+        .expect("page record should be a list of bytes");
+    PageEncoding::decode(&record)
 }
 
 fn create_dummy_key_pair(account_string: [u8; 32]) -> (SecretKey, PublicKey) {
@@ -237,6 +289,35 @@ pub(crate) fn call_session_code_with_ret<T: CLTyped + FromBytes>(
     query_stored_value::<T>(builder, account_hash.into(), [key_name.to_string()].into())
 }
 
+// Executes a mint of a token with the pretty NFT721 test metadata for the default account, leaving
+// the caller to check the outcome of the execution.
+pub(crate) fn mint_token<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    minter: AccountHash,
+    nft_contract_hash: ContractHash,
+    token_hash: Option<&str>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut mint_args = runtime_args! {
+        ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+    };
+    if let Some(token_hash) = token_hash {
+        mint_args
+            .insert(ARG_TOKEN_HASH, token_hash.to_string())
+            .unwrap();
+    }
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        minter,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        mint_args,
+    )
+    .build();
+
+    builder.exec(mint_request)
+}
+
 pub(crate) fn create_blake2b_hash<T: AsRef<[u8]>>(data: T) -> [u8; BLAKE2B_DIGEST_LENGTH] {
     let mut result = [0; BLAKE2B_DIGEST_LENGTH];
     // NOTE: Assumed safe as `BLAKE2B_DIGEST_LENGTH` is a valid value for a hasher
@@ -287,7 +368,7 @@ pub(crate) fn get_token_page_by_id(
 ) -> Vec<bool> {
     let page_number = token_id / PAGE_SIZE;
     let token_page_item_key = make_page_dictionary_item_key(token_owner_key);
-    get_dictionary_value_from_key(
+    get_page_record_from_key(
         builder,
         nft_contract_key,
         &format!("{PREFIX_PAGE_DICTIONARY}_{page_number}"),